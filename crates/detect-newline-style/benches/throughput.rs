@@ -0,0 +1,55 @@
+// measures detection throughput (MB/s) for inputs w/ short, typical, and long
+// lines - compares feeding small chunks (line ending by line ending scan)
+// against feeding large chunks (vectorized bulk counting)
+//
+// run with: cargo bench -p detect-newline-style
+use detect_newline_style::{LineEnding, LineEndingDetector};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZE: usize = 64 * 1024 * 1024;
+const ROUNDS: u32 = 5;
+
+fn input(line_len: usize) -> Vec<u8> {
+    let mut line = "x".repeat(line_len - 2);
+    line.push_str("\r\n");
+    line.repeat(SIZE / line_len).into_bytes()
+}
+
+fn measure<F: Fn(&[u8]) -> LineEnding>(name: &str, bytes: &[u8], f: F) {
+    let mut best = Duration::MAX;
+
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        let eol = f(black_box(bytes));
+        best = best.min(start.elapsed());
+        assert_eq!(eol, LineEnding::CRLF);
+    }
+
+    let mbps = bytes.len() as f64 / (1024.0 * 1024.0) / best.as_secs_f64();
+    println!("{:<40} {:>10.0} MB/s", name, mbps);
+}
+
+fn chunked(bytes: &[u8], size: usize) -> LineEnding {
+    let mut detector = LineEndingDetector::new(LineEnding::LF);
+
+    for chunk in bytes.chunks(size) {
+        detector.feed(chunk);
+    }
+
+    detector.finish()
+}
+
+fn main() {
+    for line_len in [8, 80, 1024] {
+        let bytes = input(line_len);
+        let text = std::str::from_utf8(&bytes).unwrap();
+
+        println!("lines of {} bytes:", line_len);
+        measure("  scan (512 byte chunks)", &bytes, |b| chunked(b, 512));
+        measure("  bulk (64KB chunks)", &bytes, |b| chunked(b, 64 * 1024));
+        measure("  LineEnding::find()", &bytes, |_| {
+            LineEnding::find(text, LineEnding::LF)
+        });
+    }
+}
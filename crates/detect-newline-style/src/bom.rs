@@ -0,0 +1,144 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A byte order mark (BOM) found at the start of some content - see
+/// [`LineEnding::analyze()`](crate::LineEnding::analyze)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Bom {
+    /// UTF-8 BOM (`EF BB BF`)
+    Utf8,
+    /// UTF-16 little-endian BOM (`FF FE`)
+    Utf16LE,
+    /// UTF-16 big-endian BOM (`FE FF`)
+    Utf16BE,
+    /// UTF-32 little-endian BOM (`FF FE 00 00`)
+    Utf32LE,
+    /// UTF-32 big-endian BOM (`00 00 FE FF`)
+    Utf32BE,
+}
+
+impl Bom {
+    /// Determines which byte order mark (if any) the given content starts with
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The content you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::Bom;
+    /// assert_eq!(Bom::sniff([0xEF, 0xBB, 0xBF, b'a']), Some(Bom::Utf8));
+    /// assert_eq!(Bom::sniff(b"abc"), None);
+    /// ```
+    pub fn sniff<B: AsRef<[u8]>>(bytes: B) -> Option<Bom> {
+        // UTF-32LE's mark starts w/ UTF-16LE's so it's checked first
+        match bytes.as_ref() {
+            [0xEF, 0xBB, 0xBF, ..] => Some(Bom::Utf8),
+            [0xFF, 0xFE, 0x00, 0x00, ..] => Some(Bom::Utf32LE),
+            [0x00, 0x00, 0xFE, 0xFF, ..] => Some(Bom::Utf32BE),
+            [0xFF, 0xFE, ..] => Some(Bom::Utf16LE),
+            [0xFE, 0xFF, ..] => Some(Bom::Utf16BE),
+            _ => None,
+        }
+    }
+
+    /// Returns the byte order mark's bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::Bom;
+    /// assert_eq!(Bom::Utf16BE.as_bytes(), &[0xFE, 0xFF]);
+    /// ```
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Bom::Utf8 => &[0xEF, 0xBB, 0xBF],
+            Bom::Utf16LE => &[0xFF, 0xFE],
+            Bom::Utf16BE => &[0xFE, 0xFF],
+            Bom::Utf32LE => &[0xFF, 0xFE, 0x00, 0x00],
+            Bom::Utf32BE => &[0x00, 0x00, 0xFE, 0xFF],
+        }
+    }
+
+    /// Returns the byte order mark's length in bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::Bom;
+    /// assert_eq!(Bom::Utf8.len(), 3);
+    /// ```
+    #[allow(clippy::len_without_is_empty)] // byte order marks are never empty
+    pub const fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns the name of the encoding the byte order mark indicates (e.g.
+    /// `"UTF-16LE"`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::Bom;
+    /// assert_eq!(Bom::Utf32BE.encoding(), "UTF-32BE");
+    /// ```
+    pub const fn encoding(&self) -> &'static str {
+        match self {
+            Bom::Utf8 => "UTF-8",
+            Bom::Utf16LE => "UTF-16LE",
+            Bom::Utf16BE => "UTF-16BE",
+            Bom::Utf32LE => "UTF-32LE",
+            Bom::Utf32BE => "UTF-32BE",
+        }
+    }
+}
+
+impl Display for Bom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.encoding())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Bom; 5] = [
+        Bom::Utf8,
+        Bom::Utf16LE,
+        Bom::Utf16BE,
+        Bom::Utf32LE,
+        Bom::Utf32BE,
+    ];
+
+    #[test]
+    fn it_sniffs_every_byte_order_mark() {
+        for bom in ALL {
+            let bytes = [bom.as_bytes(), b"abc"].concat();
+            assert_eq!(Bom::sniff(&bytes), Some(bom.clone()));
+            assert_eq!(Bom::sniff(bom.as_bytes()), Some(bom));
+        }
+    }
+
+    #[test]
+    fn it_sniffs_nothing_when_there_is_no_byte_order_mark() {
+        assert_eq!(Bom::sniff(b""), None);
+        assert_eq!(Bom::sniff(b"abc"), None);
+        assert_eq!(Bom::sniff([0xEF, 0xBB]), None);
+        assert_eq!(Bom::sniff([0x00, 0x00, 0xFE]), None);
+    }
+
+    #[test]
+    fn it_exposes_byte_order_mark_details() {
+        assert_eq!(Bom::Utf8.len(), 3);
+        assert_eq!(Bom::Utf16LE.len(), 2);
+        assert_eq!(Bom::Utf16BE.len(), 2);
+        assert_eq!(Bom::Utf32LE.len(), 4);
+        assert_eq!(Bom::Utf32BE.len(), 4);
+        assert_eq!(Bom::Utf8.encoding(), "UTF-8");
+        assert_eq!(format!("{}", Bom::Utf16LE), "UTF-16LE");
+    }
+}
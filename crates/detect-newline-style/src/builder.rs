@@ -0,0 +1,430 @@
+use crate::unicode::scan;
+use crate::{LineEndingDetector, UnicodeLineEnding};
+use std::io::{self, BufRead, Read};
+
+/// How [`Detector`](crate::Detector) settles a tie between the most common
+/// line endings
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TieBreak {
+    /// Use the detector's default line ending
+    #[default]
+    Default,
+    /// Use the style of the first line ending found - if it's among those
+    /// tied, otherwise the default
+    First,
+    /// Use the style of the last line ending found - if it's among those
+    /// tied, otherwise the default
+    Last,
+}
+
+/// Determines which line ending content uses based on a bundle of settings -
+/// default line ending, tie-break strategy, scan limits, and whether the
+/// Unicode line separators (NEL, LS, PS, VT, FF) are recognized.
+///
+/// Results are reported as [`UnicodeLineEnding`](crate::UnicodeLineEnding)
+/// values - unless Unicode mode is enabled (or a Unicode default is used)
+/// they're always CR, LF, or CRLF and convert to
+/// [`LineEnding`](crate::LineEnding) via `try_from()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use detect_newline_style::{Detector, LineEnding, TieBreak};
+/// let mut detector = Detector::new();
+/// detector.default_ending(LineEnding::CRLF).tie_break(TieBreak::Last).max_breaks(100);
+/// let eol = detector.detect_str("one\ntwo\r\nthree\r\nfour\n");
+/// assert_eq!(LineEnding::try_from(eol).unwrap(), LineEnding::LF);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Detector {
+    default: UnicodeLineEnding,
+    tie_break: TieBreak,
+    max_breaks: Option<usize>,
+    max_bytes: Option<u64>,
+    unicode: bool,
+}
+
+impl Detector {
+    /// Creates a new detector using default settings - LF as the default,
+    /// ties settled by the default, no scan limits, and Unicode mode disabled
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let detector = Detector::new();
+    /// assert_eq!(detector.detect_str("one\rtwo\n"), UnicodeLineEnding::LF);
+    /// ```
+    pub fn new() -> Self {
+        Detector::default()
+    }
+
+    /// Sets the line ending to use when content has no preference
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The default line ending - either a [`LineEnding`](crate::LineEnding) or a [`UnicodeLineEnding`](crate::UnicodeLineEnding)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, LineEnding, UnicodeLineEnding};
+    /// let mut detector = Detector::new();
+    /// detector.default_ending(LineEnding::CR);
+    /// assert_eq!(detector.detect_str("one"), UnicodeLineEnding::CR);
+    /// ```
+    pub fn default_ending<E: Into<UnicodeLineEnding>>(&mut self, default: E) -> &mut Self {
+        self.default = default.into();
+        self
+    }
+
+    /// Sets how ties between the most common line endings are settled
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_break` - The tie-break strategy - see: [`TieBreak`](crate::TieBreak)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, TieBreak, UnicodeLineEnding};
+    /// let mut detector = Detector::new();
+    /// detector.tie_break(TieBreak::First);
+    /// assert_eq!(detector.detect_str("one\rtwo\n"), UnicodeLineEnding::CR);
+    /// ```
+    pub fn tie_break(&mut self, tie_break: TieBreak) -> &mut Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Stops scanning once the given number of line endings have been found
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of line endings to count
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let mut detector = Detector::new();
+    /// detector.max_breaks(2);
+    /// assert_eq!(detector.detect_str("one\r\ntwo\r\nthree\nfour\nfive\n"), UnicodeLineEnding::CRLF);
+    /// ```
+    pub fn max_breaks(&mut self, max: usize) -> &mut Self {
+        self.max_breaks = Some(max);
+        self
+    }
+
+    /// Stops scanning once the given number of bytes have been read
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of bytes to scan
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let mut detector = Detector::new();
+    /// detector.max_bytes(10);
+    /// assert_eq!(detector.detect_str("one\r\ntwo\r\nthree\nfour\nfive\n"), UnicodeLineEnding::CRLF);
+    /// ```
+    pub fn max_bytes(&mut self, max: u64) -> &mut Self {
+        self.max_bytes = Some(max);
+        self
+    }
+
+    /// Sets whether the Unicode line separators (NEL, LS, PS) plus vertical
+    /// tab and form feed are recognized - content is decoded as UTF-8 with
+    /// invalid sequences ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether Unicode mode is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let mut detector = Detector::new();
+    /// detector.unicode(true);
+    /// assert_eq!(detector.detect_str("one\u{2028}two\u{2028}three\n"), UnicodeLineEnding::LS);
+    /// ```
+    pub fn unicode(&mut self, enabled: bool) -> &mut Self {
+        self.unicode = enabled;
+        self
+    }
+
+    /// Determines which line ending a given string uses
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let eol = Detector::new().detect_str("one\r\ntwo\r\nthree\n");
+    /// assert_eq!(eol, UnicodeLineEnding::CRLF);
+    /// ```
+    pub fn detect_str<S: AsRef<str>>(&self, text: S) -> UnicodeLineEnding {
+        self.detect_bytes(text.as_ref())
+    }
+
+    /// Determines which line ending the given bytes use - handy when content
+    /// may not be valid UTF-8
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The content you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let eol = Detector::new().detect_bytes(b"one\r\ntwo\xff\r\nthree\n");
+    /// assert_eq!(eol, UnicodeLineEnding::CRLF);
+    /// ```
+    pub fn detect_bytes<B: AsRef<[u8]>>(&self, bytes: B) -> UnicodeLineEnding {
+        let mut bytes = bytes.as_ref();
+
+        if !self.unicode {
+            let mut detector = self.detector();
+            detector.feed(bytes);
+            return self.resolve_detector(&detector);
+        }
+
+        if let Some(max) = self.max_bytes {
+            bytes = &bytes[..bytes.len().min(max as usize)];
+        }
+
+        let result = scan(&String::from_utf8_lossy(bytes), self.max_breaks);
+        self.resolve(result.counts, result.first, result.last)
+    }
+
+    /// Determines which line ending content from the given reader uses - in
+    /// Unicode mode content is read in full (up to `max_bytes`) before being
+    /// scanned
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The buffered reader (e.g. `BufReader<File>`) you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Detector, UnicodeLineEnding};
+    /// let eol = Detector::new().detect_reader("one\rtwo\rthree\n".as_bytes()).unwrap();
+    /// assert_eq!(eol, UnicodeLineEnding::CR);
+    /// ```
+    pub fn detect_reader<R: BufRead>(&self, reader: R) -> io::Result<UnicodeLineEnding> {
+        if !self.unicode {
+            let mut detector = self.detector();
+            detector.feed_reader(reader)?;
+            return Ok(self.resolve_detector(&detector));
+        }
+
+        let mut bytes = Vec::new();
+
+        match self.max_bytes {
+            Some(max) => reader.take(max).read_to_end(&mut bytes)?,
+            None => { reader }.read_to_end(&mut bytes)?,
+        };
+
+        Ok(self.detect_bytes(bytes))
+    }
+
+    fn detector(&self) -> LineEndingDetector {
+        let mut detector = LineEndingDetector::default();
+
+        if let Some(max) = self.max_breaks {
+            detector.max_breaks(max);
+        }
+
+        if let Some(max) = self.max_bytes {
+            detector.max_bytes(max);
+        }
+
+        detector
+    }
+
+    fn resolve_detector(&self, detector: &LineEndingDetector) -> UnicodeLineEnding {
+        let stats = detector.stats();
+        let mut counts = [0; 8];
+
+        counts[UnicodeLineEnding::CR.index()] = stats.cr;
+        counts[UnicodeLineEnding::LF.index()] = stats.lf;
+        counts[UnicodeLineEnding::CRLF.index()] = stats.crlf;
+
+        self.resolve(
+            counts,
+            detector.first().map(UnicodeLineEnding::from),
+            detector.last().map(UnicodeLineEnding::from),
+        )
+    }
+
+    // picks the line ending w/ the most occurrences - ties are settled via
+    // the tie-break strategy
+    fn resolve(
+        &self,
+        counts: [usize; 8],
+        first: Option<UnicodeLineEnding>,
+        last: Option<UnicodeLineEnding>,
+    ) -> UnicodeLineEnding {
+        let max = counts.iter().copied().max().unwrap_or_default();
+
+        if max == 0 {
+            return self.default.clone();
+        }
+
+        if counts.iter().filter(|n| **n == max).count() == 1 {
+            if let Some(idx) = counts.iter().position(|n| *n == max) {
+                return UnicodeLineEnding::ALL[idx].clone();
+            }
+        }
+
+        let eol = match self.tie_break {
+            TieBreak::Default => None,
+            TieBreak::First => first,
+            TieBreak::Last => last,
+        };
+
+        match eol {
+            Some(eol) if counts[eol.index()] == max => eol,
+            _ => self.default.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LineEnding;
+
+    #[test]
+    fn it_initializes() {
+        let detector = Detector::new();
+        assert_eq!(detector.default, UnicodeLineEnding::LF);
+        assert_eq!(detector.tie_break, TieBreak::Default);
+        assert_eq!((detector.max_breaks, detector.max_bytes), (None, None));
+        assert!(!detector.unicode);
+    }
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        assert_eq!(Detector::default(), Detector::new());
+        assert_eq!(TieBreak::default(), TieBreak::Default);
+    }
+
+    #[test]
+    fn it_sets_options() {
+        let mut detector = Detector::new();
+        detector
+            .default_ending(LineEnding::CRLF)
+            .tie_break(TieBreak::Last)
+            .max_breaks(10)
+            .max_bytes(100)
+            .unicode(true);
+        assert_eq!(detector.default, UnicodeLineEnding::CRLF);
+        assert_eq!(detector.tie_break, TieBreak::Last);
+        assert_eq!(
+            (detector.max_breaks, detector.max_bytes),
+            (Some(10), Some(100))
+        );
+        assert!(detector.unicode);
+    }
+
+    #[test]
+    fn it_detects_line_endings() {
+        let detector = Detector::new();
+        assert_eq!(
+            detector.detect_str("one\ntwo\r\nthree\n"),
+            UnicodeLineEnding::LF
+        );
+        assert_eq!(
+            detector.detect_str("one\rtwo\r\nthree\r"),
+            UnicodeLineEnding::CR
+        );
+        assert_eq!(
+            detector.detect_bytes(b"one\r\n\xfftwo\r\n"),
+            UnicodeLineEnding::CRLF
+        );
+        // unicode separators are ignored unless unicode mode is enabled
+        assert_eq!(
+            detector.detect_str("one\u{85}two\u{85}three\n"),
+            UnicodeLineEnding::LF
+        );
+    }
+
+    #[test]
+    fn it_uses_default_when_preference_cannot_be_determined() {
+        let mut detector = Detector::new();
+        detector.default_ending(UnicodeLineEnding::NEL);
+        assert_eq!(detector.detect_str(""), UnicodeLineEnding::NEL);
+        assert_eq!(detector.detect_str("one\rtwo\n"), UnicodeLineEnding::NEL);
+        assert_eq!(
+            detector.unicode(true).detect_str("one"),
+            UnicodeLineEnding::NEL
+        );
+    }
+
+    #[test]
+    fn it_settles_ties_using_tie_break_strategy() {
+        let input = "one\ntwo\r\nthree\r\nfour\n\r";
+        let mut detector = Detector::new();
+        detector.default_ending(LineEnding::CR);
+        assert_eq!(detector.detect_str(input), UnicodeLineEnding::CR);
+        detector.tie_break(TieBreak::First);
+        assert_eq!(detector.detect_str(input), UnicodeLineEnding::LF);
+        assert_eq!(detector.detect_str("one\r\ntwo\n"), UnicodeLineEnding::CRLF);
+        // the last line ending (CR) isn't among those tied
+        detector.tie_break(TieBreak::Last);
+        assert_eq!(detector.detect_str(input), UnicodeLineEnding::CR);
+        assert_eq!(detector.detect_str("one\r\ntwo\n"), UnicodeLineEnding::LF);
+        detector.unicode(true);
+        assert_eq!(
+            detector.detect_str("one\u{2029}two\u{b}"),
+            UnicodeLineEnding::VT
+        );
+    }
+
+    #[test]
+    fn it_stops_scanning_at_limits() {
+        let input = "one\u{85}two\u{85}three\nfour\nfive\nsix\n";
+        let mut detector = Detector::new();
+        detector.unicode(true).max_breaks(2);
+        assert_eq!(detector.detect_str(input), UnicodeLineEnding::NEL);
+
+        let mut detector = Detector::new();
+        detector.unicode(true).max_bytes(12);
+        assert_eq!(detector.detect_str(input), UnicodeLineEnding::NEL);
+
+        let mut detector = Detector::new();
+        detector.max_bytes(4);
+        assert_eq!(
+            detector.detect_str("one\rtwo\nthree\n"),
+            UnicodeLineEnding::CR
+        );
+    }
+
+    #[test]
+    fn it_detects_line_endings_from_reader() {
+        let input = "one\u{2028}two\u{2028}three\r\nfour\r\nfive\r\n";
+        let mut detector = Detector::new();
+        let eol = detector.detect_reader(input.as_bytes()).unwrap();
+        assert_eq!(eol, UnicodeLineEnding::CRLF);
+
+        detector.unicode(true).max_bytes(16);
+        let eol = detector.detect_reader(input.as_bytes()).unwrap();
+        assert_eq!(eol, UnicodeLineEnding::LS);
+
+        detector.max_breaks(2);
+        let reader = std::io::BufReader::with_capacity(4, input.as_bytes());
+        assert_eq!(
+            detector.detect_reader(reader).unwrap(),
+            UnicodeLineEnding::LS
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{normalize_chunk, LineEnding};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+
+// how many bytes are read from the source at a time
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// How many bytes [`convert()`](crate::convert) read and wrote
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ConversionStats {
+    /// Number of bytes read from the source
+    pub bytes_in: u64,
+    /// Number of bytes written to the destination
+    pub bytes_out: u64,
+}
+
+/// Converts every line ending (CR, LF, or CRLF) read from the given reader to
+/// the given style, writing the result to the given writer - content is
+/// streamed in fixed-size chunks so memory use stays constant regardless of
+/// input size. CRLF pairs split across chunks are converted once.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the content you want to convert (e.g. `File`)
+/// * `writer` - The destination for converted content (e.g. `BufWriter<File>`)
+/// * `to` - The line ending style to convert to
+///
+/// # Examples
+///
+/// ```rust
+/// use detect_newline_style::{convert, LineEnding};
+/// let mut out = Vec::new();
+/// let stats = convert("one\ntwo\rthree\r\n".as_bytes(), &mut out, LineEnding::CRLF).unwrap();
+/// assert_eq!(out, b"one\r\ntwo\r\nthree\r\n");
+/// assert_eq!((stats.bytes_in, stats.bytes_out), (15, 17));
+/// ```
+pub fn convert<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    to: LineEnding,
+) -> io::Result<ConversionStats> {
+    let mut stats = ConversionStats::default();
+    let mut buf = vec![0; CHUNK_SIZE];
+    let mut out = Vec::with_capacity(CHUNK_SIZE * 2);
+    let mut after_cr = false;
+
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        out.clear();
+        normalize_chunk(&to, &mut after_cr, &buf[..len], &mut out);
+        writer.write_all(&out)?;
+        stats.bytes_in += len as u64;
+        stats.bytes_out += out.len() as u64;
+    }
+
+    writer.flush()?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // hands out content a few bytes at a time
+    struct Trickle<'a>(&'a [u8], usize);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.0.len().min(self.1).min(buf.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn it_converts_line_endings() {
+        let input = "\rünï\r\ncödé\n\n\r\r\nend";
+
+        for eol in LineEnding::ALL {
+            let mut out = Vec::new();
+            let stats = convert(input.as_bytes(), &mut out, eol).unwrap();
+            let expected = eol.normalize(input);
+            assert_eq!(String::from_utf8(out).unwrap(), expected);
+            assert_eq!(stats.bytes_in, input.len() as u64);
+            assert_eq!(stats.bytes_out, expected.len() as u64);
+        }
+    }
+
+    #[test]
+    fn it_converts_crlf_split_across_reads() {
+        let input = "one\r\ntwo\r\nthree\rfour\n";
+
+        for size in 1..input.len() {
+            let mut out = Vec::new();
+            convert(Trickle(input.as_bytes(), size), &mut out, LineEnding::LF).unwrap();
+            assert_eq!(out, b"one\ntwo\nthree\nfour\n", "size: {}", size);
+        }
+    }
+
+    #[test]
+    fn it_converts_large_input() {
+        let input = "one\r\ntwo\n".repeat(CHUNK_SIZE);
+        let mut out = Vec::new();
+        let stats = convert(input.as_bytes(), &mut out, LineEnding::CRLF).unwrap();
+        assert_eq!(out, "one\r\ntwo\r\n".repeat(CHUNK_SIZE).as_bytes());
+        assert_eq!(stats.bytes_in, input.len() as u64);
+        assert_eq!(stats.bytes_out, (input.len() + CHUNK_SIZE) as u64);
+    }
+
+    #[test]
+    fn it_converts_empty_input() {
+        let mut out = Vec::new();
+        let stats = convert(io::empty(), &mut out, LineEnding::CR).unwrap();
+        assert_eq!(stats, ConversionStats::default());
+        assert!(out.is_empty());
+    }
+}
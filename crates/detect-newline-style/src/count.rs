@@ -0,0 +1,83 @@
+// bytes per block - u8 per-lane counters can't overflow w/in a block
+const BLOCK: usize = 255;
+
+// counts `\r` bytes, `\n` bytes, and `\r\n` pairs - each count is a
+// branch-free sum over a small block of bytes which the compiler vectorizes so
+// throughput doesn't depend on how many line endings the input has
+pub(crate) fn count(bytes: &[u8]) -> (usize, usize, usize) {
+    let mut cr = 0;
+    let mut lf = 0;
+    let mut crlf = 0;
+    let mut last = 0u8;
+
+    for block in bytes.chunks(BLOCK) {
+        let (r, n, rn) = count_block(block);
+        cr += usize::from(r);
+        lf += usize::from(n);
+        crlf += usize::from(rn);
+        // a pair split across blocks
+        crlf += usize::from(last == b'\r' && block[0] == b'\n');
+        last = block[block.len() - 1];
+    }
+
+    (cr, lf, crlf)
+}
+
+fn count_block(block: &[u8]) -> (u8, u8, u8) {
+    let mut cr = 0u8;
+    let mut lf = 0u8;
+    let mut crlf = 0u8;
+
+    for (a, b) in block.iter().zip(&block[1..]) {
+        let is_cr = u8::from(*a == b'\r');
+        cr += is_cr;
+        lf += u8::from(*a == b'\n');
+        crlf += is_cr & u8::from(*b == b'\n');
+    }
+
+    match block.last() {
+        Some(b'\r') => (cr + 1, lf, crlf),
+        Some(b'\n') => (cr, lf + 1, crlf),
+        _ => (cr, lf, crlf),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(bytes: &[u8]) -> (usize, usize, usize) {
+        let cr = bytes.iter().filter(|b| **b == b'\r').count();
+        let lf = bytes.iter().filter(|b| **b == b'\n').count();
+        let crlf = bytes.windows(2).filter(|w| w == b"\r\n").count();
+        (cr, lf, crlf)
+    }
+
+    #[test]
+    fn it_counts_line_ending_bytes() {
+        assert_eq!(count(b""), (0, 0, 0));
+        assert_eq!(count(b"no line breaks"), (0, 0, 0));
+        assert_eq!(count(b"\rone\r\ntwo\n\n\r\r\n"), (4, 4, 2));
+    }
+
+    #[test]
+    fn it_counts_pairs_split_across_blocks() {
+        let mut input = vec![b'x'; BLOCK * 3];
+        input[BLOCK - 1] = b'\r';
+        input[BLOCK] = b'\n';
+        input[BLOCK * 2 - 1] = b'\r';
+        input[BLOCK * 3 - 1] = b'\n';
+        assert_eq!(count(&input), (2, 2, 1));
+        assert_eq!(count(&input), naive(&input));
+    }
+
+    #[test]
+    fn it_counts_same_as_naive_scan() {
+        let input = "one\rtwo\r\nthree\nfour\r\r\n\n".repeat(1000);
+
+        for len in [0, 1, 7, BLOCK - 1, BLOCK, BLOCK + 1, BLOCK * 2, input.len()] {
+            let bytes = &input.as_bytes()[..len];
+            assert_eq!(count(bytes), naive(bytes), "len: {}", len);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Bom, LineEnding, LineEndingStats};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The result of analyzing some text - see [`LineEnding::detect()`](crate::LineEnding::detect)
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Detection {
+    /// The dominant line ending style - `None` when the text has no line
+    /// endings or two styles are tied
+    pub ending: Option<LineEnding>,
+    /// The dominant style's share of all line endings (`0.0` - `1.0`) - e.g.
+    /// `0.55` for a file w/ 55 LF and 45 CRLF endings
+    pub confidence: f64,
+    /// Counts of each line ending style
+    pub counts: LineEndingStats,
+}
+
+impl Detection {
+    pub(crate) fn new(counts: LineEndingStats) -> Detection {
+        let breaks = counts.breaks();
+        let max = counts.cr.max(counts.lf).max(counts.crlf);
+        let ending = match (counts.cr == max, counts.lf == max, counts.crlf == max) {
+            _ if breaks == 0 => None,
+            (true, false, false) => Some(LineEnding::CR),
+            (false, true, false) => Some(LineEnding::LF),
+            (false, false, true) => Some(LineEnding::CRLF),
+            _ => None,
+        };
+        let confidence = match breaks {
+            0 => 0.0,
+            _ => max as f64 / breaks as f64,
+        };
+
+        Detection {
+            ending,
+            confidence,
+            counts,
+        }
+    }
+}
+
+/// The result of analyzing raw content - see [`LineEnding::analyze()`](crate::LineEnding::analyze)
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Analysis {
+    /// The byte order mark the content starts with (if any)
+    pub bom: Option<Bom>,
+    /// Which line ending style the content uses
+    pub detection: Detection,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(cr: usize, lf: usize, crlf: usize) -> LineEndingStats {
+        LineEndingStats {
+            cr,
+            lf,
+            crlf,
+            lines: cr + lf + crlf,
+        }
+    }
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        let detection = Detection::default();
+        assert_eq!(detection.ending, None);
+        assert_eq!(detection.confidence, 0.0);
+        assert_eq!(detection.counts, LineEndingStats::default());
+    }
+
+    #[test]
+    fn it_finds_dominant_line_ending_and_its_share() {
+        let detection = Detection::new(counts(0, 55, 45));
+        assert_eq!(detection.ending, Some(LineEnding::LF));
+        assert_eq!(detection.confidence, 0.55);
+
+        let detection = Detection::new(counts(1, 1, 2));
+        assert_eq!(detection.ending, Some(LineEnding::CRLF));
+        assert_eq!(detection.confidence, 0.5);
+
+        let detection = Detection::new(counts(3, 0, 0));
+        assert_eq!(detection.ending, Some(LineEnding::CR));
+        assert_eq!(detection.confidence, 1.0);
+    }
+
+    #[test]
+    fn it_has_no_dominant_line_ending_when_tied() {
+        let detection = Detection::new(counts(0, 2, 2));
+        assert_eq!(detection.ending, None);
+        assert_eq!(detection.confidence, 0.5);
+    }
+
+    #[test]
+    fn it_has_no_dominant_line_ending_when_there_are_none() {
+        let detection = Detection::new(counts(0, 0, 0));
+        assert_eq!(detection.ending, None);
+        assert_eq!(detection.confidence, 0.0);
+    }
+}
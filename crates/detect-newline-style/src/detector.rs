@@ -0,0 +1,584 @@
+use crate::count::count;
+use crate::{preferred, LineEnding, LineEndingStats};
+use memchr::{memchr2, memchr2_iter, memrchr2};
+use std::io::{self, BufRead};
+
+// chunks at least this large are counted in bulk
+const BULK_THRESHOLD: usize = 1024;
+
+/// Incrementally determines which newline style (CR, LF, or CRLF) a stream of
+/// bytes uses - feed it chunks as they arrive (e.g. from a socket or
+/// decompressor) then call [`finish()`](crate::LineEndingDetector::finish).
+/// A CRLF pair split across two chunks is counted once.
+///
+/// Scanning can be bounded via [`max_breaks()`](crate::LineEndingDetector::max_breaks)
+/// and [`max_bytes()`](crate::LineEndingDetector::max_bytes) - once a limit is
+/// reached further content is ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineEndingDetector {
+    default: LineEnding,
+    cr: usize,
+    lf: usize,
+    crlf: usize,
+    // a trailing `\r` is held back until the next chunk shows whether it
+    // starts a CRLF pair
+    pending_cr: bool,
+    // whether content follows the last line ending seen
+    open_line: bool,
+    max_breaks: Option<usize>,
+    max_bytes: Option<u64>,
+    // bytes scanned so far
+    seen: u64,
+    // a scan limit was reached
+    done: bool,
+    // styles of the first and last line endings seen
+    first: Option<LineEnding>,
+    last: Option<LineEnding>,
+}
+
+impl LineEndingDetector {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The default newline style to use when content has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingDetector};
+    /// let detector = LineEndingDetector::new(LineEnding::CRLF);
+    /// assert_eq!(detector.finish(), LineEnding::CRLF);
+    /// ```
+    pub fn new(default: LineEnding) -> LineEndingDetector {
+        LineEndingDetector {
+            default,
+            ..Default::default()
+        }
+    }
+
+    /// Stops scanning once the given number of line endings have been found -
+    /// for detection purposes the first hundred or so are almost always
+    /// representative
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of line endings to count
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingDetector};
+    /// let mut detector = LineEndingDetector::new(LineEnding::LF);
+    /// detector.max_breaks(2).feed("one\r\ntwo\r\nthree\nfour\nfive\n");
+    /// assert!(detector.is_done());
+    /// assert_eq!(detector.finish(), LineEnding::CRLF);
+    /// ```
+    pub fn max_breaks(&mut self, max: usize) -> &mut Self {
+        self.max_breaks = Some(max);
+        self.done = self.done || self.breaks() >= max;
+        self
+    }
+
+    /// Stops scanning once the given number of bytes have been fed - a `\r`
+    /// ending exactly at the limit counts as CR
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The maximum number of bytes to scan
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingDetector};
+    /// let mut detector = LineEndingDetector::new(LineEnding::LF);
+    /// detector.max_bytes(10).feed("one\r\ntwo\r\nthree\nfour\nfive\n");
+    /// assert!(detector.is_done());
+    /// assert_eq!(detector.finish(), LineEnding::CRLF);
+    /// ```
+    pub fn max_bytes(&mut self, max: u64) -> &mut Self {
+        self.max_bytes = Some(max);
+        self.done = self.done || self.seen >= max;
+        self
+    }
+
+    /// Whether a scan limit was reached - further content is ignored so
+    /// callers can stop reading
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEndingDetector;
+    /// let mut detector = LineEndingDetector::default();
+    /// detector.max_breaks(1);
+    /// assert!(!detector.is_done());
+    /// detector.feed("one\ntwo");
+    /// assert!(detector.is_done());
+    /// ```
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Scans the next chunk of bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The chunk of content you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingDetector};
+    /// let mut detector = LineEndingDetector::new(LineEnding::LF);
+    /// detector.feed(b"one\r").feed(b"\ntwo\r").feed(b"\nthree\n");
+    /// assert_eq!(detector.finish(), LineEnding::CRLF);
+    /// ```
+    pub fn feed<B: AsRef<[u8]>>(&mut self, bytes: B) -> &mut Self {
+        let mut bytes = bytes.as_ref();
+
+        if self.done {
+            return self;
+        }
+
+        if let Some(max) = self.max_bytes {
+            let remaining = max - self.seen;
+
+            if bytes.len() as u64 >= remaining {
+                bytes = &bytes[..remaining as usize];
+                self.done = true;
+            }
+        }
+
+        if bytes.len() >= BULK_THRESHOLD && self.max_breaks.is_none() {
+            self.feed_bulk(bytes);
+            self.seen += bytes.len() as u64;
+        } else {
+            self.seen += self.feed_sparse(bytes) as u64;
+        }
+
+        self
+    }
+
+    /// Scans content from the given reader until it's exhausted or a scan
+    /// limit is reached
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The buffered reader (e.g. `BufReader<File>`) you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingDetector};
+    /// let mut detector = LineEndingDetector::new(LineEnding::LF);
+    /// detector.feed_reader("one\r\ntwo\r\n".as_bytes()).unwrap();
+    /// assert_eq!(detector.finish(), LineEnding::CRLF);
+    /// ```
+    pub fn feed_reader<R: BufRead>(&mut self, mut reader: R) -> io::Result<&mut Self> {
+        while !self.done {
+            let buf = match reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            if buf.is_empty() {
+                break;
+            }
+
+            let len = buf.len();
+            self.feed(buf);
+            reader.consume(len);
+        }
+
+        Ok(self)
+    }
+
+    // jumps from one line ending to the next - cheapest for small chunks.
+    // returns how many bytes were scanned before reaching `max_breaks`
+    fn feed_sparse(&mut self, bytes: &[u8]) -> usize {
+        let mut start = 0;
+
+        for idx in memchr2_iter(b'\r', b'\n', bytes) {
+            if idx > start {
+                self.content();
+            }
+
+            if self.reached_max_breaks() {
+                self.done = true;
+                return idx;
+            }
+
+            if bytes[idx] == b'\r' {
+                if self.pending_cr {
+                    self.cr += 1;
+                    self.record(LineEnding::CR);
+                }
+                // a `\r` past the limit is dropped
+                self.pending_cr = !self.reached_max_breaks();
+            } else if self.pending_cr {
+                self.crlf += 1;
+                self.pending_cr = false;
+                self.record(LineEnding::CRLF);
+            } else {
+                self.lf += 1;
+                self.record(LineEnding::LF);
+            }
+
+            self.open_line = false;
+            start = idx + 1;
+
+            if self.reached_max_breaks() {
+                self.done = true;
+                return start;
+            }
+        }
+
+        if start < bytes.len() {
+            self.content();
+            self.done = self.done || self.reached_max_breaks();
+        }
+
+        bytes.len()
+    }
+
+    fn breaks(&self) -> usize {
+        self.cr + self.lf + self.crlf
+    }
+
+    fn reached_max_breaks(&self) -> bool {
+        match self.max_breaks {
+            Some(max) => self.breaks() >= max,
+            None => false,
+        }
+    }
+
+    // counts every line ending byte in one vectorized pass then fixes up the
+    // chunk's edges - cheapest for large chunks w/ many line endings
+    fn feed_bulk(&mut self, bytes: &[u8]) {
+        let mut rest = bytes;
+
+        if self.pending_cr {
+            if rest[0] == b'\n' {
+                self.crlf += 1;
+                rest = &rest[1..];
+                self.record(LineEnding::CRLF);
+            } else {
+                self.cr += 1;
+                self.record(LineEnding::CR);
+            }
+            self.pending_cr = false;
+        }
+
+        let (mut cr, lf, crlf) = count(rest);
+        let mut body = rest;
+
+        // hold back a trailing `\r` until the next chunk
+        if rest.last() == Some(&b'\r') {
+            cr -= 1;
+            self.pending_cr = true;
+            body = &rest[..rest.len() - 1];
+        }
+
+        if self.first.is_none() {
+            if let Some(idx) = memchr2(b'\r', b'\n', body) {
+                self.record(match (body[idx], rest.get(idx + 1)) {
+                    (b'\n', _) => LineEnding::LF,
+                    (_, Some(b'\n')) => LineEnding::CRLF,
+                    _ => LineEnding::CR,
+                });
+            }
+        }
+
+        if let Some(idx) = memrchr2(b'\r', b'\n', body) {
+            self.last = Some(match body[idx] {
+                b'\r' => LineEnding::CR,
+                _ if idx > 0 && body[idx - 1] == b'\r' => LineEnding::CRLF,
+                _ => LineEnding::LF,
+            });
+        }
+
+        self.cr += cr - crlf;
+        self.lf += lf - crlf;
+        self.crlf += crlf;
+        self.open_line = !matches!(bytes.last(), Some(b'\r' | b'\n'));
+    }
+
+    // non-line-ending content ends any pending `\r` as a lone CR
+    fn content(&mut self) {
+        if self.pending_cr {
+            self.cr += 1;
+            self.pending_cr = false;
+            self.record(LineEnding::CR);
+        }
+        self.open_line = true;
+    }
+
+    fn record(&mut self, eol: LineEnding) {
+        self.first.get_or_insert(eol);
+        self.last = Some(eol);
+    }
+
+    // style of the first line ending fed so far - a pending `\r` counts as CR
+    pub(crate) fn first(&self) -> Option<LineEnding> {
+        match (self.first, self.pending_cr) {
+            (Some(eol), _) => Some(eol),
+            (None, true) => Some(LineEnding::CR),
+            (None, false) => None,
+        }
+    }
+
+    // style of the last line ending fed so far - a pending `\r` counts as CR
+    pub(crate) fn last(&self) -> Option<LineEnding> {
+        match self.pending_cr {
+            true => Some(LineEnding::CR),
+            false => self.last,
+        }
+    }
+
+    /// Determines which newline style the content fed so far prefers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingDetector};
+    /// let mut detector = LineEndingDetector::new(LineEnding::LF);
+    /// detector.feed("one\rtwo\r");
+    /// assert_eq!(detector.finish(), LineEnding::CR);
+    /// ```
+    pub fn finish(self) -> LineEnding {
+        let stats = self.stats();
+        preferred(stats.cr, stats.lf, stats.crlf, self.default)
+    }
+
+    /// Counts each line ending style found in the content fed so far - a
+    /// pending `\r` at the end of the last chunk counts as CR
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEndingDetector;
+    /// let mut detector = LineEndingDetector::default();
+    /// detector.feed("one\r").feed("\ntwo\nthree");
+    /// let stats = detector.stats();
+    /// assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (0, 1, 1, 3));
+    /// ```
+    pub fn stats(&self) -> LineEndingStats {
+        let cr = self.cr + usize::from(self.pending_cr);
+        let breaks = cr + self.lf + self.crlf;
+
+        LineEndingStats {
+            cr,
+            lf: self.lf,
+            crlf: self.crlf,
+            lines: breaks + usize::from(self.open_line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes() {
+        let detector = LineEndingDetector::new(LineEnding::CR);
+        assert_eq!(detector.default, LineEnding::CR);
+        assert_eq!((detector.cr, detector.lf, detector.crlf), (0, 0, 0));
+        assert!(!detector.pending_cr);
+    }
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        let detector = LineEndingDetector::default();
+        assert_eq!(detector, LineEndingDetector::new(LineEnding::LF));
+    }
+
+    #[test]
+    fn it_counts_line_endings() {
+        let mut detector = LineEndingDetector::default();
+        detector.feed("one\rtwo\r\nthree\nfour\r\r\n");
+        assert_eq!((detector.cr, detector.lf, detector.crlf), (2, 1, 2));
+        assert!(!detector.pending_cr);
+    }
+
+    #[test]
+    fn it_counts_crlf_split_across_chunks() {
+        let mut detector = LineEndingDetector::default();
+        detector.feed("one\r");
+        assert!(detector.pending_cr);
+        detector.feed("\ntwo\r").feed("\r").feed("\n");
+        assert_eq!((detector.cr, detector.lf, detector.crlf), (1, 0, 2));
+        assert_eq!(detector.finish(), LineEnding::CRLF);
+    }
+
+    #[test]
+    fn it_counts_large_chunks_in_bulk() {
+        let input = "\rthis\r\nis\nmixed\r\r\n\nünïcödé".repeat(BULK_THRESHOLD);
+        let mut sparse = LineEndingDetector::default();
+        let mut bulk = LineEndingDetector::default();
+
+        for chunk in input.as_bytes().chunks(BULK_THRESHOLD - 1) {
+            sparse.feed(chunk);
+        }
+
+        for chunk in input.as_bytes().chunks(BULK_THRESHOLD + 1) {
+            bulk.feed(chunk);
+        }
+
+        assert_eq!(bulk.stats(), sparse.stats());
+        assert_eq!(bulk, sparse);
+    }
+
+    #[test]
+    fn it_counts_crlf_split_across_large_chunks() {
+        let mut chunk = "x".repeat(BULK_THRESHOLD - 1);
+        chunk.push('\r');
+        let mut detector = LineEndingDetector::default();
+        detector.feed(&chunk);
+        assert!(detector.pending_cr);
+        detector.feed(format!("\n{}", chunk));
+        detector.feed(format!("x{}\n", chunk));
+        let stats = detector.stats();
+        assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (1, 0, 2, 3));
+    }
+
+    #[test]
+    fn it_stops_scanning_after_max_breaks() {
+        let mut detector = LineEndingDetector::default();
+        detector.max_breaks(3);
+        detector.feed("one\r\ntwo\r").feed("\nthree\r\r\nfour\n");
+        assert!(detector.is_done());
+        let stats = detector.stats();
+        assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (1, 0, 2, 3));
+
+        detector.feed("five\nsix\n");
+        assert_eq!(detector.stats(), stats);
+        assert_eq!(detector.finish(), LineEnding::CRLF);
+    }
+
+    #[test]
+    fn it_stops_scanning_after_max_breaks_in_large_chunks() {
+        let input = "one\ntwo\r\n".repeat(BULK_THRESHOLD);
+        let mut detector = LineEndingDetector::default();
+        detector.max_breaks(101).feed(&input);
+        let stats = detector.stats();
+        assert_eq!((stats.cr, stats.lf, stats.crlf), (0, 51, 50));
+        assert!(detector.is_done());
+    }
+
+    #[test]
+    fn it_stops_scanning_after_max_bytes() {
+        let mut detector = LineEndingDetector::default();
+        detector.max_bytes(9);
+        detector.feed("one\r\n").feed("two\r\nthree\n");
+        assert!(detector.is_done());
+        // the `\r` ending exactly at the limit counts as CR
+        let stats = detector.stats();
+        assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (1, 0, 1, 2));
+        assert_eq!(detector.seen, 9);
+
+        let input = "\n".repeat(BULK_THRESHOLD * 2);
+        let mut detector = LineEndingDetector::default();
+        detector.max_bytes(BULK_THRESHOLD as u64 + 1).feed(&input);
+        assert_eq!(detector.stats().lf, BULK_THRESHOLD + 1);
+    }
+
+    #[test]
+    fn it_is_done_when_limit_is_already_reached() {
+        let mut detector = LineEndingDetector::default();
+        detector.feed("one\ntwo\n");
+        assert!(!detector.is_done());
+        detector.max_breaks(2);
+        assert!(detector.is_done());
+
+        let mut detector = LineEndingDetector::default();
+        detector.max_bytes(0);
+        assert!(detector.is_done());
+        detector.feed("one\n");
+        assert_eq!(detector.stats(), LineEndingStats::default());
+    }
+
+    #[test]
+    fn it_stops_reading_when_limit_is_reached() {
+        let input = "one\ntwo\n".repeat(100);
+        let mut reader = std::io::BufReader::with_capacity(4, input.as_bytes());
+        let mut detector = LineEndingDetector::default();
+        detector.max_breaks(5).feed_reader(&mut reader).unwrap();
+        assert_eq!(detector.stats().lf, 5);
+        assert!(reader.into_inner().len() > input.len() - 32);
+    }
+
+    #[test]
+    fn it_counts_trailing_cr_when_finished() {
+        let mut detector = LineEndingDetector::default();
+        detector.feed("one\ntwo\r").feed("").feed("three\r");
+        assert_eq!(detector.finish(), LineEnding::CR);
+    }
+
+    #[test]
+    fn it_reports_stats() {
+        let mut detector = LineEndingDetector::default();
+        assert_eq!(detector.stats(), LineEndingStats::default());
+
+        detector.feed("one\rtwo\r\nthree\nfour\r");
+        let stats = detector.stats();
+        assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (2, 1, 1, 4));
+
+        detector.feed("\nfive");
+        let stats = detector.stats();
+        assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (1, 1, 2, 5));
+    }
+
+    #[test]
+    fn it_tracks_first_and_last_line_endings() {
+        let mut detector = LineEndingDetector::default();
+        assert_eq!((detector.first(), detector.last()), (None, None));
+
+        detector.feed("one\r");
+        assert_eq!(detector.first(), Some(LineEnding::CR));
+        assert_eq!(detector.last(), Some(LineEnding::CR));
+
+        detector.feed("\ntwo\nthree");
+        assert_eq!(detector.first(), Some(LineEnding::CRLF));
+        assert_eq!(detector.last(), Some(LineEnding::LF));
+
+        let input = format!("{}\r\n\n\r", "x".repeat(BULK_THRESHOLD));
+        let mut detector = LineEndingDetector::default();
+        detector.feed(&input);
+        assert_eq!(detector.first(), Some(LineEnding::CRLF));
+        assert_eq!(detector.last(), Some(LineEnding::CR));
+        detector.feed("\n");
+        assert_eq!(detector.last(), Some(LineEnding::CRLF));
+    }
+
+    #[test]
+    fn it_uses_default_when_preference_cannot_be_determined() {
+        let mut detector = LineEndingDetector::new(LineEnding::CRLF);
+        detector.feed("one\ntwo\r");
+        assert_eq!(detector.finish(), LineEnding::CRLF);
+
+        let detector = LineEndingDetector::new(LineEnding::CR);
+        assert_eq!(detector.finish(), LineEnding::CR);
+    }
+
+    #[test]
+    fn it_finds_same_line_ending_as_find_regardless_of_chunk_size() {
+        let input = "\r\nthis\r\nprefers\r\nwindows-style endings\n\r\r";
+
+        for size in 1..input.len() {
+            let mut detector = LineEndingDetector::new(LineEnding::LF);
+
+            for chunk in input.as_bytes().chunks(size) {
+                detector.feed(chunk);
+            }
+
+            assert_eq!(
+                detector.finish(),
+                LineEnding::find(input, LineEnding::LF),
+                "size: {}",
+                size
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::LineEndingDetector;
+
+const BATCH: usize = 4 * 1024;
+
+// feeds UTF-16 or UTF-32 encoded bytes (`width` of 2 or 4) to the given
+// detector - each code unit is mapped to a single byte (`\r`, `\n`, or a
+// placeholder) so the regular byte-oriented scanning applies. trailing bytes
+// which don't make up a whole code unit are ignored
+pub(crate) fn feed(
+    detector: &mut LineEndingDetector,
+    bytes: &[u8],
+    width: usize,
+    big_endian: bool,
+) {
+    let mut batch = Vec::with_capacity(BATCH);
+
+    for units in bytes.chunks(BATCH * width) {
+        batch.clear();
+        batch.extend(units.chunks_exact(width).map(|unit| {
+            let unit = match big_endian {
+                true => unit.iter().fold(0u32, |n, b| (n << 8) | u32::from(*b)),
+                false => unit
+                    .iter()
+                    .rev()
+                    .fold(0u32, |n, b| (n << 8) | u32::from(*b)),
+            };
+
+            match unit {
+                0x0D => b'\r',
+                0x0A => b'\n',
+                _ => b'x',
+            }
+        }));
+        detector.feed(&batch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LineEnding;
+
+    fn encode(text: &str, width: usize, big_endian: bool) -> Vec<u8> {
+        match width {
+            2 => text
+                .encode_utf16()
+                .flat_map(|unit| match big_endian {
+                    true => unit.to_be_bytes(),
+                    false => unit.to_le_bytes(),
+                })
+                .collect(),
+            _ => text
+                .chars()
+                .flat_map(|c| match big_endian {
+                    true => u32::from(c).to_be_bytes(),
+                    false => u32::from(c).to_le_bytes(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn it_feeds_utf16_and_utf32_bytes() {
+        let text = "\rone\r\ntwo\nthree\r\r\n✓\u{0D0A}\u{10D0A}";
+
+        for width in [2, 4] {
+            for big_endian in [false, true] {
+                let mut detector = LineEndingDetector::default();
+                feed(
+                    &mut detector,
+                    &encode(text, width, big_endian),
+                    width,
+                    big_endian,
+                );
+                assert_eq!(
+                    detector.stats(),
+                    LineEnding::stats(text),
+                    "width: {}, big endian: {}",
+                    width,
+                    big_endian
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_feeds_large_input() {
+        let text = "one\r\ntwo\n".repeat(BATCH);
+        let mut detector = LineEndingDetector::default();
+        feed(&mut detector, &encode(&text, 2, false), 2, false);
+        assert_eq!(detector.stats(), LineEnding::stats(&text));
+    }
+
+    #[test]
+    fn it_ignores_trailing_partial_code_unit() {
+        let mut bytes = encode("one\n", 4, false);
+        bytes.extend([0x0A, 0x00]);
+        let mut detector = LineEndingDetector::default();
+        feed(&mut detector, &bytes, 4, false);
+        assert_eq!(detector.stats().lf, 1);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::LineEndingStats;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Returned by [`LineEnding::find_strict()`](crate::LineEnding::find_strict)
+/// when no line ending style dominates or the text contains none
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AmbiguousLineEnding {
+    /// Counts of each line ending style found
+    pub counts: LineEndingStats,
+}
+
+impl Error for AmbiguousLineEnding {}
+
+impl Display for AmbiguousLineEnding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Error: Ambiguous Line Ending! Received: 'CR: {}, LF: {}, CRLF: {}'",
+            self.counts.cr, self.counts.lf, self.counts.crlf
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_prints_expected_message() {
+        let err = AmbiguousLineEnding {
+            counts: LineEndingStats {
+                cr: 1,
+                lf: 2,
+                crlf: 2,
+                lines: 5,
+            },
+        };
+        assert_eq!(
+            format!("{err}"),
+            "Error: Ambiguous Line Ending! Received: 'CR: 1, LF: 2, CRLF: 2'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_text_has_no_line_endings() {
+        let err = AmbiguousLineEnding::default();
+        assert_eq!(
+            format!("{err}"),
+            "Error: Ambiguous Line Ending! Received: 'CR: 0, LF: 0, CRLF: 0'"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::LineEnding;
+use std::borrow::Cow;
+
+/// Adds line ending helpers to `str` (and so `String`) for fluent use - e.g.
+/// `text.line_ending_or(LineEnding::LF)` rather than
+/// `LineEnding::find(text, LineEnding::LF)`
+pub trait LineEndingExt {
+    /// Determines which newline style this text uses (CR, LF, or CRLF) - see
+    /// [`LineEnding::find()`](crate::LineEnding::find)
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The default newline style to use when text has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingExt};
+    /// assert_eq!("a\r\nb".line_ending_or(LineEnding::LF), LineEnding::CRLF);
+    /// assert_eq!("ab".line_ending_or(LineEnding::LF), LineEnding::LF);
+    /// ```
+    fn line_ending_or(&self, default: LineEnding) -> LineEnding;
+
+    /// Converts every line ending (CR, LF, or CRLF) in this text to the given
+    /// style - see [`LineEnding::normalize()`](crate::LineEnding::normalize)
+    ///
+    /// # Arguments
+    ///
+    /// * `eol` - The newline style to convert to
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingExt};
+    /// let text = String::from("one\rtwo\r\nthree\n");
+    /// assert_eq!(text.normalize_line_endings(LineEnding::LF), "one\ntwo\nthree\n");
+    /// ```
+    fn normalize_line_endings(&self, eol: LineEnding) -> Cow<'_, str>;
+}
+
+impl LineEndingExt for str {
+    fn line_ending_or(&self, default: LineEnding) -> LineEnding {
+        LineEnding::find(self, default)
+    }
+
+    fn normalize_line_endings(&self, eol: LineEnding) -> Cow<'_, str> {
+        eol.normalize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_line_ending() {
+        assert_eq!("one\rtwo\r".line_ending_or(LineEnding::LF), LineEnding::CR);
+        assert_eq!("one\ntwo\n".line_ending_or(LineEnding::CR), LineEnding::LF);
+        assert_eq!(
+            "one\rtwo\n".line_ending_or(LineEnding::CRLF),
+            LineEnding::CRLF
+        );
+
+        let text = String::from("one\r\ntwo\r\n");
+        assert_eq!(text.line_ending_or(LineEnding::LF), LineEnding::CRLF);
+    }
+
+    #[test]
+    fn it_normalizes_line_endings() {
+        let text = "one\rtwo\r\nthree\n";
+        assert_eq!(
+            text.normalize_line_endings(LineEnding::CRLF),
+            "one\r\ntwo\r\nthree\r\n"
+        );
+        assert_eq!(
+            text.normalize_line_endings(LineEnding::CR),
+            "one\rtwo\rthree\r"
+        );
+
+        let text = String::from("one\ntwo\n");
+        let normalized = text.normalize_line_endings(LineEnding::LF);
+        assert!(matches!(normalized, Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::LineEnding;
+use memchr::memchr2;
+use std::io::{self, BufRead};
+
+/// An iterator over the line endings (CR, LF, or CRLF) of a buffered reader's
+/// content yielding each line's terminator as it is read - lets very large
+/// files be audited for consistency in a single streaming pass
+#[derive(Debug)]
+pub struct LineEndingsIter<R: BufRead> {
+    reader: R,
+    // a `\r` ended the last buffer so the next one shows whether it's CRLF
+    pending_cr: bool,
+}
+
+impl<R: BufRead> LineEndingsIter<R> {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The buffered reader (e.g. `BufReader<File>`) you want to audit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{LineEnding, LineEndingsIter};
+    /// let iter = LineEndingsIter::new("one\ntwo\r\nthree\n".as_bytes());
+    /// let endings: Vec<LineEnding> = iter.map(Result::unwrap).collect();
+    /// assert_eq!(endings, vec![LineEnding::LF, LineEnding::CRLF, LineEnding::LF]);
+    /// ```
+    pub fn new(reader: R) -> LineEndingsIter<R> {
+        LineEndingsIter {
+            reader,
+            pending_cr: false,
+        }
+    }
+
+    /// Unwraps this instance returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for LineEndingsIter<R> {
+    type Item = io::Result<LineEnding>;
+
+    fn next(&mut self) -> Option<io::Result<LineEnding>> {
+        loop {
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if self.pending_cr {
+                self.pending_cr = false;
+
+                if buf.first() == Some(&b'\n') {
+                    self.reader.consume(1);
+                    return Some(Ok(LineEnding::CRLF));
+                }
+
+                return Some(Ok(LineEnding::CR));
+            }
+
+            if buf.is_empty() {
+                return None;
+            }
+
+            let (len, eol) = match memchr2(b'\r', b'\n', buf) {
+                None => (buf.len(), None),
+                Some(idx) if buf[idx] == b'\n' => (idx + 1, Some(LineEnding::LF)),
+                Some(idx) => match buf.get(idx + 1) {
+                    Some(b'\n') => (idx + 2, Some(LineEnding::CRLF)),
+                    Some(_) => (idx + 1, Some(LineEnding::CR)),
+                    None => {
+                        self.pending_cr = true;
+                        (idx + 1, None)
+                    }
+                },
+            };
+
+            self.reader.consume(len);
+
+            if eol.is_some() {
+                return eol.map(Ok);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    fn endings<R: BufRead>(reader: R) -> Vec<LineEnding> {
+        LineEndingsIter::new(reader).map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn it_yields_each_line_ending() {
+        let input = "\rone\r\ntwo\nthree\r\r\nfour";
+        assert_eq!(
+            endings(input.as_bytes()),
+            vec![
+                LineEnding::CR,
+                LineEnding::CRLF,
+                LineEnding::LF,
+                LineEnding::CR,
+                LineEnding::CRLF
+            ]
+        );
+    }
+
+    #[test]
+    fn it_yields_nothing_when_there_are_no_line_endings() {
+        assert!(endings("".as_bytes()).is_empty());
+        assert!(endings("no line breaks".as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn it_yields_same_line_endings_regardless_of_buffer_size() {
+        let input = "\r\nthis\r\nis\nmixed\r\r\n\nünïcödé\r";
+        let expected = endings(input.as_bytes());
+
+        for capacity in 1..input.len() {
+            let reader = BufReader::with_capacity(capacity, input.as_bytes());
+            assert_eq!(endings(reader), expected, "capacity: {}", capacity);
+        }
+
+        let stats = LineEnding::stats(input);
+        let count = |eol| expected.iter().filter(|e| **e == eol).count();
+        assert_eq!(count(LineEnding::CR), stats.cr);
+        assert_eq!(count(LineEnding::LF), stats.lf);
+        assert_eq!(count(LineEnding::CRLF), stats.crlf);
+    }
+
+    #[test]
+    fn it_yields_error_when_read_fails() {
+        struct Broken;
+
+        impl io::Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("nope"))
+            }
+        }
+
+        let mut iter = LineEndingsIter::new(BufReader::new(Broken));
+        let err = iter.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "nope");
+    }
+
+    #[test]
+    fn it_unwraps_reader() {
+        let mut iter = LineEndingsIter::new("one\ntwo\n".as_bytes());
+        iter.next();
+        assert_eq!(iter.into_inner(), b"two\n");
+    }
+}
@@ -1,15 +1,53 @@
 #![doc = include_str!("../README.md")]
 
-use regex::RegexBuilder;
+use memchr::{memchr2, memrchr2};
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
 use std::str::FromStr;
 
+mod bom;
+mod builder;
+mod convert;
+mod count;
+mod detection;
+mod detector;
+mod encoding;
+mod error;
+mod ext;
+mod iter;
+mod reader;
+mod split;
+mod stats;
+mod unicode;
+mod writer;
+
+pub use bom::Bom;
+pub use builder::{Detector, TieBreak};
+pub use convert::{convert, ConversionStats};
+pub use detection::{Analysis, Detection};
+pub use detector::LineEndingDetector;
+pub use error::AmbiguousLineEnding;
+pub use ext::LineEndingExt;
+pub use iter::LineEndingsIter;
+pub use reader::NormalizingReader;
+pub use split::SplitInclusive;
+pub use stats::LineEndingStats;
+pub use unicode::UnicodeLineEnding;
+pub use writer::NormalizingWriter;
+
 const CR: &str = "\r";
 const LF: &str = "\n";
 const CRLF: &str = "\r\n";
 
-#[derive(Clone, Debug, Default, PartialEq)]
+/// How many bytes [`find_in_file()`](crate::LineEnding::find_in_file) scans
+/// from the start of a file (64KB)
+pub const FILE_SCAN_LIMIT: u64 = 64 * 1024;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum LineEnding {
     /// CR-style line ending (`"\r"`) rarely used, mostly on older systems
     /// (e.g. classic MacOS - OS-X before 10.0)
@@ -22,79 +60,657 @@ pub enum LineEnding {
 }
 
 impl LineEnding {
+    /// Every newline style - handy for iterating exhaustively
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let names: Vec<&str> = LineEnding::ALL.iter().map(|eol| eol.name()).collect();
+    /// assert_eq!(names, ["CR", "LF", "CRLF"]);
+    /// ```
+    pub const ALL: [LineEnding; 3] = [LineEnding::CR, LineEnding::LF, LineEnding::CRLF];
+
     /// Creates a new instance - you'll almost certainly rather use one of the
     /// [`find*`](crate::LineEnding::find) associated fns below :)
     ///
     /// # Arguments
     ///
-    /// * `kind` - The line ending style you want
+    /// * `kind` - The line ending style you want - either its characters (e.g.
+    ///   `"\r\n"`) or a case-insensitive name: `"cr"` / `"mac"`, `"lf"` /
+    ///   `"unix"`, or `"crlf"` / `"windows"` / `"dos"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::new("\n");
+    /// assert_eq!(eol, LineEnding::LF);
+    /// let eol = LineEnding::new("Windows");
+    /// assert_eq!(eol, LineEnding::CRLF);
+    /// ```
+    pub fn new<K: AsRef<str>>(kind: K) -> LineEnding {
+        let kind = LineEnding::from_str(kind.as_ref());
+
+        if kind.is_err() {
+            return LineEnding::LF;
+        }
+
+        kind.unwrap()
+    }
+
+    /// Returns the host platform's conventional newline style - CRLF on
+    /// Windows, LF elsewhere
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find("one\rtwo\n", LineEnding::native());
+    /// assert_eq!(eol, if cfg!(windows) { LineEnding::CRLF } else { LineEnding::LF });
+    /// ```
+    pub const fn native() -> LineEnding {
+        if cfg!(windows) {
+            LineEnding::CRLF
+        } else {
+            LineEnding::LF
+        }
+    }
+
+    /// Returns the line ending's characters (e.g. `"\r\n"`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::CRLF.as_str(), "\r\n");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::CR => CR,
+            LineEnding::LF => LF,
+            LineEnding::CRLF => CRLF,
+        }
+    }
+
+    /// Returns the line ending's human-readable name (e.g. `"CRLF"`) - also
+    /// available via alternate formatting (`{:#}`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::CRLF.name(), "CRLF");
+    /// assert_eq!(format!("{:#}", LineEnding::LF), "LF");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            LineEnding::CR => "CR",
+            LineEnding::LF => "LF",
+            LineEnding::CRLF => "CRLF",
+        }
+    }
+
+    /// Returns the line ending's bytes (e.g. `b"\r\n"`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::CRLF.as_bytes(), b"\r\n");
+    /// ```
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Returns the line ending's length in bytes - `2` for CRLF, `1` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::CRLF.len(), 2);
+    /// assert_eq!(LineEnding::LF.len(), 1);
+    /// ```
+    #[allow(clippy::len_without_is_empty)] // line endings are never empty
+    pub const fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns the EditorConfig `end_of_line` value for this style (e.g. `"crlf"`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::CRLF.to_editorconfig(), "crlf");
+    /// ```
+    pub const fn to_editorconfig(&self) -> &'static str {
+        match self {
+            LineEnding::CR => "cr",
+            LineEnding::LF => "lf",
+            LineEnding::CRLF => "crlf",
+        }
+    }
+
+    /// Parses an EditorConfig `end_of_line` value (`lf`, `crlf`, or `cr`) -
+    /// values are case-insensitive per the EditorConfig spec
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `end_of_line` property's value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::from_editorconfig("CRLF").unwrap(), LineEnding::CRLF);
+    /// assert!(LineEnding::from_editorconfig("unset").is_err());
+    /// ```
+    pub fn from_editorconfig(value: &str) -> Result<LineEnding, Box<dyn Error>> {
+        match value.trim().to_lowercase().as_str() {
+            "cr" => Ok(LineEnding::CR),
+            "lf" => Ok(LineEnding::LF),
+            "crlf" => Ok(LineEnding::CRLF),
+            _ => Err(format!("Unrecognized end_of_line value: {}", value).into()),
+        }
+    }
+
+    /// Returns the gitattributes `eol` value for this style (e.g. `"lf"`) -
+    /// git has no CR setting so `None` is returned for CR
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::LF.to_gitattributes(), Some("lf"));
+    /// assert_eq!(LineEnding::CR.to_gitattributes(), None);
+    /// ```
+    pub const fn to_gitattributes(&self) -> Option<&'static str> {
+        match self {
+            LineEnding::CR => None,
+            LineEnding::LF => Some("lf"),
+            LineEnding::CRLF => Some("crlf"),
+        }
+    }
+
+    /// Parses a gitattributes `eol` value (`lf` or `crlf`) - the attribute's
+    /// name may be included (e.g. `eol=crlf`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `eol` attribute's value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert_eq!(LineEnding::from_gitattributes("crlf").unwrap(), LineEnding::CRLF);
+    /// assert_eq!(LineEnding::from_gitattributes("eol=lf").unwrap(), LineEnding::LF);
+    /// assert!(LineEnding::from_gitattributes("cr").is_err());
+    /// ```
+    pub fn from_gitattributes(value: &str) -> Result<LineEnding, Box<dyn Error>> {
+        let value = value.trim();
+
+        match value.strip_prefix("eol=").unwrap_or(value) {
+            "lf" => Ok(LineEnding::LF),
+            "crlf" => Ok(LineEnding::CRLF),
+            _ => Err(format!("Unrecognized eol value: {}", value).into()),
+        }
+    }
+
+    /// Determines which newline style a given string uses (CR, LF, or CRLF)
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    /// * `default` - The default newline style to use when text has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find("one\ntwo\r\nthree\n", LineEnding::CRLF);
+    /// assert_eq!(eol, LineEnding::LF);
+    /// ```
+    pub fn find<S: AsRef<str>>(text: S, default: LineEnding) -> LineEnding {
+        LineEnding::find_in_bytes(text.as_ref(), default)
+    }
+
+    /// Determines which newline style arbitrary bytes use (CR, LF, or CRLF) -
+    /// never fails on invalid UTF-8 as everything other than `\r` and `\n` is
+    /// skipped over, so detection can run before encoding validation
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The content you want to analyze
+    /// * `default` - The default newline style to use when content has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find_in_bytes(b"one\xFF\r\ntwo\xC3\r\n", LineEnding::LF);
+    /// assert_eq!(eol, LineEnding::CRLF);
+    /// ```
+    pub fn find_in_bytes<B: AsRef<[u8]>>(bytes: B, default: LineEnding) -> LineEnding {
+        let mut detector = LineEndingDetector::new(default);
+        detector.feed(bytes);
+        detector.finish()
+    }
+
+    /// Counts each line ending style (CR, LF, and CRLF) a given string uses
+    /// along with its total number of lines
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let stats = LineEnding::stats("one\ntwo\r\nthree\nfour");
+    /// assert_eq!(stats.cr, 0);
+    /// assert_eq!(stats.lf, 2);
+    /// assert_eq!(stats.crlf, 1);
+    /// assert_eq!(stats.lines, 4);
+    /// ```
+    pub fn stats<S: AsRef<str>>(text: S) -> LineEndingStats {
+        let mut detector = LineEndingDetector::default();
+        detector.feed(text.as_ref());
+        detector.stats()
+    }
+
+    /// Analyzes which newline style a given string uses (CR, LF, or CRLF)
+    /// reporting how dominant it is - lets callers e.g. prompt a user when a
+    /// file's endings are mixed 55/45 instead of silently picking one
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let detection = LineEnding::detect("one\ntwo\nthree\r\nfour\n");
+    /// assert_eq!(detection.ending, Some(LineEnding::LF));
+    /// assert_eq!(detection.confidence, 0.75);
+    /// assert_eq!(detection.counts.crlf, 1);
+    /// ```
+    pub fn detect<S: AsRef<str>>(text: S) -> Detection {
+        Detection::new(LineEnding::stats(text))
+    }
+
+    /// Analyzes raw content reporting its byte order mark (if any) along w/
+    /// which newline style it uses - content starting w/ a UTF-16 or UTF-32
+    /// byte order mark is scanned in that encoding, anything else as UTF-8
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The content you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::{Bom, LineEnding};
+    /// let analysis = LineEnding::analyze(b"\xEF\xBB\xBFone\r\ntwo\r\n");
+    /// assert_eq!(analysis.bom, Some(Bom::Utf8));
+    /// assert_eq!(analysis.detection.ending, Some(LineEnding::CRLF));
+    /// ```
+    pub fn analyze<B: AsRef<[u8]>>(bytes: B) -> Analysis {
+        let bytes = bytes.as_ref();
+        let bom = Bom::sniff(bytes);
+        let content = &bytes[bom.as_ref().map_or(0, Bom::len)..];
+        let mut detector = LineEndingDetector::default();
+
+        match bom {
+            Some(Bom::Utf16LE) => encoding::feed(&mut detector, content, 2, false),
+            Some(Bom::Utf16BE) => encoding::feed(&mut detector, content, 2, true),
+            Some(Bom::Utf32LE) => encoding::feed(&mut detector, content, 4, false),
+            Some(Bom::Utf32BE) => encoding::feed(&mut detector, content, 4, true),
+            Some(Bom::Utf8) | None => {
+                detector.feed(content);
+            }
+        }
+
+        Analysis {
+            bom,
+            detection: Detection::new(detector.stats()),
+        }
+    }
+
+    /// Determines which newline style a given string uses (CR, LF, or CRLF)
+    /// refusing to guess when no style dominates or the text has none
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find_strict("one\ntwo\r\nthree\n");
+    /// assert_eq!(eol.unwrap(), LineEnding::LF);
+    /// let err = LineEnding::find_strict("one\ntwo\r\n").unwrap_err();
+    /// assert_eq!((err.counts.lf, err.counts.crlf), (1, 1));
+    /// ```
+    pub fn find_strict<S: AsRef<str>>(text: S) -> Result<LineEnding, AmbiguousLineEnding> {
+        let detection = LineEnding::detect(text);
+
+        match detection.ending {
+            Some(eol) => Ok(eol),
+            None => Err(AmbiguousLineEnding {
+                counts: detection.counts,
+            }),
+        }
+    }
+
+    /// Determines which newline style the content of a given reader uses (CR,
+    /// LF, or CRLF) - content is scanned incrementally so it never has to be
+    /// loaded into memory all at once
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The buffered reader (e.g. `BufReader<File>`) you want to analyze
+    /// * `default` - The default newline style to use when content has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let reader = std::io::Cursor::new("one\ntwo\r\nthree\n");
+    /// let eol = LineEnding::find_in_reader(reader, LineEnding::CRLF).unwrap();
+    /// assert_eq!(eol, LineEnding::LF);
+    /// ```
+    pub fn find_in_reader<R: BufRead>(reader: R, default: LineEnding) -> io::Result<LineEnding> {
+        let mut detector = LineEndingDetector::new(default);
+        detector.feed_reader(reader)?;
+        Ok(detector.finish())
+    }
+
+    /// Determines which newline style UTF-16 encoded content uses (CR, LF, or
+    /// CRLF) without transcoding it first - the byte order is taken from a
+    /// leading byte order mark, falling back to little-endian (as written by
+    /// Windows) when there is none
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The UTF-16 encoded content you want to analyze
+    /// * `default` - The default newline style to use when content has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let bytes = [0xFF, 0xFE, b'a', 0, b'\r', 0, b'\n', 0, b'b', 0];
+    /// let eol = LineEnding::find_in_utf16(bytes, LineEnding::LF);
+    /// assert_eq!(eol, LineEnding::CRLF);
+    /// ```
+    pub fn find_in_utf16<B: AsRef<[u8]>>(bytes: B, default: LineEnding) -> LineEnding {
+        let bytes = bytes.as_ref();
+        let (big_endian, bom_len) = match Bom::sniff(bytes) {
+            Some(Bom::Utf16BE) => (true, 2),
+            Some(Bom::Utf16LE | Bom::Utf32LE) => (false, 2),
+            _ => (false, 0),
+        };
+        let mut detector = LineEndingDetector::new(default);
+        encoding::feed(&mut detector, &bytes[bom_len..], 2, big_endian);
+        detector.finish()
+    }
+
+    /// Determines which newline style UTF-16LE encoded content uses (CR, LF,
+    /// or CRLF) - see [`find_in_utf16()`](crate::LineEnding::find_in_utf16)
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The UTF-16LE encoded content you want to analyze
+    /// * `default` - The default newline style to use when content has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let bytes = [b'a', 0, b'\r', 0, b'b', 0];
+    /// let eol = LineEnding::find_in_utf16le(bytes, LineEnding::LF);
+    /// assert_eq!(eol, LineEnding::CR);
+    /// ```
+    pub fn find_in_utf16le<B: AsRef<[u8]>>(bytes: B, default: LineEnding) -> LineEnding {
+        let mut detector = LineEndingDetector::new(default);
+        encoding::feed(&mut detector, bytes.as_ref(), 2, false);
+        detector.finish()
+    }
+
+    /// Determines which newline style UTF-16BE encoded content uses (CR, LF,
+    /// or CRLF) - see [`find_in_utf16()`](crate::LineEnding::find_in_utf16)
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The UTF-16BE encoded content you want to analyze
+    /// * `default` - The default newline style to use when content has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let bytes = [0, b'a', 0, b'\n', 0, b'b'];
+    /// let eol = LineEnding::find_in_utf16be(bytes, LineEnding::CRLF);
+    /// assert_eq!(eol, LineEnding::LF);
+    /// ```
+    pub fn find_in_utf16be<B: AsRef<[u8]>>(bytes: B, default: LineEnding) -> LineEnding {
+        let mut detector = LineEndingDetector::new(default);
+        encoding::feed(&mut detector, bytes.as_ref(), 2, true);
+        detector.finish()
+    }
+
+    /// Determines which newline style a given file uses (CR, LF, or CRLF) -
+    /// only the first [`FILE_SCAN_LIMIT`](crate::FILE_SCAN_LIMIT) bytes are
+    /// scanned, see [`find_in_file_with_limit()`](crate::LineEnding::find_in_file_with_limit)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file you want to analyze
+    /// * `default` - The default newline style to use when the file has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find_in_file("Cargo.toml", LineEnding::CRLF).unwrap();
+    /// assert_eq!(eol, LineEnding::LF);
+    /// ```
+    pub fn find_in_file<P: AsRef<Path>>(path: P, default: LineEnding) -> io::Result<LineEnding> {
+        LineEnding::find_in_file_with_limit(path, default, FILE_SCAN_LIMIT)
+    }
+
+    /// Determines which newline style a given file uses (CR, LF, or CRLF)
+    /// scanning at most `limit` bytes from the start of the file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file you want to analyze
+    /// * `default` - The default newline style to use when the file has no preference
+    /// * `limit` - The maximum number of bytes to scan (`u64::MAX` scans everything)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find_in_file_with_limit("Cargo.toml", LineEnding::CRLF, 1024).unwrap();
+    /// assert_eq!(eol, LineEnding::LF);
+    /// ```
+    pub fn find_in_file_with_limit<P: AsRef<Path>>(
+        path: P,
+        default: LineEnding,
+        limit: u64,
+    ) -> io::Result<LineEnding> {
+        let file = File::open(path)?;
+        LineEnding::find_in_reader(BufReader::new(file.take(limit)), default)
+    }
+
+    /// Converts every line ending (CR, LF, or CRLF) in a given string to this
+    /// style - text which already uses it throughout is returned as-is without
+    /// allocating
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to normalize
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use detect_newline_style::LineEnding;
+    /// let text = LineEnding::LF.normalize("one\rtwo\r\nthree\n");
+    /// assert_eq!(text, "one\ntwo\nthree\n");
+    /// let text = LineEnding::LF.normalize("one\ntwo\n");
+    /// assert!(matches!(text, Cow::Borrowed(_)));
+    /// ```
+    pub fn normalize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let eol = self.as_str();
+        let bytes = text.as_bytes();
+        let mut normalized: Option<String> = None;
+        let mut start = 0;
+        let mut idx = 0;
+
+        while let Some(offset) = memchr2(b'\r', b'\n', &bytes[idx..]) {
+            idx += offset;
+
+            let len = match bytes[idx] {
+                b'\r' if bytes.get(idx + 1) == Some(&b'\n') => 2,
+                _ => 1,
+            };
+
+            if &text[idx..idx + len] != eol {
+                let out = normalized.get_or_insert_with(|| String::with_capacity(text.len()));
+                out.push_str(&text[start..idx]);
+                out.push_str(eol);
+                start = idx + len;
+            }
+
+            idx += len;
+        }
+
+        match normalized {
+            Some(mut out) => {
+                out.push_str(&text[start..]);
+                Cow::Owned(out)
+            }
+            None => Cow::Borrowed(text),
+        }
+    }
+
+    /// Converts every line ending (CR, LF, or CRLF) in a given string to this
+    /// style in place - converting to CR or LF never grows the string so its
+    /// existing buffer is reused rather than allocating a second one
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to normalize
     ///
     /// # Examples
     ///
     /// ```rust
     /// use detect_newline_style::LineEnding;
-    /// let eol = LineEnding::new("\n");
-    /// assert_eq!(eol, LineEnding::LF);
+    /// let mut text = String::from("one\rtwo\r\nthree\n");
+    /// LineEnding::LF.normalize_in_place(&mut text);
+    /// assert_eq!(text, "one\ntwo\nthree\n");
     /// ```
-    pub fn new<K: AsRef<str>>(kind: K) -> LineEnding {
-        let kind = LineEnding::from_str(kind.as_ref());
+    pub fn normalize_in_place(&self, text: &mut String) {
+        if *self == LineEnding::CRLF {
+            if let Cow::Owned(normalized) = self.normalize(text) {
+                *text = normalized;
+            }
+            return;
+        }
 
-        if kind.is_err() {
-            return LineEnding::LF;
+        let eol = self.as_bytes()[0];
+        let mut bytes = std::mem::take(text).into_bytes();
+        let mut read = 0;
+        let mut write = 0;
+
+        while let Some(offset) = memchr2(b'\r', b'\n', &bytes[read..]) {
+            let idx = read + offset;
+            let len = match bytes[idx] {
+                b'\r' if bytes.get(idx + 1) == Some(&b'\n') => 2,
+                _ => 1,
+            };
+
+            bytes.copy_within(read..idx, write);
+            write += idx - read;
+            bytes[write] = eol;
+            write += 1;
+            read = idx + len;
         }
 
-        kind.unwrap()
+        bytes.copy_within(read.., write);
+        bytes.truncate(write + bytes.len() - read);
+        // only ascii line ending bytes were replaced so content is still utf-8
+        *text = String::from_utf8(bytes).expect("normalized text is valid UTF-8");
     }
 
-    /// Determines which newline style a given string uses (CR, LF, or CRLF)
+    /// Counts the lines in a given string which are terminated by this style
+    /// (other line endings are treated as content - including the `\r` and
+    /// `\n` of CRLF pairs when counting CR or LF) - a trailing line without a
+    /// line ending counts too
     ///
     /// # Arguments
     ///
     /// * `text` - The text you want to analyze
-    /// * `default` - The default newline style to use when text has no preference
     ///
     /// # Examples
     ///
     /// ```rust
     /// use detect_newline_style::LineEnding;
-    /// let eol = LineEnding::find("one\ntwo\r\nthree\n", LineEnding::CRLF);
-    /// assert_eq!(eol, LineEnding::LF);
+    /// assert_eq!(LineEnding::CRLF.count_lines("one\r\ntwo\r\nthree"), 3);
+    /// assert_eq!(LineEnding::CRLF.count_lines("one\r\ntwo\r\n"), 2);
+    /// assert_eq!(LineEnding::CRLF.count_lines("one\ntwo\n"), 1);
+    /// assert_eq!(LineEnding::LF.count_lines("one\r\ntwo\r\n"), 1);
+    /// assert_eq!(LineEnding::LF.count_lines(""), 0);
     /// ```
-    pub fn find<S: AsRef<str>>(text: S, default: LineEnding) -> LineEnding {
-        let text = text.as_ref();
-        let ptn = r"(?:\r\n?|\n)";
-        let re = RegexBuilder::new(ptn)
-            .case_insensitive(true)
-            .multi_line(true)
-            .build()
-            .unwrap();
-
-        let matches = re.find_iter(text);
-        let mut crlf_count = 0;
-        let mut cr_count = 0;
-        let mut lf_count = 0;
-
-        for item in matches {
-            let x = item.as_str();
-
-            if x == CRLF {
-                crlf_count += 1;
-            } else if x == LF {
-                lf_count += 1;
-            } else if x == CR {
-                cr_count += 1;
-            }
-        }
+    pub fn count_lines<S: AsRef<str>>(&self, text: S) -> usize {
+        let bytes = text.as_ref().as_bytes();
+        let (breaks, terminated) = match self {
+            LineEnding::CRLF => (
+                memchr::memmem::find_iter(bytes, b"\r\n").count(),
+                bytes.ends_with(b"\r\n"),
+            ),
+            LineEnding::LF => (
+                memchr::memchr_iter(b'\n', bytes)
+                    .filter(|idx| *idx == 0 || bytes[idx - 1] != b'\r')
+                    .count(),
+                bytes.ends_with(b"\n") && !bytes.ends_with(b"\r\n"),
+            ),
+            LineEnding::CR => (
+                memchr::memchr_iter(b'\r', bytes)
+                    .filter(|idx| bytes.get(idx + 1) != Some(&b'\n'))
+                    .count(),
+                bytes.ends_with(b"\r"),
+            ),
+        };
 
-        if crlf_count > lf_count && crlf_count > cr_count {
-            return LineEnding::CRLF;
-        } else if lf_count > crlf_count && lf_count > cr_count {
-            return LineEnding::LF;
-        } else if cr_count > lf_count && cr_count > crlf_count {
-            return LineEnding::CR;
-        }
+        breaks + usize::from(!bytes.is_empty() && !terminated)
+    }
 
-        default
+    /// Splits a given string into lines which include their line endings (CR,
+    /// LF, or CRLF) - joining them reconstructs the input exactly
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to split
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let lines: Vec<&str> = LineEnding::split_inclusive("one\rtwo\r\nthree\nfour").collect();
+    /// assert_eq!(lines, vec!["one\r", "two\r\n", "three\n", "four"]);
+    /// assert_eq!(lines.concat(), "one\rtwo\r\nthree\nfour");
+    /// ```
+    pub fn split_inclusive(text: &str) -> SplitInclusive<'_> {
+        SplitInclusive::new(text)
     }
 
     /// Determines which newline style a given string uses (CR, LF, or CRLF)
@@ -156,17 +772,139 @@ impl LineEnding {
     pub fn find_or_use_cr<S: AsRef<str>>(s: S) -> LineEnding {
         LineEnding::find(s, LineEnding::CR)
     }
+
+    /// Determines which newline style a given string uses (CR, LF, or CRLF)
+    /// based on its first line ending rather than by counting them all - the
+    /// "first line wins" approach used by Git and several editors
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    /// * `default` - The default newline style to use when text has no line endings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find_or_use_first("one\r\ntwo\nthree\n", LineEnding::CR);
+    /// assert_eq!(eol, LineEnding::CRLF);
+    /// let eol = LineEnding::find_or_use_first("one", LineEnding::CR);
+    /// assert_eq!(eol, LineEnding::CR);
+    /// ```
+    pub fn find_or_use_first<S: AsRef<str>>(text: S, default: LineEnding) -> LineEnding {
+        let bytes = text.as_ref().as_bytes();
+
+        match memchr2(b'\r', b'\n', bytes) {
+            Some(idx) if bytes[idx] == b'\n' => LineEnding::LF,
+            Some(idx) if bytes.get(idx + 1) == Some(&b'\n') => LineEnding::CRLF,
+            Some(_) => LineEnding::CR,
+            None => default,
+        }
+    }
+
+    /// Determines which newline style a given string uses (CR, LF, or CRLF)
+    /// based on its last line ending rather than by counting them all
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    /// * `default` - The default newline style to use when text has no line endings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let eol = LineEnding::find_or_use_last("one\ntwo\nthree\r\n", LineEnding::CR);
+    /// assert_eq!(eol, LineEnding::CRLF);
+    /// let eol = LineEnding::find_or_use_last("", LineEnding::CR);
+    /// assert_eq!(eol, LineEnding::CR);
+    /// ```
+    pub fn find_or_use_last<S: AsRef<str>>(text: S, default: LineEnding) -> LineEnding {
+        let bytes = text.as_ref().as_bytes();
+
+        match memrchr2(b'\r', b'\n', bytes) {
+            Some(idx) if bytes[idx] == b'\r' => LineEnding::CR,
+            Some(idx) if idx > 0 && bytes[idx - 1] == b'\r' => LineEnding::CRLF,
+            Some(_) => LineEnding::LF,
+            None => default,
+        }
+    }
+}
+
+/// Counts the lines in a given string using the newline style it prefers
+/// (LF when it has no preference) - see
+/// [`LineEnding::count_lines()`](crate::LineEnding::count_lines)
+///
+/// # Arguments
+///
+/// * `text` - The text you want to analyze
+///
+/// # Examples
+///
+/// ```rust
+/// use detect_newline_style::count_lines;
+/// assert_eq!(count_lines("one\r\ntwo\r\nthree"), 3);
+/// assert_eq!(count_lines("one\ntwo\n"), 2);
+/// assert_eq!(count_lines(""), 0);
+/// ```
+pub fn count_lines<S: AsRef<str>>(text: S) -> usize {
+    let text = text.as_ref();
+    LineEnding::find(text, LineEnding::default()).count_lines(text)
+}
+
+// picks the style w/ the most occurrences - ties fall back to `default`
+pub(crate) fn preferred(cr: usize, lf: usize, crlf: usize, default: LineEnding) -> LineEnding {
+    if crlf > lf && crlf > cr {
+        return LineEnding::CRLF;
+    } else if lf > crlf && lf > cr {
+        return LineEnding::LF;
+    } else if cr > lf && cr > crlf {
+        return LineEnding::CR;
+    }
+
+    default
+}
+
+// appends `input` to `out` converting each line ending to `eol` - `after_cr`
+// carries whether the previous chunk ended w/ `\r` so a CRLF pair split
+// across chunks is converted once
+pub(crate) fn normalize_chunk(
+    eol: &LineEnding,
+    after_cr: &mut bool,
+    input: &[u8],
+    out: &mut Vec<u8>,
+) {
+    let eol = eol.as_bytes();
+    let mut start = 0;
+
+    for idx in memchr::memchr2_iter(b'\r', b'\n', input) {
+        // the `\n` completing a CRLF pair was already converted w/ its `\r`
+        let skip = input[idx] == b'\n' && *after_cr && idx == start;
+
+        out.extend_from_slice(&input[start..idx]);
+
+        if !skip {
+            out.extend_from_slice(eol);
+        }
+
+        *after_cr = input[idx] == b'\r';
+        start = idx + 1;
+    }
+
+    if start < input.len() {
+        *after_cr = false;
+    }
+
+    out.extend_from_slice(&input[start..]);
 }
 
 impl Display for LineEnding {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let eol = match self {
-            LineEnding::CR => CR,
-            LineEnding::LF => LF,
-            LineEnding::CRLF => CRLF,
-        };
+        if f.alternate() {
+            return write!(f, "{}", self.name());
+        }
 
-        write!(f, "{}", eol)
+        write!(f, "{}", self.as_str())
     }
 }
 
@@ -175,14 +913,44 @@ impl FromStr for LineEnding {
 
     fn from_str(s: &str) -> Result<LineEnding, Box<dyn Error>> {
         match s.to_lowercase().as_str() {
-            CR => Ok(LineEnding::CR),
-            LF => Ok(LineEnding::LF),
-            CRLF => Ok(LineEnding::CRLF),
+            CR | "cr" | "mac" => Ok(LineEnding::CR),
+            LF | "lf" | "unix" => Ok(LineEnding::LF),
+            CRLF | "crlf" | "windows" | "dos" => Ok(LineEnding::CRLF),
             _ => Err(format!("Unrecognized input: {}", s).into()),
         }
     }
 }
 
+impl TryFrom<&[u8]> for LineEnding {
+    type Error = Box<dyn Error>;
+
+    fn try_from(bytes: &[u8]) -> Result<LineEnding, Box<dyn Error>> {
+        match bytes {
+            b"\r" => Ok(LineEnding::CR),
+            b"\n" => Ok(LineEnding::LF),
+            b"\r\n" => Ok(LineEnding::CRLF),
+            _ => Err(format!("Unrecognized input: {:?}", bytes).into()),
+        }
+    }
+}
+
+// serialized as its name (e.g. `"CRLF"`) - deserializes from anything
+// `FromStr` accepts (names, aliases, or the line ending's characters)
+#[cfg(feature = "serde")]
+impl serde::Serialize for LineEnding {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LineEnding {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<LineEnding, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        LineEnding::from_str(&input).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +970,24 @@ mod tests {
         assert_eq!(eol, LineEnding::CRLF);
     }
 
+    #[test]
+    fn it_initializes_a_line_ending_from_its_name() {
+        for name in ["cr", "CR", "mac", "Mac"] {
+            assert_eq!(LineEnding::from_str(name).unwrap(), LineEnding::CR);
+        }
+
+        for name in ["lf", "LF", "unix", "UNIX"] {
+            assert_eq!(LineEnding::from_str(name).unwrap(), LineEnding::LF);
+        }
+
+        for name in ["crlf", "CRLF", "windows", "Windows", "dos", "DOS"] {
+            assert_eq!(LineEnding::from_str(name).unwrap(), LineEnding::CRLF);
+        }
+
+        let err = LineEnding::from_str("linux!").unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized input: linux!");
+    }
+
     #[test]
     fn it_uses_lf_line_ending_when_kind_is_unrecognized() {
         let eol = LineEnding::new("NOPE!");
@@ -215,6 +1001,195 @@ mod tests {
         assert_eq!("\r\n", format!("{}", LineEnding::CRLF));
     }
 
+    #[test]
+    fn it_exposes_line_ending_characters() {
+        assert_eq!(LineEnding::CR.as_str(), "\r");
+        assert_eq!(LineEnding::LF.as_str(), "\n");
+        assert_eq!(LineEnding::CRLF.as_str(), "\r\n");
+        assert_eq!(LineEnding::CR.as_bytes(), b"\r");
+        assert_eq!(LineEnding::LF.as_bytes(), b"\n");
+        assert_eq!(LineEnding::CRLF.as_bytes(), b"\r\n");
+        assert_eq!(LineEnding::CR.len(), 1);
+        assert_eq!(LineEnding::LF.len(), 1);
+        assert_eq!(LineEnding::CRLF.len(), 2);
+    }
+
+    #[test]
+    fn it_initializes_a_line_ending_from_bytes() {
+        assert_eq!(LineEnding::try_from(&b"\r"[..]).unwrap(), LineEnding::CR);
+        assert_eq!(LineEnding::try_from(&b"\n"[..]).unwrap(), LineEnding::LF);
+        assert_eq!(
+            LineEnding::try_from(&b"\r\n"[..]).unwrap(),
+            LineEnding::CRLF
+        );
+
+        let err = LineEnding::try_from(&b"\n\r"[..]).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized input: [10, 13]");
+    }
+
+    #[test]
+    fn it_serializes_a_line_ending_name() {
+        assert_eq!("CR", format!("{:#}", LineEnding::CR));
+        assert_eq!("LF", format!("{:#}", LineEnding::LF));
+        assert_eq!("CRLF", format!("{:#}", LineEnding::CRLF));
+        assert_eq!(LineEnding::CR.name(), "CR");
+        assert_eq!(LineEnding::LF.name(), "LF");
+        assert_eq!(LineEnding::CRLF.name(), "CRLF");
+    }
+
+    #[test]
+    fn it_normalizes_line_endings_in_place() {
+        let input = "\rünï\r\ncödé\n\n\r\r\nend";
+
+        for eol in LineEnding::ALL {
+            let mut text = input.to_string();
+            eol.normalize_in_place(&mut text);
+            assert_eq!(text, eol.normalize(input), "eol: {:#}", eol);
+        }
+
+        let mut text = String::from("one\r\ntwo\r\n");
+        let capacity = text.capacity();
+        LineEnding::LF.normalize_in_place(&mut text);
+        assert_eq!(text, "one\ntwo\n");
+        assert_eq!(text.capacity(), capacity);
+
+        let mut text = String::new();
+        LineEnding::CR.normalize_in_place(&mut text);
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn it_counts_lines() {
+        assert_eq!(LineEnding::LF.count_lines("one\ntwo\nthree"), 3);
+        assert_eq!(LineEnding::LF.count_lines("one\ntwo\nthree\n"), 3);
+        assert_eq!(LineEnding::LF.count_lines("\n\n"), 2);
+        assert_eq!(LineEnding::LF.count_lines("one\r\ntwo\r\n"), 1);
+        assert_eq!(LineEnding::CR.count_lines("one\r\ntwo\r\n"), 1);
+        assert_eq!(LineEnding::LF.count_lines("\none\r\ntwo\n"), 2);
+        assert_eq!(LineEnding::CR.count_lines("one\r\ntwo\rthree\r\r\n"), 3);
+        assert_eq!(LineEnding::CR.count_lines("one\rtwo\r"), 2);
+        assert_eq!(LineEnding::CRLF.count_lines("one\r\r\ntwo\n"), 2);
+        assert_eq!(LineEnding::CRLF.count_lines("one\r"), 1);
+
+        for eol in LineEnding::ALL {
+            assert_eq!(eol.count_lines(""), 0);
+            assert_eq!(eol.count_lines("one"), 1);
+        }
+    }
+
+    #[test]
+    fn it_counts_lines_using_detected_line_ending() {
+        assert_eq!(count_lines("one\rtwo\rthree\r"), 3);
+        assert_eq!(count_lines("one\r\ntwo\r\nthree\nfour"), 3);
+        assert_eq!(count_lines("one\ntwo\r"), 2);
+        assert_eq!(count_lines("one"), 1);
+        assert_eq!(count_lines(""), 0);
+    }
+
+    #[test]
+    fn it_maps_editorconfig_values() {
+        for eol in LineEnding::ALL {
+            let value = eol.to_editorconfig();
+            assert_eq!(LineEnding::from_editorconfig(value).unwrap(), eol);
+        }
+
+        assert_eq!(
+            LineEnding::from_editorconfig(" Lf ").unwrap(),
+            LineEnding::LF
+        );
+
+        let err = LineEnding::from_editorconfig("unix").unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized end_of_line value: unix");
+    }
+
+    #[test]
+    fn it_maps_gitattributes_values() {
+        assert_eq!(LineEnding::CR.to_gitattributes(), None);
+
+        for eol in [LineEnding::LF, LineEnding::CRLF] {
+            let value = eol.to_gitattributes().unwrap();
+            assert_eq!(LineEnding::from_gitattributes(value).unwrap(), eol);
+        }
+
+        let eol = LineEnding::from_gitattributes("eol=crlf").unwrap();
+        assert_eq!(eol, LineEnding::CRLF);
+
+        let err = LineEnding::from_gitattributes("eol=cr").unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized eol value: eol=cr");
+        assert!(LineEnding::from_gitattributes("LF").is_err());
+    }
+
+    #[test]
+    fn it_uses_native_line_ending() {
+        const NATIVE: LineEnding = LineEnding::native();
+
+        #[cfg(windows)]
+        assert_eq!(NATIVE, LineEnding::CRLF);
+        #[cfg(not(windows))]
+        assert_eq!(NATIVE, LineEnding::LF);
+    }
+
+    #[test]
+    fn it_lists_all_line_endings() {
+        assert_eq!(LineEnding::ALL.len(), 3);
+        assert!(LineEnding::ALL.windows(2).all(|w| w[0] < w[1]));
+        assert!(LineEnding::ALL.contains(&LineEnding::default()));
+    }
+
+    #[test]
+    fn it_uses_line_endings_as_map_keys() {
+        let mut counts = std::collections::HashMap::new();
+
+        for eol in [LineEnding::LF, LineEnding::CRLF, LineEnding::LF] {
+            *counts.entry(eol).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts[&LineEnding::LF], 2);
+        assert_eq!(counts[&LineEnding::CRLF], 1);
+        assert!(!counts.contains_key(&LineEnding::CR));
+
+        let sorted: std::collections::BTreeSet<_> = counts.into_keys().collect();
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            [LineEnding::LF, LineEnding::CRLF]
+        );
+    }
+
+    #[test]
+    fn it_round_trips_a_line_ending_name() {
+        for eol in LineEnding::ALL {
+            assert_eq!(LineEnding::from_str(eol.name()).unwrap(), eol);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_serializes_a_line_ending_with_serde() {
+        assert_eq!(serde_json::to_string(&LineEnding::CR).unwrap(), r#""CR""#);
+        assert_eq!(serde_json::to_string(&LineEnding::LF).unwrap(), r#""LF""#);
+        assert_eq!(
+            serde_json::to_string(&LineEnding::CRLF).unwrap(),
+            r#""CRLF""#
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_deserializes_a_line_ending_with_serde() {
+        for (json, eol) in [
+            (r#""CR""#, LineEnding::CR),
+            (r#""lf""#, LineEnding::LF),
+            (r#""windows""#, LineEnding::CRLF),
+            (r#""\r\n""#, LineEnding::CRLF),
+        ] {
+            assert_eq!(serde_json::from_str::<LineEnding>(json).unwrap(), eol);
+        }
+
+        let err = serde_json::from_str::<LineEnding>(r#""nope""#).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized input: nope");
+        assert!(serde_json::from_str::<LineEnding>("1").is_err());
+    }
+
     #[test]
     fn it_finds_preferred_line_ending_when_input_prefers_unix_style_endings() {
         let input = "\nthis\nprefers\nunix-style endings\r\n";
@@ -257,6 +1232,283 @@ mod tests {
         assert_eq!(eol, LineEnding::LF);
     }
 
+    #[test]
+    fn it_counts_line_endings() {
+        let stats = LineEnding::stats("\rthis\rprefers\r\nobsolete endings\n");
+        assert_eq!(
+            stats,
+            LineEndingStats {
+                cr: 2,
+                lf: 1,
+                crlf: 1,
+                lines: 4
+            }
+        );
+
+        let stats = LineEnding::stats("no line breaks");
+        assert_eq!(stats.breaks(), 0);
+        assert_eq!(stats.lines, 1);
+
+        let stats = LineEnding::stats("");
+        assert_eq!(stats, LineEndingStats::default());
+    }
+
+    #[test]
+    fn it_detects_line_ending_with_confidence() {
+        let detection = LineEnding::detect("\rthis\rprefers\r\nobsolete endings\n");
+        assert_eq!(detection.ending, Some(LineEnding::CR));
+        assert_eq!(detection.confidence, 0.5);
+        assert_eq!(
+            detection.counts,
+            LineEnding::stats("\rthis\rprefers\r\nobsolete endings\n")
+        );
+
+        let detection = LineEnding::detect("\r\nthis\r\nis\nambiguous\n?\r\r");
+        assert_eq!(detection.ending, None);
+
+        let detection = LineEnding::detect("");
+        assert_eq!(detection.ending, None);
+        assert_eq!(detection.confidence, 0.0);
+    }
+
+    #[test]
+    fn it_finds_line_ending_strictly() {
+        let eol = LineEnding::find_strict("\rthis\rprefers\r\nobsolete endings\n");
+        assert_eq!(eol.unwrap(), LineEnding::CR);
+
+        let err = LineEnding::find_strict("\r\nthis\r\nis\nambiguous\n?\r\r").unwrap_err();
+        assert_eq!((err.counts.cr, err.counts.lf, err.counts.crlf), (2, 2, 2));
+
+        let err = LineEnding::find_strict("no line breaks").unwrap_err();
+        assert_eq!(err.counts.breaks(), 0);
+        assert_eq!(err.counts.lines, 1);
+    }
+
+    #[test]
+    fn it_finds_preferred_line_ending_in_utf16() {
+        let text = "\rthis\rprefers\r\nobsolete endings\n";
+        let le: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let be: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+
+        assert_eq!(
+            LineEnding::find_in_utf16le(&le, LineEnding::LF),
+            LineEnding::CR
+        );
+        assert_eq!(
+            LineEnding::find_in_utf16be(&be, LineEnding::LF),
+            LineEnding::CR
+        );
+        assert_eq!(
+            LineEnding::find_in_utf16(&le, LineEnding::LF),
+            LineEnding::CR
+        );
+        assert_eq!(
+            LineEnding::find_in_utf16([&[0xFE, 0xFF][..], &be].concat(), LineEnding::LF),
+            LineEnding::CR
+        );
+        assert_eq!(
+            LineEnding::find_in_utf16([&[0xFF, 0xFE][..], &le].concat(), LineEnding::LF),
+            LineEnding::CR
+        );
+        // w/o a byte order mark big-endian content is misread
+        assert_eq!(
+            LineEnding::find_in_utf16(&be, LineEnding::LF),
+            LineEnding::LF
+        );
+        assert_eq!(
+            LineEnding::find_in_utf16([], LineEnding::CRLF),
+            LineEnding::CRLF
+        );
+    }
+
+    #[test]
+    fn it_analyzes_content_with_and_without_byte_order_mark() {
+        let text = "one\r\ntwo\r\nthree\n";
+        let utf16be: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        let utf32le: Vec<u8> = text
+            .chars()
+            .flat_map(|c| u32::from(c).to_le_bytes())
+            .collect();
+
+        for (bytes, bom) in [
+            (text.as_bytes().to_vec(), None),
+            (
+                [&[0xEF, 0xBB, 0xBF][..], text.as_bytes()].concat(),
+                Some(Bom::Utf8),
+            ),
+            ([&[0xFE, 0xFF][..], &utf16be].concat(), Some(Bom::Utf16BE)),
+            (
+                [&[0xFF, 0xFE, 0, 0][..], &utf32le].concat(),
+                Some(Bom::Utf32LE),
+            ),
+        ] {
+            let analysis = LineEnding::analyze(&bytes);
+            assert_eq!(analysis.bom, bom);
+            assert_eq!(analysis.detection, LineEnding::detect(text));
+        }
+
+        let analysis = LineEnding::analyze([]);
+        assert_eq!(analysis, Analysis::default());
+    }
+
+    #[test]
+    fn it_finds_preferred_line_ending_in_reader() {
+        let input = "\rthis\rprefers\r\nobsolete endings\n";
+        let eol = LineEnding::find_in_reader(input.as_bytes(), LineEnding::CRLF).unwrap();
+        assert_eq!(eol, LineEnding::CR);
+
+        let input = "\r\nthis\r\nprefers\r\nwindows-style endings\n";
+        let eol = LineEnding::find_in_reader(input.as_bytes(), LineEnding::LF).unwrap();
+        assert_eq!(eol, LineEnding::CRLF);
+
+        let input = "\r\nthis\r\nis\nambiguous\n?\r\r";
+        let eol = LineEnding::find_in_reader(input.as_bytes(), LineEnding::LF).unwrap();
+        assert_eq!(eol, LineEnding::LF);
+
+        let eol = LineEnding::find_in_reader("".as_bytes(), LineEnding::CR).unwrap();
+        assert_eq!(eol, LineEnding::CR);
+    }
+
+    #[test]
+    fn it_finds_preferred_line_ending_in_reader_when_crlf_spans_reads() {
+        let input = "a\r\nb\r\nc\r\nd\ne\n\r";
+
+        for capacity in 1..input.len() {
+            let reader = BufReader::with_capacity(capacity, input.as_bytes());
+            let eol = LineEnding::find_in_reader(reader, LineEnding::LF).unwrap();
+            assert_eq!(eol, LineEnding::CRLF, "capacity: {}", capacity);
+        }
+    }
+
+    #[test]
+    fn it_fails_to_find_line_ending_in_reader_when_read_fails() {
+        struct Broken;
+
+        impl io::Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("nope"))
+            }
+        }
+
+        let err = LineEnding::find_in_reader(BufReader::new(Broken), LineEnding::LF).unwrap_err();
+        assert_eq!(err.to_string(), "nope");
+    }
+
+    #[test]
+    fn it_finds_preferred_line_ending_in_file() {
+        let path = std::env::temp_dir().join(format!(
+            "detect-newline-style-{}-find-in-file.txt",
+            std::process::id()
+        ));
+        let head = "one\r\ntwo\r\n";
+        let tail = "three\nfour\nfive\n";
+        std::fs::write(&path, format!("{}{}", head, tail)).unwrap();
+
+        let eol = LineEnding::find_in_file(&path, LineEnding::CR).unwrap();
+        assert_eq!(eol, LineEnding::LF);
+
+        let limit = head.len() as u64;
+        let eol = LineEnding::find_in_file_with_limit(&path, LineEnding::CR, limit).unwrap();
+        assert_eq!(eol, LineEnding::CRLF);
+
+        let eol = LineEnding::find_in_file_with_limit(&path, LineEnding::CR, 0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(eol, LineEnding::CR);
+    }
+
+    #[test]
+    fn it_fails_to_find_line_ending_in_file_when_file_is_missing() {
+        let path = std::env::temp_dir().join("detect-newline-style-nope.txt");
+        let err = LineEnding::find_in_file(&path, LineEnding::LF).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn it_normalizes_line_endings() {
+        let input = "\rthis\r\nis\nmixed\r\r\n\n";
+        assert_eq!(LineEnding::CR.normalize(input), "\rthis\ris\rmixed\r\r\r");
+        assert_eq!(LineEnding::LF.normalize(input), "\nthis\nis\nmixed\n\n\n");
+        assert_eq!(
+            LineEnding::CRLF.normalize(input),
+            "\r\nthis\r\nis\r\nmixed\r\n\r\n\r\n"
+        );
+        assert_eq!(LineEnding::CRLF.normalize("ünïcödé\n✓"), "ünïcödé\r\n✓");
+    }
+
+    #[test]
+    fn it_borrows_text_when_line_endings_are_already_normalized() {
+        for (eol, input) in [
+            (LineEnding::CR, "one\rtwo\r"),
+            (LineEnding::LF, "one\ntwo\n"),
+            (LineEnding::CRLF, "one\r\ntwo\r\n"),
+            (LineEnding::LF, "no line breaks"),
+            (LineEnding::LF, ""),
+        ] {
+            let text = eol.normalize(input);
+            assert!(matches!(text, Cow::Borrowed(t) if t == input));
+        }
+
+        let text = LineEnding::LF.normalize("one\r\ntwo\n");
+        assert!(matches!(text, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn it_finds_preferred_line_ending_in_invalid_utf8() {
+        let input = b"\xC3\r\xFF\xFEthis\r\x80prefers\r\nobsolete\xF0\x28 endings\n\xC3";
+        assert_eq!(
+            LineEnding::find_in_bytes(input, LineEnding::LF),
+            LineEnding::CR
+        );
+        assert_eq!(
+            LineEnding::find_in_bytes(b"\xFF\xFE", LineEnding::CRLF),
+            LineEnding::CRLF
+        );
+        assert_eq!(
+            LineEnding::find_in_bytes([], LineEnding::CR),
+            LineEnding::CR
+        );
+    }
+
+    #[test]
+    fn it_finds_first_line_ending() {
+        for (input, eol) in [
+            ("one\ntwo\r\nthree\r\n", LineEnding::LF),
+            ("one\r\ntwo\nthree\n", LineEnding::CRLF),
+            ("one\rtwo\nthree\n", LineEnding::CR),
+            ("\r", LineEnding::CR),
+            ("\r\n", LineEnding::CRLF),
+            ("no line breaks", LineEnding::LF),
+            ("", LineEnding::LF),
+        ] {
+            assert_eq!(
+                LineEnding::find_or_use_first(input, LineEnding::LF),
+                eol,
+                "input: {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_finds_last_line_ending() {
+        for (input, eol) in [
+            ("one\r\ntwo\r\nthree\n", LineEnding::LF),
+            ("one\ntwo\nthree\r\n", LineEnding::CRLF),
+            ("one\ntwo\nthree\r", LineEnding::CR),
+            ("\n", LineEnding::LF),
+            ("\r\n", LineEnding::CRLF),
+            ("no line breaks", LineEnding::CR),
+            ("", LineEnding::CR),
+        ] {
+            assert_eq!(
+                LineEnding::find_or_use_last(input, LineEnding::CR),
+                eol,
+                "input: {:?}",
+                input
+            );
+        }
+    }
+
     #[test]
     fn it_finds_preferred_line_ending_defaulting_to_cr_endings() {
         let input = "\rthis\rprefers\r\nobsolete endings\n";
@@ -0,0 +1,413 @@
+use detect_newline_style::{convert, LineEnding, LineEndingDetector};
+use std::env;
+use std::error::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+type DynError = Box<dyn Error>;
+
+const USAGE: &str = "Usage: eol <path>... [options]
+
+Prints the line ending style (CR, LF, CRLF, mixed, or none) each file uses.
+Paths may include `*` and `?` wildcards in their last component.
+
+Options:
+  --stats          Also print counts of each line ending style
+  --to <ending>    Convert line endings (lf / unix, crlf / windows / dos, cr / mac)
+                   printing the result - combine w/ --write to rewrite files
+  --write          Rewrite files in place (requires --to)
+  -h, --help       Print this message";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Options {
+    paths: Vec<String>,
+    stats: bool,
+    to: Option<LineEnding>,
+    write: bool,
+    help: bool,
+}
+
+impl Options {
+    fn new(args: Vec<String>) -> Result<Self, DynError> {
+        let mut opts = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--stats" => opts.stats = true,
+                "--to" => opts.to = Some(LineEnding::from_str(&value(&arg, args.next())?)?),
+                "--write" => opts.write = true,
+                "-h" | "--help" => opts.help = true,
+                _ if arg.starts_with('-') => {
+                    return Err(format!("Unrecognized argument! Received: '{}'", arg).into());
+                }
+                _ => opts.paths.push(arg),
+            }
+        }
+
+        if opts.help {
+            return Ok(opts);
+        }
+
+        if opts.paths.is_empty() {
+            return Err(format!("Missing path!\n\n{}", USAGE).into());
+        }
+
+        if opts.write && opts.to.is_none() {
+            return Err("Missing '--to' for '--write'!".into());
+        }
+
+        Ok(opts)
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, DynError> {
+    match value {
+        Some(v) if !v.starts_with('-') => Ok(v),
+        _ => Err(format!("Missing value for '{}'!", flag).into()),
+    }
+}
+
+fn main() {
+    if let Err(e) = try_main() {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn try_main() -> Result<(), DynError> {
+    let mut args: Vec<String> = env::args().collect();
+
+    args.remove(0); // drop executable path
+
+    let opts = Options::new(args)?;
+
+    if opts.help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let mut paths = vec![];
+
+    for pattern in &opts.paths {
+        paths.extend(expand(pattern)?);
+    }
+
+    for path in paths {
+        match &opts.to {
+            Some(eol) if opts.write => rewrite(&path, eol)?,
+            Some(eol) => {
+                convert(File::open(&path)?, io::stdout().lock(), *eol)?;
+            }
+            None => println!("{}", line(&path, opts.stats)?),
+        }
+    }
+
+    Ok(())
+}
+
+// tab-separated `path` and line ending style (plus counts w/ `--stats`) for
+// easy use w/ `cut`, `awk`, etc
+fn line(path: &Path, stats: bool) -> Result<String, DynError> {
+    let mut detector = LineEndingDetector::default();
+    detector.feed_reader(BufReader::new(File::open(path)?))?;
+    let counts = detector.stats();
+    let style = match counts.breaks() {
+        0 => "none",
+        _ if counts.is_mixed() => "mixed",
+        _ => detector.finish().name(),
+    };
+
+    match stats {
+        true => Ok(format!(
+            "{}\t{}\tcr={} lf={} crlf={} lines={}",
+            path.display(),
+            style,
+            counts.cr,
+            counts.lf,
+            counts.crlf,
+            counts.lines
+        )),
+        false => Ok(format!("{}\t{}", path.display(), style)),
+    }
+}
+
+// converts the file's line endings via a temporary sibling file which then
+// replaces the original so a failure part-way leaves it untouched - symlinks
+// are resolved so the file they point at is rewritten (keeping the link) and
+// the original's permissions are carried over
+fn rewrite(path: &Path, eol: &LineEnding) -> Result<(), DynError> {
+    let path = fs::canonicalize(path)?;
+    let permissions = fs::metadata(&path)?.permissions();
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".eol-tmp");
+    let tmp = PathBuf::from(tmp);
+
+    // never clobber (or follow a link at) a pre-existing temporary path
+    let file = OpenOptions::new().write(true).create_new(true).open(&tmp)?;
+    let result = convert(File::open(&path)?, file, *eol)
+        .and_then(|_| fs::set_permissions(&tmp, permissions));
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+
+    fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+// expands `*` and `?` wildcards in the pattern's last path component - other
+// patterns are returned as-is
+fn expand(pattern: &str) -> Result<Vec<PathBuf>, DynError> {
+    let path = Path::new(pattern);
+    let name = match path.file_name().and_then(|n| n.to_str()) {
+        Some(name) if name.contains(['*', '?']) => name,
+        _ => return Ok(vec![path.to_path_buf()]),
+    };
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let mut paths = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+
+        if entry.file_type()?.is_file() && matches(name, &file_name.to_string_lossy()) {
+            paths.push(match path.parent() {
+                Some(parent) => parent.join(file_name),
+                None => PathBuf::from(file_name),
+            });
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(format!("No files match! Received: '{}'", pattern).into());
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+// whether `name` matches the wildcard `pattern` (`*` any run of characters,
+// `?` any single character)
+fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn tmp_file(name: &str, content: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("eol-{}-{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn it_parses_paths() {
+        let opts = Options::new(to_args(&["one.txt", "src/*.rs"])).unwrap();
+        assert_eq!(
+            opts,
+            Options {
+                paths: to_args(&["one.txt", "src/*.rs"]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_parses_options() {
+        let args = to_args(&["--to", "windows", "one.txt", "--write", "--stats"]);
+        let opts = Options::new(args).unwrap();
+        assert_eq!(opts.paths, to_args(&["one.txt"]));
+        assert_eq!(opts.to, Some(LineEnding::CRLF));
+        assert!(opts.write);
+        assert!(opts.stats);
+
+        let opts = Options::new(to_args(&["--help"])).unwrap();
+        assert!(opts.help);
+    }
+
+    #[test]
+    fn it_fails_when_path_is_missing() {
+        let err = Options::new(to_args(&["--stats"])).unwrap_err();
+        assert!(err.to_string().starts_with("Missing path!"));
+    }
+
+    #[test]
+    fn it_fails_when_argument_is_unrecognized() {
+        let err = Options::new(to_args(&["one.txt", "--nope"])).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized argument! Received: '--nope'");
+    }
+
+    #[test]
+    fn it_fails_when_option_value_is_missing_or_unrecognized() {
+        let err = Options::new(to_args(&["one.txt", "--to"])).unwrap_err();
+        assert_eq!(err.to_string(), "Missing value for '--to'!");
+
+        let err = Options::new(to_args(&["one.txt", "--to", "nope"])).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized input: nope");
+    }
+
+    #[test]
+    fn it_fails_when_writing_without_target() {
+        let err = Options::new(to_args(&["one.txt", "--write"])).unwrap_err();
+        assert_eq!(err.to_string(), "Missing '--to' for '--write'!");
+    }
+
+    #[test]
+    fn it_matches_wildcards() {
+        assert!(matches("*.rs", "main.rs"));
+        assert!(matches("*", ""));
+        assert!(matches("ma?n.*", "main.rs"));
+        assert!(matches("*a*b*", "xxaxxbxx"));
+        assert!(matches("main.rs", "main.rs"));
+        assert!(!matches("*.rs", "main.rs.bak"));
+        assert!(!matches("?", ""));
+        assert!(!matches("*a*b", "xxaxxbxx"));
+    }
+
+    #[test]
+    fn it_expands_wildcards() {
+        let a = tmp_file("expand-a.txt", "");
+        let b = tmp_file("expand-b.txt", "");
+        let pattern = env::temp_dir().join(format!("eol-{}-expand-?.txt", std::process::id()));
+        let paths = expand(pattern.to_str().unwrap()).unwrap();
+        fs::remove_file(&a).unwrap();
+        fs::remove_file(&b).unwrap();
+        assert_eq!(paths, vec![a, b]);
+
+        assert_eq!(
+            expand("Cargo.toml").unwrap(),
+            vec![PathBuf::from("Cargo.toml")]
+        );
+        assert_eq!(
+            expand("src/ma*.rs").unwrap(),
+            vec![PathBuf::from("src/main.rs")]
+        );
+
+        let err = expand("nope-*.txt").unwrap_err();
+        assert_eq!(err.to_string(), "No files match! Received: 'nope-*.txt'");
+    }
+
+    #[test]
+    fn it_formats_line() {
+        let path = tmp_file("line.txt", "one\r\ntwo\r\nthree");
+        let plain = line(&path, false).unwrap();
+        let stats = line(&path, true).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(plain, format!("{}\tCRLF", path.display()));
+        assert_eq!(
+            stats,
+            format!("{}\tCRLF\tcr=0 lf=0 crlf=2 lines=3", path.display())
+        );
+    }
+
+    #[test]
+    fn it_formats_line_when_style_is_mixed_or_missing() {
+        let mixed = tmp_file("mixed.txt", "one\r\ntwo\n");
+        let mostly = tmp_file("mostly.txt", &("a\n".repeat(55) + &"b\r\n".repeat(45)));
+        let none = tmp_file("none.txt", "one");
+        let lines = (
+            line(&mixed, false).unwrap(),
+            line(&mostly, false).unwrap(),
+            line(&none, false).unwrap(),
+        );
+        fs::remove_file(&mixed).unwrap();
+        fs::remove_file(&mostly).unwrap();
+        fs::remove_file(&none).unwrap();
+        assert_eq!(lines.0, format!("{}\tmixed", mixed.display()));
+        assert_eq!(lines.1, format!("{}\tmixed", mostly.display()));
+        assert_eq!(lines.2, format!("{}\tnone", none.display()));
+    }
+
+    #[test]
+    fn it_rewrites_file() {
+        let path = tmp_file("rewrite.txt", "one\r\ntwo\rthree\n");
+        rewrite(&path, &LineEnding::LF).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(content, "one\ntwo\nthree\n");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn it_rewrites_file_preserving_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = tmp_file("rewrite-mode.sh", "#!/bin/sh\r\necho hi\r\n");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        rewrite(&path, &LineEnding::LF).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o755);
+        assert_eq!(content, "#!/bin/sh\necho hi\n");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn it_rewrites_symlink_target() {
+        let target = tmp_file("rewrite-target.txt", "one\r\ntwo\r\n");
+        let link = env::temp_dir().join(format!("eol-{}-rewrite-link.txt", std::process::id()));
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        rewrite(&link, &LineEnding::LF).unwrap();
+        let is_link = fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink();
+        let content = fs::read_to_string(&target).unwrap();
+        fs::remove_file(&link).unwrap();
+        fs::remove_file(&target).unwrap();
+        assert!(is_link);
+        assert_eq!(content, "one\ntwo\n");
+    }
+
+    #[test]
+    fn it_fails_to_rewrite_when_temporary_file_exists() {
+        let path = tmp_file("rewrite-busy.txt", "one\r\n");
+        let tmp = tmp_file("rewrite-busy.txt.eol-tmp", "keep me");
+        let err = rewrite(&path, &LineEnding::LF).unwrap_err();
+        let contents = (fs::read_to_string(&path), fs::read_to_string(&tmp));
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(&tmp).unwrap();
+        assert!(err.to_string().contains("exists"), "{}", err);
+        assert_eq!(contents.0.unwrap(), "one\r\n");
+        assert_eq!(contents.1.unwrap(), "keep me");
+    }
+}
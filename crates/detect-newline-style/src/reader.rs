@@ -0,0 +1,177 @@
+use crate::{normalize_chunk, LineEnding};
+use std::io::{self, Read};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Wraps a reader converting every line ending (CR, LF, or CRLF) read
+/// through it to the configured style - content is converted a chunk at a
+/// time so it never has to be loaded into memory all at once
+#[derive(Debug)]
+pub struct NormalizingReader<R: Read> {
+    inner: R,
+    eol: LineEnding,
+    // the last byte read was `\r` so a leading `\n` completes its CRLF pair
+    after_cr: bool,
+    chunk: Vec<u8>,
+    // converted content not yet handed to the caller
+    pending: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: Read> NormalizingReader<R> {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The reader to normalize content from
+    /// * `eol` - The line ending style to read
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Read;
+    /// use detect_newline_style::{LineEnding, NormalizingReader};
+    /// let mut reader = NormalizingReader::new("one\r\ntwo\rthree\n".as_bytes(), LineEnding::LF);
+    /// let mut text = String::new();
+    /// reader.read_to_string(&mut text).unwrap();
+    /// assert_eq!(text, "one\ntwo\nthree\n");
+    /// ```
+    pub fn new(inner: R, eol: LineEnding) -> NormalizingReader<R> {
+        NormalizingReader {
+            inner,
+            eol,
+            after_cr: false,
+            chunk: vec![0; CHUNK_SIZE],
+            pending: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader - reading from it
+    /// directly bypasses normalization
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this instance returning the underlying reader - converted
+    /// content which hasn't been read yet is lost
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for NormalizingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // a chunk holding only the `\n` of a split CRLF pair converts to
+        // nothing so keep reading until there's output or the input ends
+        while self.pos == self.pending.len() {
+            let len = self.inner.read(&mut self.chunk)?;
+
+            if len == 0 {
+                return Ok(0);
+            }
+
+            self.pending.clear();
+            self.pos = 0;
+            normalize_chunk(
+                &self.eol,
+                &mut self.after_cr,
+                &self.chunk[..len],
+                &mut self.pending,
+            );
+        }
+
+        let len = buf.len().min(self.pending.len() - self.pos);
+        buf[..len].copy_from_slice(&self.pending[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // yields its content one small slice at a time
+    struct Trickle<'a> {
+        chunks: std::slice::Chunks<'a, u8>,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    Ok(chunk.len())
+                }
+                None => Ok(0),
+            }
+        }
+    }
+
+    fn normalize<R: Read>(reader: R, eol: LineEnding) -> String {
+        let mut text = String::new();
+        NormalizingReader::new(reader, eol)
+            .read_to_string(&mut text)
+            .unwrap();
+        text
+    }
+
+    #[test]
+    fn it_initializes() {
+        let mut reader = NormalizingReader::new("abc".as_bytes(), LineEnding::CR);
+        assert_eq!(reader.eol, LineEnding::CR);
+        assert!(!reader.after_cr);
+        assert_eq!(reader.get_ref(), b"abc");
+        assert_eq!(reader.get_mut(), b"abc");
+        assert_eq!(reader.into_inner(), b"abc");
+    }
+
+    #[test]
+    fn it_normalizes_line_endings() {
+        let input = "\rthis\r\nis\nmixed\r\r\n\n".as_bytes();
+        assert_eq!(normalize(input, LineEnding::CR), "\rthis\ris\rmixed\r\r\r");
+        assert_eq!(normalize(input, LineEnding::LF), "\nthis\nis\nmixed\n\n\n");
+        assert_eq!(
+            normalize(input, LineEnding::CRLF),
+            "\r\nthis\r\nis\r\nmixed\r\n\r\n\r\n"
+        );
+        assert_eq!(normalize("".as_bytes(), LineEnding::CRLF), "");
+    }
+
+    #[test]
+    fn it_normalizes_regardless_of_read_size() {
+        let input = "\r\nthis\r\nis\nmixed\r\r\n\nünïcödé\r";
+        let expected = LineEnding::CRLF.normalize(input);
+
+        for size in 1..input.len() {
+            let trickle = Trickle {
+                chunks: input.as_bytes().chunks(size),
+            };
+            assert_eq!(
+                normalize(trickle, LineEnding::CRLF),
+                expected,
+                "size: {}",
+                size
+            );
+        }
+    }
+
+    #[test]
+    fn it_normalizes_into_small_buffers() {
+        let mut reader = NormalizingReader::new("a\nb\n".as_bytes(), LineEnding::CRLF);
+        let mut buf = [0u8; 1];
+        let mut out = Vec::new();
+
+        while reader.read(&mut buf).unwrap() > 0 {
+            out.push(buf[0]);
+        }
+
+        assert_eq!(out, b"a\r\nb\r\n");
+    }
+}
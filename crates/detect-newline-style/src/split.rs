@@ -0,0 +1,66 @@
+use memchr::memchr2;
+
+/// An iterator over the lines of some text including their line endings (CR,
+/// LF, or CRLF) - see [`LineEnding::split_inclusive()`](crate::LineEnding::split_inclusive)
+#[derive(Clone, Debug)]
+pub struct SplitInclusive<'a> {
+    text: &'a str,
+}
+
+impl<'a> SplitInclusive<'a> {
+    pub(crate) fn new(text: &'a str) -> SplitInclusive<'a> {
+        SplitInclusive { text }
+    }
+}
+
+impl<'a> Iterator for SplitInclusive<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        let bytes = self.text.as_bytes();
+        let end = match memchr2(b'\r', b'\n', bytes) {
+            Some(idx) if bytes[idx] == b'\r' && bytes.get(idx + 1) == Some(&b'\n') => idx + 2,
+            Some(idx) => idx + 1,
+            None => bytes.len(),
+        };
+
+        let (line, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_splits_lines_including_line_endings() {
+        let lines: Vec<&str> = SplitInclusive::new("\rone\r\ntwo\nthree\r\r\nfour").collect();
+        assert_eq!(
+            lines,
+            vec!["\r", "one\r\n", "two\n", "three\r", "\r\n", "four"]
+        );
+    }
+
+    #[test]
+    fn it_splits_text_ending_with_a_line_ending() {
+        let lines: Vec<&str> = SplitInclusive::new("one\ntwo\r").collect();
+        assert_eq!(lines, vec!["one\n", "two\r"]);
+    }
+
+    #[test]
+    fn it_yields_nothing_when_text_is_empty() {
+        assert_eq!(SplitInclusive::new("").next(), None);
+    }
+
+    #[test]
+    fn it_yields_text_when_it_has_no_line_breaks() {
+        let lines: Vec<&str> = SplitInclusive::new("ünïcödé").collect();
+        assert_eq!(lines, vec!["ünïcödé"]);
+    }
+}
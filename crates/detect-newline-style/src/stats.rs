@@ -0,0 +1,109 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Counts of each line ending style found in some text - see
+/// [`LineEnding::stats()`](crate::LineEnding::stats)
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct LineEndingStats {
+    /// Number of CR-style line endings (`"\r"`)
+    pub cr: usize,
+    /// Number of LF-style line endings (`"\n"`)
+    pub lf: usize,
+    /// Number of CRLF-style line endings (`"\r\n"`)
+    pub crlf: usize,
+    /// Number of lines - a trailing line without a line ending counts too
+    pub lines: usize,
+}
+
+impl LineEndingStats {
+    /// Total number of line endings regardless of style
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// let stats = LineEnding::stats("one\ntwo\r\nthree");
+    /// assert_eq!(stats.breaks(), 2);
+    /// assert_eq!(stats.lines, 3);
+    /// ```
+    pub fn breaks(&self) -> usize {
+        self.cr + self.lf + self.crlf
+    }
+
+    /// Whether more than one line ending style was found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::LineEnding;
+    /// assert!(LineEnding::stats("one\ntwo\r\nthree").is_mixed());
+    /// assert!(!LineEnding::stats("one\ntwo\nthree").is_mixed());
+    /// ```
+    pub fn is_mixed(&self) -> bool {
+        [self.cr, self.lf, self.crlf]
+            .iter()
+            .filter(|n| **n > 0)
+            .count()
+            > 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        let stats = LineEndingStats::default();
+        assert_eq!((stats.cr, stats.lf, stats.crlf, stats.lines), (0, 0, 0, 0));
+        assert_eq!(stats.breaks(), 0);
+        assert!(!stats.is_mixed());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_serializes_with_serde() {
+        let stats = LineEndingStats {
+            cr: 1,
+            lf: 2,
+            crlf: 3,
+            lines: 6,
+        };
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(json, r#"{"cr":1,"lf":2,"crlf":3,"lines":6}"#);
+        assert_eq!(
+            serde_json::from_str::<LineEndingStats>(&json).unwrap(),
+            stats
+        );
+    }
+
+    #[test]
+    fn it_totals_line_breaks() {
+        let stats = LineEndingStats {
+            cr: 1,
+            lf: 2,
+            crlf: 3,
+            lines: 7,
+        };
+        assert_eq!(stats.breaks(), 6);
+    }
+
+    #[test]
+    fn it_determines_when_line_endings_are_mixed() {
+        let stats = LineEndingStats {
+            lf: 3,
+            lines: 3,
+            ..Default::default()
+        };
+        assert!(!stats.is_mixed());
+
+        let stats = LineEndingStats {
+            lf: 3,
+            crlf: 1,
+            lines: 4,
+            ..Default::default()
+        };
+        assert!(stats.is_mixed());
+    }
+}
@@ -0,0 +1,276 @@
+use crate::LineEnding;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Line endings including the Unicode line separators (NEL, LS, PS) plus
+/// vertical tab and form feed - found in documents produced by mainframes
+/// and word processors. See [`LineEnding`](crate::LineEnding) for the
+/// everyday CR, LF, and CRLF styles.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum UnicodeLineEnding {
+    /// CR-style line ending (`"\r"`)
+    CR,
+    /// LF-style line ending (`"\n"`)
+    #[default]
+    LF,
+    /// CRLF-style line ending (`"\r\n"`)
+    CRLF,
+    /// Next line (`"\u{85}"`) typically used by EBCDIC-based mainframes
+    NEL,
+    /// Line separator (`"\u{2028}"`)
+    LS,
+    /// Paragraph separator (`"\u{2029}"`)
+    PS,
+    /// Vertical tab (`"\u{b}"`)
+    VT,
+    /// Form feed (`"\u{c}"`) typically used as a page break
+    FF,
+}
+
+impl UnicodeLineEnding {
+    pub(crate) const ALL: [UnicodeLineEnding; 8] = [
+        UnicodeLineEnding::CR,
+        UnicodeLineEnding::LF,
+        UnicodeLineEnding::CRLF,
+        UnicodeLineEnding::NEL,
+        UnicodeLineEnding::LS,
+        UnicodeLineEnding::PS,
+        UnicodeLineEnding::VT,
+        UnicodeLineEnding::FF,
+    ];
+
+    /// Determines which line ending a given string uses (any of CR, LF, CRLF,
+    /// NEL, LS, PS, VT, or FF)
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text you want to analyze
+    /// * `default` - The default line ending to use when text has no preference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::UnicodeLineEnding;
+    /// let eol = UnicodeLineEnding::find("one\u{2028}two\u{2028}three\n", UnicodeLineEnding::LF);
+    /// assert_eq!(eol, UnicodeLineEnding::LS);
+    /// ```
+    pub fn find<S: AsRef<str>>(text: S, default: UnicodeLineEnding) -> UnicodeLineEnding {
+        let counts = scan(text.as_ref(), None).counts;
+        let max = counts.iter().copied().max().unwrap_or_default();
+
+        if max == 0 || counts.iter().filter(|n| **n == max).count() > 1 {
+            return default;
+        }
+
+        match counts.iter().position(|n| *n == max) {
+            Some(idx) => UnicodeLineEnding::ALL[idx].clone(),
+            None => default,
+        }
+    }
+
+    /// Returns the line ending's characters (e.g. `"\u{2028}"`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::UnicodeLineEnding;
+    /// assert_eq!(UnicodeLineEnding::NEL.as_str(), "\u{85}");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            UnicodeLineEnding::CR => "\r",
+            UnicodeLineEnding::LF => "\n",
+            UnicodeLineEnding::CRLF => "\r\n",
+            UnicodeLineEnding::NEL => "\u{85}",
+            UnicodeLineEnding::LS => "\u{2028}",
+            UnicodeLineEnding::PS => "\u{2029}",
+            UnicodeLineEnding::VT => "\u{b}",
+            UnicodeLineEnding::FF => "\u{c}",
+        }
+    }
+
+    /// Returns the line ending's human-readable name (e.g. `"NEL"`) - also
+    /// available via alternate formatting (`{:#}`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use detect_newline_style::UnicodeLineEnding;
+    /// assert_eq!(UnicodeLineEnding::PS.name(), "PS");
+    /// assert_eq!(format!("{:#}", UnicodeLineEnding::FF), "FF");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            UnicodeLineEnding::CR => "CR",
+            UnicodeLineEnding::LF => "LF",
+            UnicodeLineEnding::CRLF => "CRLF",
+            UnicodeLineEnding::NEL => "NEL",
+            UnicodeLineEnding::LS => "LS",
+            UnicodeLineEnding::PS => "PS",
+            UnicodeLineEnding::VT => "VT",
+            UnicodeLineEnding::FF => "FF",
+        }
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            UnicodeLineEnding::CR => 0,
+            UnicodeLineEnding::LF => 1,
+            UnicodeLineEnding::CRLF => 2,
+            UnicodeLineEnding::NEL => 3,
+            UnicodeLineEnding::LS => 4,
+            UnicodeLineEnding::PS => 5,
+            UnicodeLineEnding::VT => 6,
+            UnicodeLineEnding::FF => 7,
+        }
+    }
+}
+
+// occurrences of each line ending (indexed like `UnicodeLineEnding::ALL`)
+// plus the first and last ones found
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Scan {
+    pub(crate) counts: [usize; 8],
+    pub(crate) first: Option<UnicodeLineEnding>,
+    pub(crate) last: Option<UnicodeLineEnding>,
+}
+
+// counts line endings until `max_breaks` (if any) have been found
+pub(crate) fn scan(text: &str, max_breaks: Option<usize>) -> Scan {
+    let mut scan = Scan::default();
+    let mut chars = text.chars().peekable();
+    let mut breaks = 0;
+
+    while let Some(c) = chars.next() {
+        if max_breaks.is_some_and(|max| breaks >= max) {
+            break;
+        }
+
+        let eol = match c {
+            '\r' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                UnicodeLineEnding::CRLF
+            }
+            '\r' => UnicodeLineEnding::CR,
+            '\n' => UnicodeLineEnding::LF,
+            '\u{85}' => UnicodeLineEnding::NEL,
+            '\u{2028}' => UnicodeLineEnding::LS,
+            '\u{2029}' => UnicodeLineEnding::PS,
+            '\u{b}' => UnicodeLineEnding::VT,
+            '\u{c}' => UnicodeLineEnding::FF,
+            _ => continue,
+        };
+
+        scan.counts[eol.index()] += 1;
+        scan.first.get_or_insert(eol.clone());
+        scan.last = Some(eol);
+        breaks += 1;
+    }
+
+    scan
+}
+
+impl Display for UnicodeLineEnding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.name());
+        }
+
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<LineEnding> for UnicodeLineEnding {
+    fn from(eol: LineEnding) -> UnicodeLineEnding {
+        match eol {
+            LineEnding::CR => UnicodeLineEnding::CR,
+            LineEnding::LF => UnicodeLineEnding::LF,
+            LineEnding::CRLF => UnicodeLineEnding::CRLF,
+        }
+    }
+}
+
+impl TryFrom<UnicodeLineEnding> for LineEnding {
+    type Error = Box<dyn Error>;
+
+    fn try_from(eol: UnicodeLineEnding) -> Result<LineEnding, Box<dyn Error>> {
+        match eol {
+            UnicodeLineEnding::CR => Ok(LineEnding::CR),
+            UnicodeLineEnding::LF => Ok(LineEnding::LF),
+            UnicodeLineEnding::CRLF => Ok(LineEnding::CRLF),
+            _ => Err(format!("Unrecognized input: {}", eol.name()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        assert_eq!(UnicodeLineEnding::default(), UnicodeLineEnding::LF);
+    }
+
+    #[test]
+    fn it_finds_preferred_line_ending() {
+        for eol in UnicodeLineEnding::ALL {
+            let input = format!("one{0}two{0}three\n", eol.as_str());
+            assert_eq!(
+                UnicodeLineEnding::find(&input, UnicodeLineEnding::CR),
+                eol,
+                "eol: {:#}",
+                eol
+            );
+        }
+    }
+
+    #[test]
+    fn it_uses_default_when_preference_cannot_be_determined() {
+        let input = "one\u{85}two\u{2029}three";
+        let eol = UnicodeLineEnding::find(input, UnicodeLineEnding::FF);
+        assert_eq!(eol, UnicodeLineEnding::FF);
+
+        let eol = UnicodeLineEnding::find("", UnicodeLineEnding::VT);
+        assert_eq!(eol, UnicodeLineEnding::VT);
+    }
+
+    #[test]
+    fn it_indexes_every_line_ending() {
+        for (idx, eol) in UnicodeLineEnding::ALL.iter().enumerate() {
+            assert_eq!(eol.index(), idx);
+        }
+    }
+
+    #[test]
+    fn it_serializes_a_line_ending() {
+        assert_eq!(format!("{}", UnicodeLineEnding::NEL), "\u{85}");
+        assert_eq!(format!("{}", UnicodeLineEnding::CRLF), "\r\n");
+        assert_eq!(format!("{:#}", UnicodeLineEnding::LS), "LS");
+        assert_eq!(format!("{:#}", UnicodeLineEnding::VT), "VT");
+    }
+
+    #[test]
+    fn it_converts_to_and_from_line_ending() {
+        for eol in LineEnding::ALL {
+            let unicode = UnicodeLineEnding::from(eol);
+            assert_eq!(unicode.as_str(), eol.as_str());
+            assert_eq!(LineEnding::try_from(unicode).unwrap(), eol);
+        }
+
+        let err = LineEnding::try_from(UnicodeLineEnding::NEL).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized input: NEL");
+    }
+
+    #[test]
+    fn it_scans_until_max_breaks() {
+        let result = scan("one\u{85}two\r\nthree\u{2028}four\n", Some(3));
+        assert_eq!(result.counts, [0, 0, 1, 1, 1, 0, 0, 0]);
+        assert_eq!(result.first, Some(UnicodeLineEnding::NEL));
+        assert_eq!(result.last, Some(UnicodeLineEnding::LS));
+
+        let result = scan("one\u{c}two\r", None);
+        assert_eq!(result.counts, [1, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(result, scan("one\u{c}two\r", Some(2)));
+    }
+}
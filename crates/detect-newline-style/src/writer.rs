@@ -0,0 +1,134 @@
+use crate::{normalize_chunk, LineEnding};
+use std::io::{self, Write};
+
+/// Wraps a writer converting every line ending (CR, LF, or CRLF) written
+/// through it to the configured style - CRLF pairs split across separate
+/// writes are converted once
+#[derive(Debug)]
+pub struct NormalizingWriter<W: Write> {
+    inner: W,
+    eol: LineEnding,
+    // the last byte written was `\r` so a leading `\n` completes its CRLF pair
+    after_cr: bool,
+    scratch: Vec<u8>,
+}
+
+impl<W: Write> NormalizingWriter<W> {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The writer to send normalized content to
+    /// * `eol` - The line ending style to write
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use detect_newline_style::{LineEnding, NormalizingWriter};
+    /// let mut writer = NormalizingWriter::new(Vec::new(), LineEnding::CRLF);
+    /// writer.write_all(b"one\ntwo\rthree\r\n").unwrap();
+    /// assert_eq!(writer.into_inner(), b"one\r\ntwo\r\nthree\r\n");
+    /// ```
+    pub fn new(inner: W, eol: LineEnding) -> NormalizingWriter<W> {
+        NormalizingWriter {
+            inner,
+            eol,
+            after_cr: false,
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer - writing to it
+    /// directly bypasses normalization
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps this instance returning the underlying writer
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for NormalizingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.scratch.clear();
+        normalize_chunk(&self.eol, &mut self.after_cr, buf, &mut self.scratch);
+        self.inner.write_all(&self.scratch)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(chunks: &[&str], eol: LineEnding) -> String {
+        let mut writer = NormalizingWriter::new(Vec::new(), eol);
+
+        for chunk in chunks {
+            writer.write_all(chunk.as_bytes()).unwrap();
+        }
+
+        writer.flush().unwrap();
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn it_initializes() {
+        let mut writer = NormalizingWriter::new(Vec::<u8>::new(), LineEnding::CR);
+        assert_eq!(writer.eol, LineEnding::CR);
+        assert!(!writer.after_cr);
+        assert!(writer.get_ref().is_empty());
+        writer.get_mut().push(b'\n');
+        assert_eq!(writer.into_inner(), b"\n");
+    }
+
+    #[test]
+    fn it_normalizes_line_endings() {
+        let input = ["\rthis\r\nis\nmixed\r\r\n\n"];
+        assert_eq!(normalize(&input, LineEnding::CR), "\rthis\ris\rmixed\r\r\r");
+        assert_eq!(normalize(&input, LineEnding::LF), "\nthis\nis\nmixed\n\n\n");
+        assert_eq!(
+            normalize(&input, LineEnding::CRLF),
+            "\r\nthis\r\nis\r\nmixed\r\n\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn it_normalizes_crlf_split_across_writes() {
+        let input = ["one\r", "\ntwo\r", "", "\n", "\r", "three\n"];
+        assert_eq!(normalize(&input, LineEnding::LF), "one\ntwo\n\nthree\n");
+        assert_eq!(
+            normalize(&input, LineEnding::CRLF),
+            "one\r\ntwo\r\n\r\nthree\r\n"
+        );
+    }
+
+    #[test]
+    fn it_normalizes_regardless_of_write_size() {
+        let input = "\r\nthis\r\nis\nmixed\r\r\n\nünïcödé\r";
+        let expected = LineEnding::LF.normalize(input);
+
+        for size in 1..input.len() {
+            let mut writer = NormalizingWriter::new(Vec::new(), LineEnding::LF);
+
+            for chunk in input.as_bytes().chunks(size) {
+                writer.write_all(chunk).unwrap();
+            }
+
+            let output = String::from_utf8(writer.into_inner()).unwrap();
+            assert_eq!(output, expected, "size: {}", size);
+        }
+    }
+}
@@ -60,3 +60,208 @@ fn it_detects_crlf_style_endings_while_defaulting_to_crlf_endings() {
     let eol = LineEnding::find_or_use_crlf(input);
     assert_eq!(eol, LineEnding::CRLF);
 }
+
+#[test]
+fn it_detects_line_endings_in_a_buffered_reader() {
+    let input = "one\rtwo\r\nthree\r\nfour\n".repeat(1000);
+    let reader = std::io::BufReader::with_capacity(7, input.as_bytes());
+    let eol = LineEnding::find_in_reader(reader, LineEnding::LF).unwrap();
+    assert_eq!(eol, LineEnding::CRLF);
+}
+
+#[test]
+fn it_detects_line_endings_across_fed_chunks() {
+    let mut detector = LineEndingDetector::new(LineEnding::LF);
+
+    for chunk in ["one\r", "\ntwo\r", "\nthree", "\r", "\n", "four\n"] {
+        detector.feed(chunk);
+    }
+
+    assert_eq!(detector.finish(), LineEnding::CRLF);
+}
+
+#[test]
+fn it_detects_line_endings_in_a_file() {
+    let eol = LineEnding::find_in_file("README.md", LineEnding::CRLF).unwrap();
+    assert_eq!(eol, LineEnding::LF);
+}
+
+#[test]
+fn it_reports_line_ending_stats() {
+    let input = "one\ntwo\nthree\r\nfour\nfive\r\nsix\n";
+    let stats = LineEnding::stats(input);
+    assert_eq!((stats.cr, stats.lf, stats.crlf), (0, 4, 2));
+    assert_eq!(stats.lines, 6);
+    assert!(stats.is_mixed());
+}
+
+#[test]
+fn it_normalizes_to_the_detected_line_ending() {
+    let input = "one\r\ntwo\nthree\r\nfour\r\n";
+    let eol = LineEnding::find_or_use_lf(input);
+    assert_eq!(eol.normalize(input), "one\r\ntwo\r\nthree\r\nfour\r\n");
+}
+
+#[test]
+fn it_normalizes_line_endings_written_through_a_writer() {
+    use std::io::Write;
+
+    let mut writer = NormalizingWriter::new(Vec::new(), LineEnding::CRLF);
+    writeln!(writer, "one").unwrap();
+    write!(writer, "two\r").unwrap();
+    write!(writer, "\nthree\r").unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.into_inner(), b"one\r\ntwo\r\nthree\r\n");
+}
+
+#[test]
+fn it_normalizes_line_endings_read_through_a_reader() {
+    use std::io::Read;
+
+    let input = "one\r\ntwo\rthree\n".repeat(5000);
+    let mut reader = NormalizingReader::new(input.as_bytes(), LineEnding::LF);
+    let mut text = String::new();
+    reader.read_to_string(&mut text).unwrap();
+    assert_eq!(text, "one\ntwo\nthree\n".repeat(5000));
+}
+
+#[test]
+fn it_splits_lines_preserving_line_endings() {
+    let input = "one\rtwo\r\nthree\nfour\r\n";
+    let lines: Vec<&str> = LineEnding::split_inclusive(input).collect();
+    assert_eq!(lines, vec!["one\r", "two\r\n", "three\n", "four\r\n"]);
+    assert_eq!(lines.concat(), input);
+}
+
+#[test]
+fn it_parses_line_endings_from_names() {
+    let eol: LineEnding = "dos".parse().unwrap();
+    assert_eq!(eol, LineEnding::CRLF);
+    let eol: LineEnding = "unix".parse().unwrap();
+    assert_eq!(eol, LineEnding::LF);
+    let eol: LineEnding = "mac".parse().unwrap();
+    assert_eq!(eol, LineEnding::CR);
+}
+
+#[test]
+fn it_detects_line_endings_from_the_first_few_lines() {
+    let input = format!("{}{}", "one\r\n".repeat(100), "two\n".repeat(1000));
+    let mut detector = LineEndingDetector::new(LineEnding::LF);
+    detector
+        .max_breaks(100)
+        .feed_reader(input.as_bytes())
+        .unwrap();
+    assert!(detector.is_done());
+    assert_eq!(detector.finish(), LineEnding::CRLF);
+    assert_eq!(LineEnding::find(&input, LineEnding::CRLF), LineEnding::LF);
+}
+
+#[test]
+fn it_reports_detection_confidence_for_mixed_text() {
+    let input = format!("{}{}", "one\n".repeat(55), "two\r\n".repeat(45));
+    let detection = LineEnding::detect(input);
+    assert_eq!(detection.ending, Some(LineEnding::LF));
+    assert_eq!(detection.confidence, 0.55);
+    assert_eq!(detection.counts.lines, 100);
+}
+
+#[test]
+fn it_refuses_to_guess_ambiguous_line_endings() {
+    let err = LineEnding::find_strict("one\rtwo\nthree\r\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error: Ambiguous Line Ending! Received: 'CR: 1, LF: 1, CRLF: 1'"
+    );
+}
+
+#[test]
+fn it_detects_unicode_line_separators() {
+    let input = "one\u{85}two\u{85}three\u{85}four\r\n";
+    let eol = UnicodeLineEnding::find(input, UnicodeLineEnding::LF);
+    assert_eq!(eol, UnicodeLineEnding::NEL);
+    assert_eq!(LineEnding::find(input, LineEnding::LF), LineEnding::CRLF);
+}
+
+#[test]
+fn it_detects_line_endings_in_utf16_with_bom() {
+    let text = "\u{FEFF}one\r\ntwo\r\nthree\n";
+    let bytes: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+    let eol = LineEnding::find_in_utf16(bytes, LineEnding::LF);
+    assert_eq!(eol, LineEnding::CRLF);
+}
+
+#[test]
+fn it_analyzes_byte_order_mark_and_line_endings() {
+    let text = "\u{FEFF}one\ntwo\nthree\r\n";
+    let bytes: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    let analysis = LineEnding::analyze(bytes);
+    assert_eq!(analysis.bom, Some(Bom::Utf16LE));
+    assert_eq!(analysis.detection.ending, Some(LineEnding::LF));
+    assert_eq!(analysis.detection.counts.lines, 3);
+}
+
+#[test]
+fn it_audits_line_endings_in_a_single_pass() {
+    let input = format!("{}stray\r\n{}", "one\n".repeat(500), "two\n".repeat(500));
+    let reader = std::io::BufReader::with_capacity(64, input.as_bytes());
+    let inconsistent: Vec<usize> = LineEndingsIter::new(reader)
+        .enumerate()
+        .filter(|(_, eol)| eol.as_ref().unwrap() != &LineEnding::LF)
+        .map(|(idx, _)| idx + 1)
+        .collect();
+    assert_eq!(inconsistent, vec![501]);
+}
+
+#[test]
+fn it_detects_first_and_last_line_endings() {
+    let input = "one\r\ntwo\nthree\nfour\r";
+    assert_eq!(
+        LineEnding::find_or_use_first(input, LineEnding::LF),
+        LineEnding::CRLF
+    );
+    assert_eq!(
+        LineEnding::find_or_use_last(input, LineEnding::LF),
+        LineEnding::CR
+    );
+    assert_eq!(LineEnding::find(input, LineEnding::CRLF), LineEnding::LF);
+}
+
+#[test]
+fn it_detects_line_endings_in_invalid_utf8() {
+    let mut input = "one\r\ntwo\r\n".repeat(1000).into_bytes();
+    input.extend_from_slice(b"\xFF\xFE\x80\n");
+    let eol = LineEnding::find_in_bytes(&input, LineEnding::LF);
+    assert_eq!(eol, LineEnding::CRLF);
+}
+
+#[test]
+fn it_detects_line_endings_using_configured_detector() {
+    let input = "one\u{2028}two\u{2028}three\r\nfour\r\nfive\n";
+    let mut detector = Detector::new();
+    detector.default_ending(LineEnding::CR);
+    assert_eq!(detector.detect_str(input), UnicodeLineEnding::CRLF);
+    detector.unicode(true);
+    assert_eq!(detector.detect_str(input), UnicodeLineEnding::CR);
+    detector.tie_break(TieBreak::First);
+    assert_eq!(detector.detect_str(input), UnicodeLineEnding::LS);
+    let eol = detector.detect_reader(input.as_bytes()).unwrap();
+    assert_eq!(eol, UnicodeLineEnding::LS);
+}
+
+#[test]
+fn it_detects_and_normalizes_line_endings_fluently() {
+    let text = String::from("one\r\ntwo\r\nthree\n");
+    let eol = text.line_ending_or(LineEnding::LF);
+    assert_eq!(eol, LineEnding::CRLF);
+    assert_eq!(text.normalize_line_endings(eol), "one\r\ntwo\r\nthree\r\n");
+}
+
+#[test]
+fn it_converts_line_endings_between_reader_and_writer() {
+    let input = "one\r\ntwo\r\nthree\r\n".repeat(1000);
+    let mut out = Vec::new();
+    let stats = convert(input.as_bytes(), &mut out, LineEnding::LF).unwrap();
+    assert_eq!(out, "one\ntwo\nthree\n".repeat(1000).as_bytes());
+    assert_eq!(stats.bytes_in, 17000);
+    assert_eq!(stats.bytes_out, 14000);
+}
@@ -22,6 +22,12 @@ pub enum NodeJSArch {
     PPC64LE,
     #[cfg_attr(feature = "json", serde(rename = "s390x"))]
     S390X,
+    #[cfg_attr(feature = "json", serde(rename = "armv6l"))]
+    ARMV6L,
+    #[cfg_attr(feature = "json", serde(rename = "riscv64"))]
+    RISCV64,
+    #[cfg_attr(feature = "json", serde(rename = "loong64"))]
+    LOONG64,
 }
 
 impl Default for NodeJSArch {
@@ -50,6 +56,9 @@ impl Display for NodeJSArch {
             NodeJSArch::PPC64 => "ppc64",
             NodeJSArch::PPC64LE => "ppc64le",
             NodeJSArch::S390X => "s390x",
+            NodeJSArch::ARMV6L => "armv6l",
+            NodeJSArch::RISCV64 => "riscv64",
+            NodeJSArch::LOONG64 => "loong64",
         };
 
         write!(f, "{}", arch)
@@ -68,6 +77,9 @@ impl FromStr for NodeJSArch {
             "ppc64" | "powerpc64" => Ok(NodeJSArch::PPC64),
             "ppc64le" => Ok(NodeJSArch::PPC64LE),
             "s390x" => Ok(NodeJSArch::S390X),
+            "armv6l" => Ok(NodeJSArch::ARMV6L),
+            "riscv64" => Ok(NodeJSArch::RISCV64),
+            "loong64" | "loongarch64" => Ok(NodeJSArch::LOONG64),
             _ => Err(NodeJSRelInfoError::UnrecognizedArch(s.to_string())),
         }
     }
@@ -130,6 +142,22 @@ mod tests {
         let arch = NodeJSArch::from_str("s390x").unwrap();
 
         assert_eq!(arch, NodeJSArch::S390X);
+
+        let arch = NodeJSArch::from_str("armv6l").unwrap();
+
+        assert_eq!(arch, NodeJSArch::ARMV6L);
+
+        let arch = NodeJSArch::from_str("riscv64").unwrap();
+
+        assert_eq!(arch, NodeJSArch::RISCV64);
+
+        let arch = NodeJSArch::from_str("loong64").unwrap();
+
+        assert_eq!(arch, NodeJSArch::LOONG64);
+
+        let arch = NodeJSArch::from_str("loongarch64").unwrap();
+
+        assert_eq!(arch, NodeJSArch::LOONG64);
     }
 
     #[test]
@@ -161,6 +189,18 @@ mod tests {
         let text = format!("{}", NodeJSArch::S390X);
 
         assert_eq!(text, "s390x");
+
+        let text = format!("{}", NodeJSArch::ARMV6L);
+
+        assert_eq!(text, "armv6l");
+
+        let text = format!("{}", NodeJSArch::RISCV64);
+
+        assert_eq!(text, "riscv64");
+
+        let text = format!("{}", NodeJSArch::LOONG64);
+
+        assert_eq!(text, "loong64");
     }
 
     #[test]
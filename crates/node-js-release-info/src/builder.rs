@@ -0,0 +1,273 @@
+use crate::arch::NodeJSArch;
+use crate::error::NodeJSRelInfoError;
+use crate::ext::NodeJSPkgExt;
+use crate::kind::NodeJSArtifactKind;
+use crate::os::NodeJSOS;
+use crate::specs;
+use crate::NodeJSRelInfo;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeJSRelInfoBuilder {
+    os: NodeJSOS,
+    arch: NodeJSArch,
+    ext: NodeJSPkgExt,
+    kind: NodeJSArtifactKind,
+    version: String,
+}
+
+impl NodeJSRelInfoBuilder {
+    /// Creates a new builder using default settings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfoBuilder;
+    /// let builder = NodeJSRelInfoBuilder::new();
+    /// ```
+    pub fn new() -> Self {
+        NodeJSRelInfoBuilder::default()
+    }
+
+    /// Sets the operating system for the Node.js distributable you are targeting
+    ///
+    /// # Arguments
+    ///
+    /// * `os` - The operating system - see: [`NodeJSOS`](crate::NodeJSOS)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSOS, NodeJSRelInfoBuilder};
+    /// let builder = NodeJSRelInfoBuilder::new().os(NodeJSOS::Darwin);
+    /// ```
+    pub fn os(mut self, os: NodeJSOS) -> Self {
+        self.os = os;
+        self
+    }
+
+    /// Sets the CPU architecture for the Node.js distributable you are targeting
+    ///
+    /// # Arguments
+    ///
+    /// * `arch` - The CPU architecture - see: [`NodeJSArch`](crate::NodeJSArch)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArch, NodeJSRelInfoBuilder};
+    /// let builder = NodeJSRelInfoBuilder::new().arch(NodeJSArch::ARM64);
+    /// ```
+    pub fn arch(mut self, arch: NodeJSArch) -> Self {
+        self.arch = arch;
+        self
+    }
+
+    /// Sets the file extension for the Node.js distributable you are targeting
+    ///
+    /// # Arguments
+    ///
+    /// * `ext` - The file extension - see: [`NodeJSPkgExt`](crate::NodeJSPkgExt)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSPkgExt, NodeJSRelInfoBuilder};
+    /// let builder = NodeJSRelInfoBuilder::new().ext(NodeJSPkgExt::Tarxz);
+    /// ```
+    pub fn ext(mut self, ext: NodeJSPkgExt) -> Self {
+        self.ext = ext;
+        self
+    }
+
+    /// Sets the kind of Node.js artifact you are targeting
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The artifact kind - see: [`NodeJSArtifactKind`](crate::NodeJSArtifactKind)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArtifactKind, NodeJSRelInfoBuilder};
+    /// let builder = NodeJSRelInfoBuilder::new().kind(NodeJSArtifactKind::Headers);
+    /// ```
+    pub fn kind(mut self, kind: NodeJSArtifactKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the version of Node.js you are targeting
+    ///
+    /// # Arguments
+    ///
+    /// * `semver` - The Node.js version you are targeting (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfoBuilder;
+    /// let builder = NodeJSRelInfoBuilder::new().version("20.6.1");
+    /// ```
+    pub fn version<T: AsRef<str>>(mut self, semver: T) -> Self {
+        self.version = semver.as_ref().to_owned();
+        self
+    }
+
+    /// Validates settings and creates a new [`NodeJSRelInfo`](crate::NodeJSRelInfo)
+    /// instance - fails without making any network requests when the version
+    /// is invalid or the os / arch / ext combination is never published
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSOS, NodeJSPkgExt, NodeJSRelInfoBuilder};
+    ///
+    /// let info = NodeJSRelInfoBuilder::new()
+    ///   .version("20.6.1")
+    ///   .os(NodeJSOS::Windows)
+    ///   .ext(NodeJSPkgExt::Msi)
+    ///   .build()
+    ///   .unwrap();
+    ///
+    /// assert_eq!(info.os, NodeJSOS::Windows);
+    ///
+    /// let err = NodeJSRelInfoBuilder::new()
+    ///   .version("20.6.1")
+    ///   .os(NodeJSOS::Linux)
+    ///   .ext(NodeJSPkgExt::Msi)
+    ///   .build()
+    ///   .unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "Error: Incompatible File Extension! Received: 'msi (linux)'");
+    /// ```
+    pub fn build(self) -> Result<NodeJSRelInfo, NodeJSRelInfoError> {
+        let mut info = NodeJSRelInfo::new(specs::validate_version(&self.version)?);
+        info.os = self.os;
+        info.arch = self.arch;
+        info.ext = self.ext;
+        info.kind = self.kind;
+        info.validate()?;
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes() {
+        let builder = NodeJSRelInfoBuilder::new();
+        assert_eq!(builder.os, NodeJSOS::Linux);
+        assert_eq!(builder.arch, NodeJSArch::X64);
+        assert_eq!(builder.ext, NodeJSPkgExt::Targz);
+        assert_eq!(builder.kind, NodeJSArtifactKind::Binary);
+        assert_eq!(builder.version, "");
+    }
+
+    #[test]
+    fn it_builds_node_js_release_info() {
+        let info = NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .os(NodeJSOS::Darwin)
+            .arch(NodeJSArch::ARM64)
+            .ext(NodeJSPkgExt::Tarxz)
+            .build()
+            .unwrap();
+
+        assert_eq!(info.version, "20.6.1");
+        assert_eq!(info.os, NodeJSOS::Darwin);
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+        assert_eq!(info.filename, "");
+        assert_eq!(info.url, "");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidVersion(\"NOPE!\")"
+    )]
+    fn it_fails_to_build_when_version_is_invalid() {
+        NodeJSRelInfoBuilder::new()
+            .version("NOPE!")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: IncompatibleExt(\"msi (linux)\")"
+    )]
+    fn it_fails_to_build_when_ext_is_unavailable_for_os() {
+        NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .os(NodeJSOS::Linux)
+            .ext(NodeJSPkgExt::Msi)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: IncompatibleArch(\"s390x (darwin)\")"
+    )]
+    fn it_fails_to_build_when_arch_is_unavailable_for_os() {
+        NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .os(NodeJSOS::Darwin)
+            .arch(NodeJSArch::S390X)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn it_builds_node_js_release_info_for_headers() {
+        let info = NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .kind(NodeJSArtifactKind::Headers)
+            .ext(NodeJSPkgExt::Tarxz)
+            .build()
+            .unwrap();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Headers);
+        assert_eq!(info.filename(), "node-v20.6.1-headers.tar.xz");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: IncompatibleExt(\"zip (headers)\")"
+    )]
+    fn it_fails_to_build_when_ext_is_unavailable_for_kind() {
+        NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .kind(NodeJSArtifactKind::Headers)
+            .ext(NodeJSPkgExt::Zip)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn it_builds_node_js_release_info_for_windows_sub_path_artifacts() {
+        let info = NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .kind(NodeJSArtifactKind::Pdb)
+            .os(NodeJSOS::Windows)
+            .arch(NodeJSArch::ARM64)
+            .ext(NodeJSPkgExt::Zip)
+            .build()
+            .unwrap();
+
+        assert_eq!(info.filename(), "win-arm64/node_pdb.zip");
+
+        let err = NodeJSRelInfoBuilder::new()
+            .version("20.6.1")
+            .kind(NodeJSArtifactKind::Exe)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Incompatible OS! Received: 'linux (exe)'"
+        );
+    }
+}
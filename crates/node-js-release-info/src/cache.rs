@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Storage for responses (`SHASUMS256.txt`, `index.json`, etc) retrieved from
+/// the Node.js [downloads server](https://nodejs.org/download/release/) keyed
+/// by url - implement this to back caching with redis, sled, or your own store.
+/// Each response's `ETag` / `Last-Modified` headers are stored under
+/// `{url}#validators` so a stored response is revalidated via a conditional
+/// request (`304 Not Modified` counts as a hit) rather than used as-is
+pub trait ReleaseInfoCache: Send + Sync {
+    /// Returns the stored response body for the given url, if any
+    fn get(&self, url: &str) -> Option<String>;
+    /// Stores the response body for the given url
+    fn set(&self, url: &str, body: &str);
+}
+
+/// A cache which never stores anything (default)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NoopReleaseInfoCache;
+
+impl ReleaseInfoCache for NoopReleaseInfoCache {
+    fn get(&self, _url: &str) -> Option<String> {
+        None
+    }
+
+    fn set(&self, _url: &str, _body: &str) {}
+}
+
+/// A cache which keeps responses in memory for the life of the instance
+#[derive(Debug, Default)]
+pub struct MemoryReleaseInfoCache {
+    entries: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryReleaseInfoCache {
+    /// Creates a new, empty in-memory cache
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{MemoryReleaseInfoCache, ReleaseInfoCache};
+    /// let cache = MemoryReleaseInfoCache::new();
+    /// cache.set("https://nodejs.org/dist/index.json", "[]");
+    /// assert_eq!(cache.get("https://nodejs.org/dist/index.json"), Some("[]".to_string()));
+    /// ```
+    pub fn new() -> Self {
+        MemoryReleaseInfoCache::default()
+    }
+}
+
+impl ReleaseInfoCache for MemoryReleaseInfoCache {
+    fn get(&self, url: &str) -> Option<String> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    fn set(&self, url: &str, body: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(url.to_owned(), body.to_owned());
+    }
+}
+
+// shareable handle so `http::Settings` can stay `Clone`, `Debug`, and
+// `PartialEq` - handles are equal when they point at the same cache
+#[derive(Clone)]
+pub struct CacheHandle(pub Arc<dyn ReleaseInfoCache>);
+
+impl Default for CacheHandle {
+    fn default() -> Self {
+        static NOOP: OnceLock<Arc<dyn ReleaseInfoCache>> = OnceLock::new();
+        CacheHandle(NOOP.get_or_init(|| Arc::new(NoopReleaseInfoCache)).clone())
+    }
+}
+
+impl Debug for CacheHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CacheHandle")
+    }
+}
+
+impl PartialEq for CacheHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_never_stores_anything_when_noop() {
+        let cache = NoopReleaseInfoCache;
+        cache.set("https://nodejs.org/dist/index.json", "[]");
+        assert_eq!(cache.get("https://nodejs.org/dist/index.json"), None);
+    }
+
+    #[test]
+    fn it_stores_responses_in_memory() {
+        let cache = MemoryReleaseInfoCache::new();
+        assert_eq!(cache.get("https://nodejs.org/dist/index.json"), None);
+
+        cache.set("https://nodejs.org/dist/index.json", "[]");
+        cache.set("https://nodejs.org/dist/index.json", "[{}]");
+
+        assert_eq!(
+            cache.get("https://nodejs.org/dist/index.json"),
+            Some("[{}]".to_string())
+        );
+        assert_eq!(cache.get("https://nodejs.org/dist/index.tab"), None);
+    }
+
+    #[test]
+    fn it_compares_handles_by_identity() {
+        let handle = CacheHandle(Arc::new(MemoryReleaseInfoCache::new()));
+        assert_eq!(handle, handle.clone());
+        assert_ne!(handle, CacheHandle::default());
+        assert_eq!(CacheHandle::default(), CacheHandle::default());
+        assert_eq!(format!("{:?}", handle), "CacheHandle");
+    }
+}
@@ -0,0 +1,57 @@
+use crate::error::NodeJSRelInfoError;
+use crate::sha256::Sha256;
+use sha2::Digest;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+
+// streams the file at `path` through SHA-256 chunk by chunk (so large
+// artifacts never need to be buffered in memory) returning the digest
+pub async fn sha256_file<P: AsRef<Path>>(path: P) -> Result<Sha256, NodeJSRelInfoError> {
+    let mut file = File::open(path).await?;
+    let mut hasher = sha2::Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+
+    loop {
+        let n = file.read(&mut buf).await?;
+
+        if n == 0 {
+            break;
+        }
+
+        hasher.update(&buf[..n]);
+    }
+
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&hasher.finalize());
+    Ok(Sha256::new(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn it_computes_sha256_of_file() {
+        let path = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-checksum.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "hello").unwrap();
+
+        let digest = sha256_file(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            digest.to_hex(),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_compute_sha256_when_file_is_missing() {
+        let path = std::env::temp_dir().join("node-js-release-info-nope.txt");
+        let err = sha256_file(&path).await.unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::IoError(_)));
+    }
+}
@@ -0,0 +1,423 @@
+use crate::arch::NodeJSArch;
+use crate::ext::NodeJSPkgExt;
+use crate::kind::NodeJSArtifactKind;
+use crate::os::NodeJSOS;
+use crate::NodeJSRelInfo;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(Deserialize, Serialize), serde(transparent))]
+pub struct NodeJSRelInfoCollection {
+    items: Vec<NodeJSRelInfo>,
+}
+
+impl NodeJSRelInfoCollection {
+    /// Creates a new collection from the provided release info items
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The release info items to collect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![NodeJSRelInfo::new("20.6.1")]);
+    /// assert_eq!(all.len(), 1);
+    /// ```
+    pub fn new(items: Vec<NodeJSRelInfo>) -> Self {
+        NodeJSRelInfoCollection { items }
+    }
+
+    /// Keeps only items of the given artifact kind
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The artifact kind - see: [`NodeJSArtifactKind`](crate::NodeJSArtifactKind)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArtifactKind, NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").binary().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").headers().to_owned(),
+    /// ]);
+    /// assert_eq!(all.for_kind(NodeJSArtifactKind::Headers).len(), 1);
+    /// ```
+    pub fn for_kind(self, kind: NodeJSArtifactKind) -> Self {
+        self.filter(|info| info.kind == kind)
+    }
+
+    /// Keeps only items targeting the given operating system
+    ///
+    /// # Arguments
+    ///
+    /// * `os` - The operating system - see: [`NodeJSOS`](crate::NodeJSOS)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSOS, NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").macos().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").linux().to_owned(),
+    /// ]);
+    /// assert_eq!(all.for_os(NodeJSOS::Darwin).len(), 1);
+    /// ```
+    pub fn for_os(self, os: NodeJSOS) -> Self {
+        self.filter(|info| info.os == os)
+    }
+
+    /// Keeps only items targeting the given CPU architecture
+    ///
+    /// # Arguments
+    ///
+    /// * `arch` - The CPU architecture - see: [`NodeJSArch`](crate::NodeJSArch)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArch, NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").x64().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").arm64().to_owned(),
+    /// ]);
+    /// assert_eq!(all.for_arch(NodeJSArch::ARM64).len(), 1);
+    /// ```
+    pub fn for_arch(self, arch: NodeJSArch) -> Self {
+        self.filter(|info| info.arch == arch)
+    }
+
+    /// Keeps only items using the given file extension
+    ///
+    /// # Arguments
+    ///
+    /// * `ext` - The file extension - see: [`NodeJSPkgExt`](crate::NodeJSPkgExt)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSPkgExt, NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").tar_gz().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").tar_xz().to_owned(),
+    /// ]);
+    /// assert_eq!(all.for_ext(NodeJSPkgExt::Tarxz).len(), 1);
+    /// ```
+    pub fn for_ext(self, ext: NodeJSPkgExt) -> Self {
+        self.filter(|info| info.ext == ext)
+    }
+
+    /// Keeps only binary archives (`tar.gz`, `tar.xz`, `zip`, `7z`) - drops
+    /// installers and every non-binary artifact (headers, source, etc)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").windows().zip().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").windows().msi().to_owned(),
+    /// ]);
+    /// assert_eq!(all.archives_only().len(), 1);
+    /// ```
+    pub fn archives_only(self) -> Self {
+        self.filter(|info| info.kind == NodeJSArtifactKind::Binary && info.ext != NodeJSPkgExt::Msi)
+    }
+
+    /// Sorts items by filename (the default order of fetched collections)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").linux().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").macos().to_owned(),
+    /// ]);
+    /// let all = all.sort_by_filename();
+    /// assert_eq!(all[0].filename(), "node-v20.6.1-darwin-x64.tar.gz");
+    /// ```
+    pub fn sort_by_filename(mut self) -> Self {
+        self.items
+            .sort_by_key(|info| match info.filename.is_empty() {
+                true => info.filename(),
+                false => info.filename.clone(),
+            });
+        self
+    }
+
+    /// Sorts items by operating system, then CPU architecture, then file
+    /// extension (each compared by name, e.g. `darwin` before `linux`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArch, NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").linux().x64().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").linux().arm64().to_owned(),
+    /// ]);
+    /// let all = all.sort_by_os_arch();
+    /// assert_eq!(all[0].arch, NodeJSArch::ARM64);
+    /// ```
+    pub fn sort_by_os_arch(mut self) -> Self {
+        self.items.sort_by_key(|info| {
+            (
+                info.os.to_string(),
+                info.arch.to_string(),
+                info.ext.to_string(),
+            )
+        });
+        self
+    }
+
+    /// Finds the binary matching the given configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `os` - The operating system - see: [`NodeJSOS`](crate::NodeJSOS)
+    /// * `arch` - The CPU architecture - see: [`NodeJSArch`](crate::NodeJSArch)
+    /// * `ext` - The file extension - see: [`NodeJSPkgExt`](crate::NodeJSPkgExt)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArch, NodeJSOS, NodeJSPkgExt, NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![
+    ///   NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned(),
+    ///   NodeJSRelInfo::new("20.6.1").linux().x64().to_owned(),
+    /// ]);
+    /// let info = all.find(NodeJSOS::Darwin, NodeJSArch::ARM64, NodeJSPkgExt::Targz);
+    /// assert!(info.is_some());
+    /// ```
+    pub fn find(
+        &self,
+        os: NodeJSOS,
+        arch: NodeJSArch,
+        ext: NodeJSPkgExt,
+    ) -> Option<&NodeJSRelInfo> {
+        self.items.iter().find(|info| {
+            info.kind == NodeJSArtifactKind::Binary
+                && info.os == os
+                && info.arch == arch
+                && info.ext == ext
+        })
+    }
+
+    /// Consumes the collection returning the underlying `Vec`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoCollection};
+    /// let all = NodeJSRelInfoCollection::new(vec![NodeJSRelInfo::new("20.6.1")]);
+    /// let items: Vec<NodeJSRelInfo> = all.into_vec();
+    /// ```
+    pub fn into_vec(self) -> Vec<NodeJSRelInfo> {
+        self.items
+    }
+
+    fn filter<F: Fn(&NodeJSRelInfo) -> bool>(self, predicate: F) -> Self {
+        self.items.into_iter().filter(predicate).collect()
+    }
+}
+
+impl Deref for NodeJSRelInfoCollection {
+    type Target = [NodeJSRelInfo];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl From<Vec<NodeJSRelInfo>> for NodeJSRelInfoCollection {
+    fn from(items: Vec<NodeJSRelInfo>) -> Self {
+        NodeJSRelInfoCollection::new(items)
+    }
+}
+
+impl From<NodeJSRelInfoCollection> for Vec<NodeJSRelInfo> {
+    fn from(all: NodeJSRelInfoCollection) -> Self {
+        all.into_vec()
+    }
+}
+
+impl FromIterator<NodeJSRelInfo> for NodeJSRelInfoCollection {
+    fn from_iter<I: IntoIterator<Item = NodeJSRelInfo>>(iter: I) -> Self {
+        NodeJSRelInfoCollection::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for NodeJSRelInfoCollection {
+    type Item = NodeJSRelInfo;
+    type IntoIter = std::vec::IntoIter<NodeJSRelInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a NodeJSRelInfoCollection {
+    type Item = &'a NodeJSRelInfo;
+    type IntoIter = std::slice::Iter<'a, NodeJSRelInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs;
+
+    fn get_fake_collection() -> NodeJSRelInfoCollection {
+        let version = String::from("20.6.1");
+        let specs = specs::parse(&version, specs::get_fake_specs().to_string()).unwrap();
+        specs
+            .into_iter()
+            .map(|(kind, os, arch, ext, sha256, filename)| NodeJSRelInfo {
+                kind,
+                os,
+                arch,
+                ext,
+                sha256,
+                filename,
+                version: version.clone(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_initializes() {
+        let all = NodeJSRelInfoCollection::new(vec![]);
+        assert!(all.is_empty());
+        assert_eq!(all, NodeJSRelInfoCollection::default());
+    }
+
+    #[test]
+    fn it_derefs_to_slice() {
+        let all = get_fake_collection();
+        assert_eq!(all.len(), 41);
+        assert_eq!(all[2].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+        assert_eq!(all.iter().count(), 41);
+        assert_eq!((&all).into_iter().count(), 41);
+    }
+
+    #[test]
+    fn it_filters_by_kind() {
+        let all = get_fake_collection().for_kind(NodeJSArtifactKind::Binary);
+        assert_eq!(all.len(), 24);
+        assert!(all
+            .iter()
+            .all(|info| info.kind == NodeJSArtifactKind::Binary));
+
+        let all = get_fake_collection().for_kind(NodeJSArtifactKind::Pdb);
+        assert_eq!(all.len(), 6);
+        assert!(all.iter().all(|info| info.kind == NodeJSArtifactKind::Pdb));
+    }
+
+    #[test]
+    fn it_filters_by_os() {
+        let all = get_fake_collection().for_os(NodeJSOS::Darwin);
+        assert_eq!(all.len(), 5);
+        assert!(all.iter().all(|info| info.os == NodeJSOS::Darwin));
+    }
+
+    #[test]
+    fn it_filters_by_arch() {
+        let all = get_fake_collection().for_arch(NodeJSArch::ARM64);
+        assert_eq!(all.len(), 11);
+        assert!(all.iter().all(|info| info.arch == NodeJSArch::ARM64));
+    }
+
+    #[test]
+    fn it_filters_by_ext() {
+        let all = get_fake_collection().for_ext(NodeJSPkgExt::Msi);
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().all(|info| info.ext == NodeJSPkgExt::Msi));
+    }
+
+    #[test]
+    fn it_filters_archives() {
+        let all = get_fake_collection().archives_only();
+        assert_eq!(all.len(), 21);
+        assert!(all.iter().all(|info| info.ext != NodeJSPkgExt::Msi));
+        assert!(all
+            .iter()
+            .all(|info| info.kind == NodeJSArtifactKind::Binary));
+    }
+
+    #[test]
+    fn it_chains_filters() {
+        let all = get_fake_collection()
+            .for_os(NodeJSOS::Windows)
+            .for_arch(NodeJSArch::X64)
+            .archives_only();
+
+        let filenames: Vec<&str> = all.iter().map(|info| info.filename.as_str()).collect();
+        assert_eq!(
+            filenames,
+            vec!["node-v20.6.1-win-x64.7z", "node-v20.6.1-win-x64.zip"]
+        );
+    }
+
+    #[test]
+    fn it_sorts_by_filename() {
+        let all: NodeJSRelInfoCollection = get_fake_collection().into_iter().rev().collect();
+        let all = all.sort_by_filename();
+        let filenames: Vec<&str> = all.iter().map(|info| info.filename.as_str()).collect();
+        let mut sorted = filenames.clone();
+        sorted.sort();
+        assert_eq!(filenames, sorted);
+        assert_eq!(all[0].filename, "node-v20.6.1-aix-ppc64.tar.gz");
+    }
+
+    #[test]
+    fn it_sorts_by_os_arch() {
+        let all = get_fake_collection()
+            .for_kind(NodeJSArtifactKind::Binary)
+            .sort_by_os_arch();
+        let configs: Vec<String> = all
+            .iter()
+            .take(5)
+            .map(|info| format!("{}-{}.{}", info.os, info.arch, info.ext))
+            .collect();
+        assert_eq!(
+            configs,
+            vec![
+                "aix-ppc64.tar.gz",
+                "darwin-arm64.tar.gz",
+                "darwin-arm64.tar.xz",
+                "darwin-x64.tar.gz",
+                "darwin-x64.tar.xz",
+            ]
+        );
+        assert_eq!(all.last().unwrap().filename, "node-v20.6.1-win-x86.zip");
+    }
+
+    #[test]
+    fn it_finds_configuration() {
+        let all = get_fake_collection();
+        let info = all
+            .find(NodeJSOS::Linux, NodeJSArch::X64, NodeJSPkgExt::Tarxz)
+            .unwrap();
+        assert_eq!(info.filename, "node-v20.6.1-linux-x64.tar.xz");
+
+        let info = all.find(NodeJSOS::AIX, NodeJSArch::PPC64, NodeJSPkgExt::Tarxz);
+        assert_eq!(info, None);
+    }
+
+    #[test]
+    fn it_converts_to_and_from_vec() {
+        let items = get_fake_collection().into_vec();
+        assert_eq!(items.len(), 41);
+        let all = NodeJSRelInfoCollection::from(items.clone());
+        assert_eq!(Vec::from(all), items);
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+/// Network behavior shared by requests to the Node.js [downloads server](https://nodejs.org/download/release/) -
+/// attach it to an instance via [`NodeJSRelInfo::config()`](crate::NodeJSRelInfo::config)
+/// or process-wide via [`set_global()`](NodeJSRelInfoConfig::set_global) for
+/// instances created with [`NodeJSRelInfo::from_global_config()`](crate::NodeJSRelInfo::from_global_config)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeJSRelInfoConfig {
+    /// Max time to wait for each request to complete - unlimited when unset
+    /// (ignored on wasm32 where the browser decides)
+    pub timeout: Option<Duration>,
+    /// Number of times to retry a request which failed with a retryable error
+    /// (timeouts, connection failures, `408`, `429`, and `5xx` responses)
+    pub retries: u32,
+    /// Base url releases are served under (e.g. `https://npmmirror.com/mirrors/node`)
+    /// used instead of `https://nodejs.org/download/release`
+    pub mirror: Option<String>,
+    /// Fully qualified url of the proxy to send requests through - by default,
+    /// the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables are respected
+    pub proxy: Option<String>,
+    /// `User-Agent` header sent with every request - defaults to `node-js-release-info/{version}`
+    pub user_agent: Option<String>,
+}
+
+fn global() -> &'static RwLock<NodeJSRelInfoConfig> {
+    static GLOBAL: OnceLock<RwLock<NodeJSRelInfoConfig>> = OnceLock::new();
+    GLOBAL.get_or_init(|| RwLock::new(NodeJSRelInfoConfig::default()))
+}
+
+impl NodeJSRelInfoConfig {
+    /// Creates a new config using default settings
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfoConfig;
+    /// use std::time::Duration;
+    /// let config = NodeJSRelInfoConfig {
+    ///   timeout: Some(Duration::from_secs(30)),
+    ///   retries: 2,
+    ///   ..NodeJSRelInfoConfig::new()
+    /// };
+    /// ```
+    pub fn new() -> Self {
+        NodeJSRelInfoConfig::default()
+    }
+
+    /// Returns the process-wide config applied to instances created via
+    /// [`NodeJSRelInfo::from_global_config()`](crate::NodeJSRelInfo::from_global_config)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfoConfig;
+    /// let config = NodeJSRelInfoConfig::global();
+    /// ```
+    pub fn global() -> NodeJSRelInfoConfig {
+        global().read().unwrap().clone()
+    }
+
+    /// Sets the process-wide config applied to instances created afterwards via
+    /// [`NodeJSRelInfo::from_global_config()`](crate::NodeJSRelInfo::from_global_config) -
+    /// existing instances are unaffected
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The config to apply to new instances
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoConfig};
+    /// NodeJSRelInfoConfig::set_global(NodeJSRelInfoConfig {
+    ///   user_agent: Some("my-tool/1.0.0".to_string()),
+    ///   ..NodeJSRelInfoConfig::new()
+    /// });
+    /// let info = NodeJSRelInfo::from_global_config("20.6.1"); // sends `User-Agent: my-tool/1.0.0`
+    /// ```
+    pub fn set_global(config: NodeJSRelInfoConfig) {
+        *global().write().unwrap() = config;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes() {
+        let config = NodeJSRelInfoConfig::new();
+        assert_eq!(config.timeout, None);
+        assert_eq!(config.retries, 0);
+        assert_eq!(config.mirror, None);
+        assert_eq!(config.proxy, None);
+        assert_eq!(config.user_agent, None);
+        assert_eq!(config, NodeJSRelInfoConfig::default());
+    }
+}
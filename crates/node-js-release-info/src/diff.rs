@@ -0,0 +1,110 @@
+use crate::collection::NodeJSRelInfoCollection;
+use crate::NodeJSRelInfo;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(Deserialize, Serialize))]
+pub struct NodeJSRelInfoDiff {
+    /// Configurations only available in the newer version (newer version's info)
+    pub added: NodeJSRelInfoCollection,
+    /// Configurations only available in the older version (older version's info)
+    pub removed: NodeJSRelInfoCollection,
+    /// Configurations available in both versions whose `sha256` differs (newer
+    /// version's info)
+    pub changed: NodeJSRelInfoCollection,
+}
+
+impl NodeJSRelInfoDiff {
+    /// Compares two collections by configuration (`kind`, `os`, `arch`, and
+    /// `ext`)
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The older version's release info
+    /// * `to` - The newer version's release info
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoCollection, NodeJSRelInfoDiff};
+    /// let from = NodeJSRelInfoCollection::new(vec![NodeJSRelInfo::new("20.6.1").linux().to_owned()]);
+    /// let to = NodeJSRelInfoCollection::new(vec![NodeJSRelInfo::new("20.7.0").macos().to_owned()]);
+    /// let diff = NodeJSRelInfoDiff::new(&from, &to);
+    /// assert_eq!(diff.added.len(), 1);
+    /// assert_eq!(diff.removed.len(), 1);
+    /// assert!(diff.changed.is_empty());
+    /// ```
+    pub fn new(from: &NodeJSRelInfoCollection, to: &NodeJSRelInfoCollection) -> Self {
+        let find = |all: &NodeJSRelInfoCollection, info: &NodeJSRelInfo| {
+            all.iter()
+                .find(|i| {
+                    i.kind == info.kind
+                        && i.os == info.os
+                        && i.arch == info.arch
+                        && i.ext == info.ext
+                })
+                .cloned()
+        };
+
+        let added = to.iter().filter(|info| find(from, info).is_none());
+        let removed = from.iter().filter(|info| find(to, info).is_none());
+        let changed = to.iter().filter(|info| match find(from, info) {
+            Some(prev) => prev.sha256 != info.sha256,
+            None => false,
+        });
+
+        NodeJSRelInfoDiff {
+            added: added.cloned().collect(),
+            removed: removed.cloned().collect(),
+            changed: changed.cloned().collect(),
+        }
+    }
+
+    /// Whether the versions have the same configurations and checksums
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(os: &str, arch: &str, sha256: &str) -> NodeJSRelInfo {
+        NodeJSRelInfo {
+            os: os.parse().unwrap(),
+            arch: arch.parse().unwrap(),
+            sha256: sha256.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_initializes() {
+        let diff = NodeJSRelInfoDiff::new(&Default::default(), &Default::default());
+        assert!(diff.is_empty());
+        assert_eq!(diff, NodeJSRelInfoDiff::default());
+    }
+
+    #[test]
+    fn it_compares_collections() {
+        let from = NodeJSRelInfoCollection::new(vec![
+            info("linux", "x64", "aaa"),
+            info("linux", "arm64", "bbb"),
+            info("aix", "ppc64", "ccc"),
+        ]);
+        let to = NodeJSRelInfoCollection::new(vec![
+            info("linux", "x64", "aaa"),
+            info("linux", "arm64", "ddd"),
+            info("darwin", "arm64", "eee"),
+        ]);
+
+        let diff = NodeJSRelInfoDiff::new(&from, &to);
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.added.into_vec(), vec![info("darwin", "arm64", "eee")]);
+        assert_eq!(diff.removed.into_vec(), vec![info("aix", "ppc64", "ccc")]);
+        assert_eq!(diff.changed.into_vec(), vec![info("linux", "arm64", "ddd")]);
+    }
+}
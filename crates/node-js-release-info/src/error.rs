@@ -12,17 +12,173 @@ pub enum NodeJSRelInfoError {
     /// The file extension of the Node.js distributable you are targeting is
     /// unrecognized - see: [`NodeJSPkgExt`](crate::NodeJSPkgExt) for options
     UnrecognizedExt(String),
+    /// The kind of Node.js artifact you are targeting is unrecognized - see:
+    /// [`NodeJSArtifactKind`](crate::NodeJSArtifactKind) for options
+    UnrecognizedKind(String),
+    /// The serialization format is unrecognized or its feature is not enabled -
+    /// see: [`NodeJSRelInfoFormat`](crate::NodeJSRelInfoFormat) for options
+    UnrecognizedFormat(String),
+    /// The host links against a C library official Node.js builds don't
+    /// support (e.g. `musl` on Alpine) - builds for it are published via the
+    /// [unofficial-builds](https://unofficial-builds.nodejs.org) project
+    UnsupportedLibc(String),
+    /// The file extension is never published for the targeted operating
+    /// system or artifact kind (e.g. `msi` for `linux`)
+    IncompatibleExt(String),
+    /// The CPU architecture is never published for the targeted operating
+    /// system or artifact kind (e.g. `x64` for `aix`)
+    IncompatibleArch(String),
+    /// The operating system is never published for the targeted artifact kind
+    /// (e.g. `linux` for `pkg`)
+    IncompatibleOs(String),
     /// The version string provided is invalid - see: [semver](https://semver.org)
     InvalidVersion(String),
     /// The version of Node.js you are targeting is not available
     UnrecognizedVersion(String),
+    /// `SHASUMS256.txt` lists artifacts the parser doesn't recognize (e.g. a
+    /// newly introduced platform or package format) - only returned in
+    /// [strict](crate::NodeJSRelInfo::strict) mode
+    UnrecognizedArtifacts(Vec<String>),
     /// The Node.js configuration you are targeting is not available
     UnrecognizedConfiguration(String),
+    /// The signature for the Node.js release info (`SHASUMS256.txt.sig`) could
+    /// not be verified against the Node.js [release keys](https://github.com/nodejs/release-keys)
+    InvalidSignature(String),
+    /// `gpgv` (used to verify the release info signature) could not be found -
+    /// it ships with [GnuPG](https://gnupg.org) and must be on your `$PATH`
+    MissingGpgv(String),
+    /// The keyring used to verify the release info signature is missing or
+    /// empty - the crate bundles the Node.js [release keys](https://github.com/nodejs/release-keys)
+    /// unless a keyring is provided via [`gpg_keyring()`](crate::NodeJSRelInfo::gpg_keyring)
+    MissingKeyring(String),
+    /// The SHA-256 checksum of a local Node.js distributable does not match
+    /// the published `sha256` (or no `sha256` was available to compare against)
+    InvalidChecksum(String),
+    /// The filename of the Node.js distributable would resolve outside the
+    /// directory it is downloaded to (e.g. an absolute path or one with `..`)
+    InvalidFilename(String),
+    /// The Node.js release index (`index.json`) could not be retrieved or parsed
+    InvalidReleaseIndex(String),
+    /// The Node.js [release schedule](https://github.com/nodejs/Release) could
+    /// not be retrieved or parsed
+    InvalidReleaseSchedule(String),
     /// Something went wrong issuing or processing the HTTP GET request to the Node.js [downloads server](https://nodejs.org/download/release/)
     HttpError(reqwest::Error),
+    /// The Node.js [downloads server](https://nodejs.org/download/release/)
+    /// responded with an unsuccessful (4xx / 5xx) HTTP status - `retry_after`
+    /// holds the number of seconds to wait when a `Retry-After` header is sent
+    HttpStatus {
+        status: u16,
+        url: String,
+        retry_after: Option<u64>,
+    },
+    /// Something went wrong serializing release info to the requested format
+    SerializationError(String),
+    /// Something went wrong writing a downloaded Node.js distributable to disk
+    IoError(std::io::Error),
 }
 
-impl Error for NodeJSRelInfoError {}
+/// Broad categories of [`NodeJSRelInfoError`] so callers can branch on the
+/// kind of failure without matching every variant or its message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeJSRelInfoErrorKind {
+    /// The request could not be completed (connection failures, timeouts, and
+    /// unsuccessful HTTP statuses other than `404`)
+    Network,
+    /// The requested version, configuration, or resource does not exist
+    NotFound,
+    /// A response or document could not be parsed or serialized
+    Parse,
+    /// An input or artifact was rejected (unrecognized options, invalid
+    /// versions, bad signatures, checksum mismatches, etc)
+    Validation,
+    /// Something went wrong reading from or writing to disk, or running an
+    /// external tool (e.g. `gpgv`)
+    Io,
+}
+
+impl Error for NodeJSRelInfoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NodeJSRelInfoError::HttpError(e) => Some(e),
+            NodeJSRelInfoError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl NodeJSRelInfoError {
+    /// The broad category of the failure - see: [`NodeJSRelInfoErrorKind`](crate::NodeJSRelInfoErrorKind)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfoError, NodeJSRelInfoErrorKind};
+    /// let err = NodeJSRelInfoError::UnrecognizedVersion("1.0.0".to_string());
+    /// assert_eq!(err.kind(), NodeJSRelInfoErrorKind::NotFound);
+    /// let err = NodeJSRelInfoError::InvalidVersion("NOPE!".to_string());
+    /// assert_eq!(err.kind(), NodeJSRelInfoErrorKind::Validation);
+    /// ```
+    pub fn kind(&self) -> NodeJSRelInfoErrorKind {
+        match self {
+            NodeJSRelInfoError::UnrecognizedOs(_)
+            | NodeJSRelInfoError::UnrecognizedArch(_)
+            | NodeJSRelInfoError::UnrecognizedExt(_)
+            | NodeJSRelInfoError::UnrecognizedKind(_)
+            | NodeJSRelInfoError::UnrecognizedFormat(_)
+            | NodeJSRelInfoError::UnsupportedLibc(_)
+            | NodeJSRelInfoError::IncompatibleExt(_)
+            | NodeJSRelInfoError::IncompatibleArch(_)
+            | NodeJSRelInfoError::IncompatibleOs(_)
+            | NodeJSRelInfoError::InvalidVersion(_)
+            | NodeJSRelInfoError::InvalidSignature(_)
+            | NodeJSRelInfoError::MissingKeyring(_)
+            | NodeJSRelInfoError::InvalidFilename(_)
+            | NodeJSRelInfoError::InvalidChecksum(_) => NodeJSRelInfoErrorKind::Validation,
+            NodeJSRelInfoError::UnrecognizedVersion(_)
+            | NodeJSRelInfoError::UnrecognizedConfiguration(_)
+            | NodeJSRelInfoError::HttpStatus { status: 404, .. } => {
+                NodeJSRelInfoErrorKind::NotFound
+            }
+            NodeJSRelInfoError::InvalidReleaseIndex(_)
+            | NodeJSRelInfoError::InvalidReleaseSchedule(_)
+            | NodeJSRelInfoError::UnrecognizedArtifacts(_)
+            | NodeJSRelInfoError::SerializationError(_) => NodeJSRelInfoErrorKind::Parse,
+            NodeJSRelInfoError::HttpError(_) | NodeJSRelInfoError::HttpStatus { .. } => {
+                NodeJSRelInfoErrorKind::Network
+            }
+            NodeJSRelInfoError::MissingGpgv(_) | NodeJSRelInfoError::IoError(_) => {
+                NodeJSRelInfoErrorKind::Io
+            }
+        }
+    }
+
+    /// Whether the failure is likely transient such that issuing the same
+    /// request again later may succeed (timeouts, connection failures, `408`,
+    /// `429`, and `5xx` responses)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfoError;
+    /// let err = NodeJSRelInfoError::HttpStatus {
+    ///   status: 503,
+    ///   url: "https://nodejs.org/download/release/v20.6.1/SHASUMS256.txt".to_string(),
+    ///   retry_after: Some(120),
+    /// };
+    /// assert!(err.is_retryable());
+    /// assert!(!NodeJSRelInfoError::UnrecognizedVersion("1.0.0".to_string()).is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            NodeJSRelInfoError::HttpStatus { status, .. } => {
+                matches!(status, 408 | 429 | 500..=599)
+            }
+            NodeJSRelInfoError::HttpError(e) => e.is_timeout() || e.is_request(),
+            _ => false,
+        }
+    }
+}
 
 impl Display for NodeJSRelInfoError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
@@ -36,16 +192,75 @@ impl Display for NodeJSRelInfoError {
             NodeJSRelInfoError::UnrecognizedExt(input) => {
                 format!("Unrecognized File Extension! Received: '{}'", input)
             }
+            NodeJSRelInfoError::UnrecognizedKind(input) => {
+                format!("Unrecognized Artifact Kind! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::UnrecognizedFormat(input) => {
+                format!("Unrecognized Format! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::UnsupportedLibc(input) => {
+                format!(
+                    "Unsupported Libc (see: https://unofficial-builds.nodejs.org)! Received: '{}'",
+                    input
+                )
+            }
+            NodeJSRelInfoError::IncompatibleExt(input) => {
+                format!("Incompatible File Extension! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::IncompatibleArch(input) => {
+                format!("Incompatible Arch! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::IncompatibleOs(input) => {
+                format!("Incompatible OS! Received: '{}'", input)
+            }
             NodeJSRelInfoError::InvalidVersion(input) => {
                 format!("Invalid Version! Received: '{}'", input)
             }
             NodeJSRelInfoError::UnrecognizedVersion(input) => {
                 format!("Unrecognized Version! Received: '{}'", input)
             }
+            NodeJSRelInfoError::UnrecognizedArtifacts(input) => {
+                format!("Unrecognized Artifacts! Received: '{}'", input.join(", "))
+            }
             NodeJSRelInfoError::UnrecognizedConfiguration(input) => {
                 format!("Unrecognized Configuration! Received: '{}'", input)
             }
-            NodeJSRelInfoError::HttpError(e) => return write!(f, "{}", e),
+            NodeJSRelInfoError::InvalidSignature(input) => {
+                format!("Invalid Signature! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::MissingGpgv(input) => {
+                format!(
+                    "Missing gpgv (see: https://gnupg.org)! Received: '{}'",
+                    input
+                )
+            }
+            NodeJSRelInfoError::MissingKeyring(input) => {
+                format!("Missing Keyring! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::InvalidChecksum(input) => {
+                format!("Invalid Checksum! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::InvalidFilename(input) => {
+                format!("Invalid Filename! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::InvalidReleaseIndex(input) => {
+                format!("Invalid Release Index! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::InvalidReleaseSchedule(input) => {
+                format!("Invalid Release Schedule! Received: '{}'", input)
+            }
+            NodeJSRelInfoError::HttpStatus { status, url, .. } => {
+                format!("HTTP Status {}! Received: '{}'", status, url)
+            }
+            NodeJSRelInfoError::SerializationError(input) => {
+                format!("Unable to Serialize! Received: '{}'", input)
+            }
+            // details are left to `source()` so error chains don't repeat them
+            NodeJSRelInfoError::HttpError(e) => match e.url() {
+                Some(url) => format!("HTTP Request Failed! Received: '{}'", url),
+                None => "HTTP Request Failed!".to_string(),
+            },
+            NodeJSRelInfoError::IoError(_) => "IO Failed!".to_string(),
         };
 
         write!(f, "Error: {}", message)
@@ -58,6 +273,12 @@ impl From<reqwest::Error> for NodeJSRelInfoError {
     }
 }
 
+impl From<std::io::Error> for NodeJSRelInfoError {
+    fn from(e: std::io::Error) -> Self {
+        NodeJSRelInfoError::IoError(e)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,6 +310,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_prints_expected_message_when_kind_is_unrecognized() {
+        let err = NodeJSRelInfoError::UnrecognizedKind("unknown-kind".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Unrecognized Artifact Kind! Received: 'unknown-kind'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_format_is_unrecognized() {
+        let err = NodeJSRelInfoError::UnrecognizedFormat("xml".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Unrecognized Format! Received: 'xml'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_libc_is_unsupported() {
+        let err = NodeJSRelInfoError::UnsupportedLibc("musl".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Unsupported Libc (see: https://unofficial-builds.nodejs.org)! Received: 'musl'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_configuration_is_incompatible() {
+        let err = NodeJSRelInfoError::IncompatibleExt("msi (linux)".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Incompatible File Extension! Received: 'msi (linux)'"
+        );
+
+        let err = NodeJSRelInfoError::IncompatibleArch("x64 (aix)".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Incompatible Arch! Received: 'x64 (aix)'"
+        );
+
+        let err = NodeJSRelInfoError::IncompatibleOs("linux (pkg)".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Incompatible OS! Received: 'linux (pkg)'"
+        );
+    }
+
     #[test]
     fn it_prints_expected_message_when_version_is_invalid() {
         let err = NodeJSRelInfoError::InvalidVersion("invalid-ver".to_string());
@@ -116,15 +385,219 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_prints_expected_message_when_artifacts_are_unrecognized() {
+        let err = NodeJSRelInfoError::UnrecognizedArtifacts(vec![
+            "node-v20.6.1-linux-wasm32.tar.gz".to_string(),
+            "win-x64/node.dll".to_string(),
+        ]);
+        assert_eq!(
+            format!("{err}"),
+            "Error: Unrecognized Artifacts! Received: 'node-v20.6.1-linux-wasm32.tar.gz, win-x64/node.dll'"
+        );
+        assert_eq!(err.kind(), NodeJSRelInfoErrorKind::Parse);
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_signature_is_invalid() {
+        let err = NodeJSRelInfoError::InvalidSignature("1.0.0".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Invalid Signature! Received: '1.0.0'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_gpgv_is_missing() {
+        let err = NodeJSRelInfoError::MissingGpgv("gpgv".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Missing gpgv (see: https://gnupg.org)! Received: 'gpgv'"
+        );
+        assert_eq!(err.kind(), NodeJSRelInfoErrorKind::Io);
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_keyring_is_missing() {
+        let err = NodeJSRelInfoError::MissingKeyring("keys/pubring.kbx".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Missing Keyring! Received: 'keys/pubring.kbx'"
+        );
+        assert_eq!(err.kind(), NodeJSRelInfoErrorKind::Validation);
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_filename_is_invalid() {
+        let err = NodeJSRelInfoError::InvalidFilename("../node.tar.gz".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Invalid Filename! Received: '../node.tar.gz'"
+        );
+        assert_eq!(err.kind(), NodeJSRelInfoErrorKind::Validation);
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_checksum_is_invalid() {
+        let err = NodeJSRelInfoError::InvalidChecksum("node.tar.gz".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Invalid Checksum! Received: 'node.tar.gz'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_release_index_is_invalid() {
+        let err = NodeJSRelInfoError::InvalidReleaseIndex("index.json".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Invalid Release Index! Received: 'index.json'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_when_release_schedule_is_invalid() {
+        let err = NodeJSRelInfoError::InvalidReleaseSchedule("schedule.json".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Invalid Release Schedule! Received: 'schedule.json'"
+        );
+    }
+
+    #[test]
+    fn it_categorizes_errors() {
+        let cases = [
+            (
+                NodeJSRelInfoError::UnrecognizedArch("nope".to_string()),
+                NodeJSRelInfoErrorKind::Validation,
+            ),
+            (
+                NodeJSRelInfoError::InvalidChecksum("node.tar.gz".to_string()),
+                NodeJSRelInfoErrorKind::Validation,
+            ),
+            (
+                NodeJSRelInfoError::UnrecognizedConfiguration("node.zip".to_string()),
+                NodeJSRelInfoErrorKind::NotFound,
+            ),
+            (
+                NodeJSRelInfoError::HttpStatus {
+                    status: 404,
+                    url: "https://nodejs.org".to_string(),
+                    retry_after: None,
+                },
+                NodeJSRelInfoErrorKind::NotFound,
+            ),
+            (
+                NodeJSRelInfoError::HttpStatus {
+                    status: 503,
+                    url: "https://nodejs.org".to_string(),
+                    retry_after: None,
+                },
+                NodeJSRelInfoErrorKind::Network,
+            ),
+            (
+                NodeJSRelInfoError::InvalidReleaseIndex("index.json".to_string()),
+                NodeJSRelInfoErrorKind::Parse,
+            ),
+            (
+                NodeJSRelInfoError::SerializationError("toml".to_string()),
+                NodeJSRelInfoErrorKind::Parse,
+            ),
+            (
+                NodeJSRelInfoError::IoError(std::io::Error::other("nope")),
+                NodeJSRelInfoErrorKind::Io,
+            ),
+        ];
+
+        for (err, kind) in cases {
+            assert_eq!(err.kind(), kind, "{:?}", err);
+        }
+    }
+
+    #[test]
+    fn it_exposes_source_of_wrapped_errors() {
+        let err = NodeJSRelInfoError::IoError(std::io::Error::other("disk full"));
+        assert_eq!(err.source().unwrap().to_string(), "disk full");
+
+        let err = NodeJSRelInfoError::UnrecognizedVersion("1.0.0".to_string());
+        assert!(err.source().is_none());
+    }
+
     #[tokio::test]
     async fn it_prints_expected_message_upon_http_error() {
         let err = fake_http_error().await.unwrap_err();
+        assert_eq!(format!("{err}"), "Error: HTTP Request Failed!");
+
+        let error = reqwest::get("http://127.0.0.1:1/index.json")
+            .await
+            .unwrap_err();
+        let err = NodeJSRelInfoError::from(error);
         assert_eq!(
             format!("{err}"),
-            "builder error: relative URL without a base"
+            "Error: HTTP Request Failed! Received: 'http://127.0.0.1:1/index.json'"
         );
     }
 
+    #[tokio::test]
+    async fn it_exposes_source_of_http_errors() {
+        let err = fake_http_error().await.unwrap_err();
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<reqwest::Error>().is_some());
+        assert_eq!(err.kind(), NodeJSRelInfoErrorKind::Network);
+    }
+
+    #[test]
+    fn it_prints_expected_message_upon_http_status() {
+        let err = NodeJSRelInfoError::HttpStatus {
+            status: 503,
+            url: "https://nodejs.org/dist/index.json".to_string(),
+            retry_after: None,
+        };
+        assert_eq!(
+            format!("{err}"),
+            "Error: HTTP Status 503! Received: 'https://nodejs.org/dist/index.json'"
+        );
+    }
+
+    #[test]
+    fn it_determines_whether_error_is_retryable() {
+        let status = |status| NodeJSRelInfoError::HttpStatus {
+            status,
+            url: "https://nodejs.org/dist/index.json".to_string(),
+            retry_after: None,
+        };
+        assert!(status(408).is_retryable());
+        assert!(status(429).is_retryable());
+        assert!(status(500).is_retryable());
+        assert!(status(503).is_retryable());
+        assert!(!status(403).is_retryable());
+        assert!(!status(404).is_retryable());
+        assert!(!NodeJSRelInfoError::InvalidVersion("nope".to_string()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_builder_errors() {
+        let err = fake_http_error().await.unwrap_err();
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn it_prints_expected_message_upon_serialization_error() {
+        let err = NodeJSRelInfoError::SerializationError("unsupported type".to_string());
+        assert_eq!(
+            format!("{err}"),
+            "Error: Unable to Serialize! Received: 'unsupported type'"
+        );
+    }
+
+    #[test]
+    fn it_prints_expected_message_upon_io_error() {
+        let error = std::io::Error::new(std::io::ErrorKind::NotFound, "nope");
+        let err = NodeJSRelInfoError::from(error);
+        assert_eq!(format!("{err}"), "Error: IO Failed!");
+        assert_eq!(format!("{}", err.source().unwrap()), "nope");
+    }
+
     async fn fake_http_error() -> std::result::Result<(), NodeJSRelInfoError> {
         let error = reqwest::get("not-a-url").await.unwrap_err();
         Err(NodeJSRelInfoError::from(error))
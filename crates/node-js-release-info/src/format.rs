@@ -0,0 +1,112 @@
+use crate::error::NodeJSRelInfoError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum NodeJSRelInfoFormat {
+    #[default]
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl Display for NodeJSRelInfoFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let format = match self {
+            NodeJSRelInfoFormat::Json => "json",
+            #[cfg(feature = "yaml")]
+            NodeJSRelInfoFormat::Yaml => "yaml",
+            #[cfg(feature = "toml")]
+            NodeJSRelInfoFormat::Toml => "toml",
+        };
+
+        write!(f, "{}", format)
+    }
+}
+
+impl FromStr for NodeJSRelInfoFormat {
+    type Err = NodeJSRelInfoError;
+
+    fn from_str(s: &str) -> Result<NodeJSRelInfoFormat, NodeJSRelInfoError> {
+        match s {
+            "json" => Ok(NodeJSRelInfoFormat::Json),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Ok(NodeJSRelInfoFormat::Yaml),
+            #[cfg(feature = "toml")]
+            "toml" => Ok(NodeJSRelInfoFormat::Toml),
+            _ => Err(NodeJSRelInfoError::UnrecognizedFormat(s.to_string())),
+        }
+    }
+}
+
+// serializes `value` as a human-readable document in the given format
+pub fn to_string_pretty<T: serde::Serialize>(
+    value: &T,
+    format: NodeJSRelInfoFormat,
+) -> Result<String, NodeJSRelInfoError> {
+    let serialized = match format {
+        NodeJSRelInfoFormat::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+        #[cfg(feature = "yaml")]
+        NodeJSRelInfoFormat::Yaml => serde_yaml::to_string(value).map_err(|e| e.to_string()),
+        #[cfg(feature = "toml")]
+        NodeJSRelInfoFormat::Toml => toml::to_string_pretty(value).map_err(|e| e.to_string()),
+    };
+
+    serialized.map_err(NodeJSRelInfoError::SerializationError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes() {
+        let format = NodeJSRelInfoFormat::default();
+        assert_eq!(format, NodeJSRelInfoFormat::Json);
+    }
+
+    #[test]
+    fn it_formats_to_string() {
+        assert_eq!(NodeJSRelInfoFormat::Json.to_string(), "json");
+    }
+
+    #[test]
+    fn it_initializes_from_str() {
+        let format = NodeJSRelInfoFormat::from_str("json").unwrap();
+        assert_eq!(format, NodeJSRelInfoFormat::Json);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedFormat(\"xml\")"
+    )]
+    fn it_fails_to_initialize_from_str_when_format_is_unrecognized() {
+        NodeJSRelInfoFormat::from_str("xml").unwrap();
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn it_initializes_yaml_from_str() {
+        assert_eq!(
+            NodeJSRelInfoFormat::from_str("yaml").unwrap(),
+            NodeJSRelInfoFormat::Yaml
+        );
+        assert_eq!(
+            NodeJSRelInfoFormat::from_str("yml").unwrap(),
+            NodeJSRelInfoFormat::Yaml
+        );
+        assert_eq!(NodeJSRelInfoFormat::Yaml.to_string(), "yaml");
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn it_initializes_toml_from_str() {
+        assert_eq!(
+            NodeJSRelInfoFormat::from_str("toml").unwrap(),
+            NodeJSRelInfoFormat::Toml
+        );
+        assert_eq!(NodeJSRelInfoFormat::Toml.to_string(), "toml");
+    }
+}
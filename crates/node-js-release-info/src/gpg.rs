@@ -0,0 +1,349 @@
+use crate::error::NodeJSRelInfoError;
+use crate::http;
+use crate::url::NodeJSURLFormatter;
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+#[cfg(unix)]
+use std::os::unix::fs::DirBuilderExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// the `gpgv` executable (resolved via `$PATH`)
+const GPGV: &str = "gpgv";
+
+// the Node.js release keys (`gpg/pubring.kbx` exported by
+// https://github.com/nodejs/release-keys) bundled so verification never
+// depends on what the user happens to have imported - refresh via:
+// `curl -fsSL https://github.com/nodejs/release-keys/raw/HEAD/gpg/pubring.kbx -o keys/pubring.kbx`
+const RELEASE_KEYS: &[u8] = include_bytes!("../keys/pubring.kbx");
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Settings {
+    pub enabled: bool,
+    pub keyring: Option<PathBuf>,
+}
+
+// downloads `SHASUMS256.txt.sig` and verifies it against the provided specs
+// using `gpgv` and the bundled Node.js release keys - a user-provided keyring
+// is used instead when set (see: https://github.com/nodejs/node#verifying-binaries)
+pub async fn verify(
+    version: &String,
+    specs: &str,
+    url_fmt: &NodeJSURLFormatter,
+    http: &http::Settings,
+    settings: &Settings,
+) -> Result<(), NodeJSRelInfoError> {
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    if settings.keyring.is_none() {
+        release_keys(RELEASE_KEYS)?;
+    }
+
+    let sig_url = url_fmt.info_sig(version);
+    let client = http.client()?;
+    http::throttle(http).await;
+    let res = client.get(sig_url).send().await?;
+
+    if !res.status().is_success() {
+        return Err(http::status_error(&res));
+    }
+
+    let sig = res.bytes().await?;
+    let dir = temp_dir(version)?;
+    let keyring = match &settings.keyring {
+        Some(keyring) => Ok(keyring.clone()),
+        None => write_new(&dir, "release-keys.kbx", RELEASE_KEYS),
+    };
+    let verified = keyring.and_then(|keyring| {
+        let sig_path = write_new(&dir, "SHASUMS256.txt.sig", &sig)?;
+        run_gpgv(GPGV, &sig_path, specs, &keyring)
+    });
+
+    let _ = fs::remove_dir_all(&dir);
+
+    match verified {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(NodeJSRelInfoError::InvalidSignature(version.clone())),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            Err(NodeJSRelInfoError::MissingGpgv(GPGV.to_string()))
+        }
+        Err(e) => Err(NodeJSRelInfoError::IoError(e)),
+    }
+}
+
+// the bundled keyring is only empty when the crate was packaged w/o it -
+// treated as an error so signatures are never reported as verified w/o keys
+fn release_keys(keys: &[u8]) -> Result<&[u8], NodeJSRelInfoError> {
+    match keys.is_empty() {
+        true => Err(NodeJSRelInfoError::MissingKeyring(
+            "keys/pubring.kbx".to_string(),
+        )),
+        false => Ok(keys),
+    }
+}
+
+// writes the contents to a new file in the provided (private) directory -
+// fails rather than following anything already present at that path
+fn write_new(dir: &Path, name: &str, contents: &[u8]) -> io::Result<PathBuf> {
+    let path = dir.join(name);
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+
+    file.write_all(contents)?;
+    Ok(path)
+}
+
+// runs `gpgv` against the signature file piping the specs via stdin - returns
+// `Ok(false)` when the signature doesn't verify and `Err` when `gpgv` can't be
+// run (`NotFound` when it isn't installed)
+fn run_gpgv(program: &str, sig_path: &Path, specs: &str, keyring: &Path) -> io::Result<bool> {
+    let mut cmd = Command::new(program);
+
+    // relative keyring paths are otherwise resolved against `$GNUPGHOME` - an
+    // explicit keyring also keeps `gpgv` from consulting `trustedkeys.kbx`
+    match std::path::absolute(keyring) {
+        Ok(keyring) => cmd.arg("--keyring").arg(keyring),
+        Err(_) => cmd.arg("--keyring").arg(keyring),
+    };
+
+    let mut child = cmd
+        .arg(sig_path)
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // `gpgv` exits w/o reading stdin when the signature itself is malformed
+    if let Some(mut stdin) = child.stdin.take() {
+        match stdin.write_all(specs.as_bytes()) {
+            Err(e) if e.kind() != ErrorKind::BrokenPipe => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(e);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(child.wait()?.success())
+}
+
+// creates a new directory only the current user can access - fails rather
+// than reusing one that already exists
+fn temp_dir(version: &str) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let path = std::env::temp_dir().join(format!(
+        "node-js-release-info-{}-{}-{:08x}-v{}",
+        std::process::id(),
+        id,
+        nanos,
+        version
+    ));
+
+    let mut builder = DirBuilder::new();
+
+    #[cfg(unix)]
+    builder.mode(0o700);
+
+    builder.create(&path)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+pub fn get_fake_keyring() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/gpg/test-keyring.gpg")
+}
+
+#[cfg(test)]
+pub fn get_fake_specs_sig() -> Vec<u8> {
+    let sig = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/gpg/SHASUMS256.txt.sig");
+    fs::read(sig).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs;
+    use mockito::{Mock, Server};
+
+    fn setup_server_mock(
+        version: &str,
+        url_fmt: &mut NodeJSURLFormatter,
+        server: &mut Server,
+    ) -> Mock {
+        url_fmt.host = server.host_with_port();
+        url_fmt.protocol = "http:".to_string();
+        server.mock("GET", url_fmt.info_sig_pathname(version).as_str())
+    }
+
+    fn enabled(keyring: Option<PathBuf>) -> Settings {
+        Settings {
+            enabled: true,
+            keyring,
+        }
+    }
+
+    #[test]
+    fn it_creates_private_temp_dirs() {
+        let a = temp_dir("20.6.1").unwrap();
+        let b = temp_dir("20.6.1").unwrap();
+        assert_ne!(a, b);
+        assert!(a.is_dir());
+        assert!(a.to_string_lossy().ends_with("-v20.6.1"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&a).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+
+        let sig = write_new(&a, "SHASUMS256.txt.sig", b"SIG").unwrap();
+        assert_eq!(fs::read(&sig).unwrap(), b"SIG");
+        let err = write_new(&a, "SHASUMS256.txt.sig", b"NOPE").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(fs::read(&sig).unwrap(), b"SIG");
+
+        fs::remove_dir_all(&a).unwrap();
+        fs::remove_dir_all(&b).unwrap();
+    }
+
+    #[test]
+    fn it_fails_when_bundled_keyring_is_empty() {
+        assert_eq!(release_keys(b"KEYS").unwrap(), b"KEYS");
+        assert!(matches!(
+            release_keys(b""),
+            Err(NodeJSRelInfoError::MissingKeyring(path)) if path == "keys/pubring.kbx"
+        ));
+    }
+
+    #[test]
+    #[ignore = "requires gpgv"]
+    fn it_runs_gpgv() {
+        let dir = temp_dir("20.6.1").unwrap();
+        let sig = write_new(&dir, "SHASUMS256.txt.sig", &get_fake_specs_sig()).unwrap();
+        let keyring = get_fake_keyring();
+        let specs = specs::get_fake_specs();
+
+        assert!(run_gpgv(GPGV, &sig, specs, &keyring).unwrap());
+        assert!(!run_gpgv(GPGV, &sig, "FAKESPECS", &keyring).unwrap());
+
+        let err = run_gpgv("gpgv-nope", &sig, specs, &keyring).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_skips_verification_when_disabled() {
+        let version = String::from("20.6.1");
+        let url_fmt = NodeJSURLFormatter::new();
+        let http = http::Settings::default();
+        let settings = Settings::default();
+        verify(&version, "FAKESPECS", &url_fmt, &http, &settings)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires gpgv"]
+    async fn it_verifies_signature() {
+        let version = String::from("20.6.1");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&version, &mut url_fmt, &mut server)
+            .with_body(get_fake_specs_sig())
+            .create_async()
+            .await;
+
+        let http = http::Settings::default();
+        let settings = enabled(Some(get_fake_keyring()));
+        verify(
+            &version,
+            specs::get_fake_specs(),
+            &url_fmt,
+            &http,
+            &settings,
+        )
+        .await
+        .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires gpgv"]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidSignature(\"20.6.1\")"
+    )]
+    async fn it_fails_to_verify_when_specs_do_not_match_signature() {
+        let version = String::from("20.6.1");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&version, &mut url_fmt, &mut server)
+            .with_body(get_fake_specs_sig())
+            .create_async()
+            .await;
+
+        let http = http::Settings::default();
+        let settings = enabled(Some(get_fake_keyring()));
+        verify(&version, "FAKESPECS", &url_fmt, &http, &settings)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires gpgv"]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidSignature(\"20.6.1\")"
+    )]
+    async fn it_fails_to_verify_when_signature_is_invalid() {
+        let version = String::from("20.6.1");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&version, &mut url_fmt, &mut server)
+            .with_body("NOPE!")
+            .create_async()
+            .await;
+
+        let http = http::Settings::default();
+        let settings = enabled(Some(get_fake_keyring()));
+        verify(&version, "FAKESPECS", &url_fmt, &http, &settings)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: HttpStatus { status: 404"
+    )]
+    async fn it_fails_to_verify_when_signature_is_missing() {
+        let version = String::from("20.6.1");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&version, &mut url_fmt, &mut server)
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let http = http::Settings::default();
+        let settings = enabled(Some(get_fake_keyring()));
+        verify(&version, "FAKESPECS", &url_fmt, &http, &settings)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,1096 @@
+use crate::cache::CacheHandle;
+use crate::error::NodeJSRelInfoError;
+use reqwest::header::{
+    HeaderMap, HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RETRY_AFTER,
+};
+use reqwest::{Client, Response, StatusCode};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{NoProxy, Proxy};
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::fs::File;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::io::AsyncWriteExt;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Settings {
+    // explicit proxy url - when unset, `HTTP_PROXY` / `HTTPS_PROXY` / `NO_PROXY`
+    // env vars are respected (ignored on wasm32 where the browser decides)
+    pub proxy: Option<String>,
+    // `User-Agent` header sent w/ every request - defaults to `DEFAULT_USER_AGENT`
+    pub user_agent: Option<String>,
+    // max requests issued per second across the process - unlimited when unset
+    // (ignored on wasm32 where the browser schedules requests)
+    pub rate_limit: Option<u32>,
+    // user-provided response store consulted before issuing any GET request
+    pub cache: CacheHandle,
+    // max time to wait for each request to complete - unlimited when unset
+    // (ignored on wasm32 where the browser decides)
+    pub timeout: Option<Duration>,
+    // number of times to retry a GET request which failed w/ a retryable error
+    pub retries: u32,
+    // user-provided client used instead of the shared one - `proxy` and
+    // `user_agent` are ignored when set
+    pub client: Option<ClientHandle>,
+}
+
+// wraps a user-provided client so `Settings` can stay `PartialEq` - handles
+// are equal when they point at the same client
+#[derive(Clone, Debug)]
+pub struct ClientHandle(pub Arc<Client>);
+
+impl PartialEq for ClientHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type ClientKey = (Option<String>, Option<String>);
+
+// clients are built lazily and shared process-wide per `proxy` / `user_agent`
+// combination so repeated requests reuse pooled connections
+#[cfg(not(target_arch = "wasm32"))]
+fn clients() -> &'static Mutex<HashMap<ClientKey, Client>> {
+    static CLIENTS: OnceLock<Mutex<HashMap<ClientKey, Client>>> = OnceLock::new();
+    CLIENTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+impl Settings {
+    pub fn client(&self) -> Result<Client, NodeJSRelInfoError> {
+        if let Some(client) = &self.client {
+            return Ok(client.0.as_ref().clone());
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let key = (self.proxy.clone(), self.user_agent.clone());
+            let mut clients = clients().lock().unwrap();
+
+            if let Some(client) = clients.get(&key) {
+                return Ok(client.clone());
+            }
+
+            let client = self.build_client()?;
+            clients.insert(key, client.clone());
+            Ok(client)
+        }
+
+        // the browser pools connections so there is nothing to share
+        #[cfg(target_arch = "wasm32")]
+        self.build_client()
+    }
+
+    fn build_client(&self) -> Result<Client, NodeJSRelInfoError> {
+        let user_agent = self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut builder = Client::builder().user_agent(user_agent);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy)?.no_proxy(NoProxy::from_env()));
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+// `ETag` / `Last-Modified` headers of a previous response - kept in the
+// configured cache alongside its body (see: `Validators::key()`) so conditional
+// requests are only issued when a cache is in use
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    // cache key the validators for the given url are stored under
+    pub fn key(url: &str) -> String {
+        format!("{}#validators", url)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+
+    // header values can't contain newlines so one value per line is safe
+    fn encode(&self) -> String {
+        let etag = self.etag.as_deref().unwrap_or_default();
+        let last_modified = self.last_modified.as_deref().unwrap_or_default();
+        format!("{}\n{}", etag, last_modified)
+    }
+
+    fn decode(text: &str) -> Self {
+        let mut lines = text
+            .lines()
+            .map(|l| Some(l.to_string()).filter(|l| !l.is_empty()));
+        Validators {
+            etag: lines.next().flatten(),
+            last_modified: lines.next().flatten(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn next_slot() -> &'static Mutex<Option<Instant>> {
+    static NEXT_SLOT: OnceLock<Mutex<Option<Instant>>> = OnceLock::new();
+    NEXT_SLOT.get_or_init(|| Mutex::new(None))
+}
+
+// when a rate limit is configured, waits for the next available slot so that
+// requests are spaced at least `1 / rate_limit` seconds apart - slots are
+// shared process-wide so concurrent batch operations are throttled together
+pub async fn throttle(settings: &Settings) {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(rate_limit) = settings.rate_limit.filter(|r| *r > 0) {
+        let interval = Duration::from_secs(1) / rate_limit;
+        let wait = {
+            let mut next = next_slot().lock().unwrap();
+            let now = Instant::now();
+            let slot = next.map_or(now, |n| n.max(now));
+            *next = Some(slot + interval);
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    let _ = settings;
+}
+
+fn header_value(headers: &HeaderMap, name: HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+// wall-clock millis used to time requests for `tracing` events
+#[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// `SystemTime::now()` panics in the browser so ask JS instead
+#[cfg(all(feature = "tracing", target_arch = "wasm32"))]
+fn now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+// captures the status, final url, and `Retry-After` delay (when sent as a
+// number of seconds) of an unsuccessful response
+pub fn status_error(res: &Response) -> NodeJSRelInfoError {
+    let retry_after = header_value(res.headers(), RETRY_AFTER).and_then(|v| v.trim().parse().ok());
+
+    NodeJSRelInfoError::HttpStatus {
+        status: res.status().as_u16(),
+        url: res.url().to_string(),
+        retry_after,
+    }
+}
+
+// issues the GET request retrying up to `settings.retries` times when it fails
+// w/ a retryable error (timeout, 5xx, etc) - see: `get_once()`
+pub async fn get<U: AsRef<str>>(url: U, settings: &Settings) -> Result<String, NodeJSRelInfoError> {
+    let url = url.as_ref();
+    let mut attempt = 0;
+
+    loop {
+        match get_once(url, settings).await {
+            Err(e) if e.is_retryable() && attempt < settings.retries => {
+                attempt += 1;
+                #[cfg(feature = "tracing")]
+                tracing::warn!(url, attempt, error = %e, "retrying");
+                backoff(&e, attempt).await;
+            }
+            res => return res,
+        }
+    }
+}
+
+// waits before retrying - honors `Retry-After` (up to 30s) when sent, otherwise
+// backs off exponentially starting at 100ms (no-op on wasm32)
+async fn backoff(err: &NodeJSRelInfoError, attempt: u32) {
+    let wait = match err {
+        NodeJSRelInfoError::HttpStatus {
+            retry_after: Some(secs),
+            ..
+        } => Duration::from_secs((*secs).min(30)),
+        _ => Duration::from_millis(100) * 2u32.saturating_pow(attempt - 1).min(64),
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(wait).await;
+
+    #[cfg(target_arch = "wasm32")]
+    let _ = wait;
+}
+
+// returns the response stored in the configured cache when available - when
+// validators (`ETag` / `Last-Modified`) were stored along w/ it, a conditional
+// GET is issued instead and a `304 Not Modified` response is treated as a
+// cache hit while any other unsuccessful response is surfaced as `HttpStatus`
+// (entries w/o validators are only reused for immutable urls - see: `is_immutable()`)
+async fn get_once(url: &str, settings: &Settings) -> Result<String, NodeJSRelInfoError> {
+    let cache = &settings.cache.0;
+    let cached = cache.get(url).map(|body| {
+        let validators = cache
+            .get(&Validators::key(url))
+            .map(|v| Validators::decode(&v))
+            .unwrap_or_default();
+        (body, validators)
+    });
+
+    if let Some((body, validators)) = &cached {
+        if validators.is_empty() && is_immutable(url) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(url, "cache hit");
+            return Ok(body.clone());
+        }
+    }
+
+    let mut req = settings.client()?.get(url);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(timeout) = settings.timeout {
+        req = req.timeout(timeout);
+    }
+
+    throttle(settings).await;
+
+    if let Some((_, validators)) = &cached {
+        if let Some(etag) = &validators.etag {
+            req = req.header(IF_NONE_MATCH, etag);
+        }
+
+        if let Some(last_modified) = &validators.last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    let start = now_ms();
+    let res = req.send().await?;
+    let status = res.status();
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        url,
+        status = status.as_u16(),
+        elapsed_ms = now_ms().saturating_sub(start),
+        "GET"
+    );
+
+    if status == StatusCode::NOT_MODIFIED {
+        if let Some((body, _)) = cached {
+            return Ok(body);
+        }
+    }
+
+    if !status.is_success() {
+        return Err(status_error(&res));
+    }
+
+    let validators = Validators {
+        etag: header_value(res.headers(), ETAG),
+        last_modified: header_value(res.headers(), LAST_MODIFIED),
+    };
+    let body = res.text().await?;
+
+    // overwrite validators left by a previous response so they don't go stale
+    if !validators.is_empty() || cached.is_some() {
+        cache.set(&Validators::key(url), &validators.encode());
+    }
+
+    cache.set(url, &body);
+    Ok(body)
+}
+
+// whether the url points into a version directory (e.g. `/v20.6.1/SHASUMS256.txt`)
+// whose contents never change once published - unlike `index.json`,
+// `schedule.json`, or `latest-v{major}.x` aliases which are updated in place
+fn is_immutable(url: &str) -> bool {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+
+    path.split('/').any(|segment| {
+        let parts = match segment.strip_prefix('v') {
+            Some(version) => version.split('.').collect::<Vec<_>>(),
+            None => return false,
+        };
+
+        parts.len() == 3
+            && parts
+                .iter()
+                .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+// streams the response body to `dest` chunk by chunk, reporting bytes written
+// so far along with the expected total (via `Content-Length`, when provided)
+// after each chunk so large artifacts never need to be buffered in memory
+pub async fn download<U, P, F>(
+    url: U,
+    dest: P,
+    settings: &Settings,
+    mut on_progress: F,
+) -> Result<u64, NodeJSRelInfoError>
+where
+    U: AsRef<str>,
+    P: AsRef<Path>,
+    F: FnMut(u64, Option<u64>),
+{
+    // the timeout applies to each wait (headers, then every chunk) rather than
+    // the whole transfer so large artifacts on slow connections aren't cut off
+    let req = settings.client()?.get(url.as_ref());
+
+    throttle(settings).await;
+    #[cfg(feature = "tracing")]
+    let start = now_ms();
+    let mut res = within(settings.timeout, req.send()).await?;
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(url = url.as_ref(), status = res.status().as_u16(), "GET");
+
+    if !res.status().is_success() {
+        return Err(status_error(&res));
+    }
+
+    let total = res.content_length();
+    let mut file = File::create(dest).await?;
+    let mut done: u64 = 0;
+
+    on_progress(done, total);
+
+    while let Some(chunk) = within(settings.timeout, res.chunk()).await? {
+        file.write_all(&chunk).await?;
+        done += chunk.len() as u64;
+        on_progress(done, total);
+    }
+
+    file.flush().await?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        url = url.as_ref(),
+        bytes = done,
+        elapsed_ms = now_ms().saturating_sub(start),
+        "downloaded"
+    );
+    Ok(done)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+// awaits `fut` failing w/ `std::io::ErrorKind::TimedOut` if it takes longer
+// than `timeout` (when set)
+async fn within<T, F>(timeout: Option<Duration>, fut: F) -> Result<T, NodeJSRelInfoError>
+where
+    F: std::future::Future<Output = Result<T, reqwest::Error>>,
+{
+    let timeout = match timeout {
+        Some(t) => t,
+        None => return Ok(fut.await?),
+    };
+
+    match tokio::time::timeout(timeout, fut).await {
+        Ok(res) => Ok(res?),
+        Err(elapsed) => Err(std::io::Error::from(elapsed).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{MemoryReleaseInfoCache, ReleaseInfoCache};
+    use mockito::{Matcher, Server};
+    use std::sync::Arc;
+
+    fn cached() -> Settings {
+        Settings {
+            cache: CacheHandle(Arc::new(MemoryReleaseInfoCache::new())),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn it_fetches_a_url() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/no-validators.txt", server.url());
+        let mock = server
+            .mock("GET", "/no-validators.txt")
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        let body = get(&url, &Settings::default()).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(body, "hello");
+    }
+
+    #[tokio::test]
+    async fn it_issues_conditional_request_using_etag() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/etag.txt", server.url());
+        let mock1 = server
+            .mock("GET", "/etag.txt")
+            .match_header("if-none-match", Matcher::Missing)
+            .with_header("etag", "\"abc123\"")
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        let settings = cached();
+        let body = get(&url, &settings).await.unwrap();
+        mock1.assert_async().await;
+
+        assert_eq!(body, "hello");
+
+        let mock2 = server
+            .mock("GET", "/etag.txt")
+            .match_header("if-none-match", "\"abc123\"")
+            .with_status(304)
+            .create_async()
+            .await;
+
+        let body = get(&url, &settings).await.unwrap();
+        mock2.assert_async().await;
+
+        assert_eq!(body, "hello");
+    }
+
+    #[tokio::test]
+    async fn it_issues_conditional_request_using_last_modified() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/last-modified.txt", server.url());
+        let last_modified = "Tue, 12 Sep 2023 22:01:31 GMT";
+        let mock1 = server
+            .mock("GET", "/last-modified.txt")
+            .match_header("if-modified-since", Matcher::Missing)
+            .with_header("last-modified", last_modified)
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        let settings = cached();
+        get(&url, &settings).await.unwrap();
+        mock1.assert_async().await;
+
+        let validators = settings.cache.0.get(&Validators::key(&url)).unwrap();
+        let validators = Validators::decode(&validators);
+
+        assert_eq!(validators.etag, None);
+        assert_eq!(validators.last_modified, Some(last_modified.to_string()));
+
+        let mock2 = server
+            .mock("GET", "/last-modified.txt")
+            .match_header("if-modified-since", last_modified)
+            .with_status(304)
+            .create_async()
+            .await;
+
+        let body = get(&url, &settings).await.unwrap();
+        mock2.assert_async().await;
+
+        assert_eq!(body, "hello");
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retain_validators_without_a_cache() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/uncached.txt", server.url());
+        let mock = server
+            .mock("GET", "/uncached.txt")
+            .match_header("if-none-match", Matcher::Missing)
+            .with_header("etag", "\"abc123\"")
+            .with_body("hello")
+            .expect(2)
+            .create_async()
+            .await;
+
+        get(&url, &Settings::default()).await.unwrap();
+        let body = get(&url, &Settings::default()).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(body, "hello");
+    }
+
+    #[tokio::test]
+    async fn it_replaces_stale_validators() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/stale.txt", server.url());
+        let settings = cached();
+        let mock1 = server
+            .mock("GET", "/stale.txt")
+            .with_header("etag", "\"abc123\"")
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        get(&url, &settings).await.unwrap();
+        mock1.assert_async().await;
+        mock1.remove_async().await;
+
+        let mock2 = server
+            .mock("GET", "/stale.txt")
+            .match_header("if-none-match", "\"abc123\"")
+            .with_body("goodbye")
+            .create_async()
+            .await;
+
+        let body = get(&url, &settings).await.unwrap();
+        mock2.assert_async().await;
+
+        assert_eq!(body, "goodbye");
+        assert_eq!(settings.cache.0.get(&url), Some("goodbye".to_string()));
+        assert_eq!(
+            settings.cache.0.get(&Validators::key(&url)),
+            Some("\n".to_string())
+        );
+
+        // w/o validators mutable urls are requested again
+        mock2.remove_async().await;
+        let mock3 = server
+            .mock("GET", "/stale.txt")
+            .match_header("if-none-match", Matcher::Missing)
+            .with_body("hello again")
+            .create_async()
+            .await;
+
+        let body = get(&url, &settings).await.unwrap();
+        mock3.assert_async().await;
+
+        assert_eq!(body, "hello again");
+    }
+
+    #[tokio::test]
+    async fn it_reuses_cached_responses_without_validators_only_when_immutable() {
+        let mut server = Server::new_async().await;
+        let settings = cached();
+        let index_url = format!("{}/download/release/index.json", server.url());
+        let specs_url = format!("{}/download/release/v20.6.1/SHASUMS256.txt", server.url());
+        let index_mock = server
+            .mock("GET", "/download/release/index.json")
+            .with_body("[]")
+            .expect(2)
+            .create_async()
+            .await;
+        let specs_mock = server
+            .mock("GET", "/download/release/v20.6.1/SHASUMS256.txt")
+            .with_body("specs")
+            .expect(1)
+            .create_async()
+            .await;
+
+        for _ in 0..2 {
+            assert_eq!(get(&index_url, &settings).await.unwrap(), "[]");
+            assert_eq!(get(&specs_url, &settings).await.unwrap(), "specs");
+        }
+
+        index_mock.assert_async().await;
+        specs_mock.assert_async().await;
+    }
+
+    #[test]
+    fn it_recognizes_immutable_urls() {
+        let base = "https://nodejs.org/download/release";
+        assert!(is_immutable(&format!("{base}/v20.6.1/SHASUMS256.txt")));
+        assert!(is_immutable(&format!("{base}/v20.6.1/SHASUMS256.txt.sig")));
+        assert!(!is_immutable(&format!("{base}/index.json")));
+        assert!(!is_immutable(&format!(
+            "{base}/latest-v20.x/SHASUMS256.txt"
+        )));
+        assert!(!is_immutable(&format!("{base}/v20.x/SHASUMS256.txt")));
+        assert!(!is_immutable(
+            "https://raw.githubusercontent.com/nodejs/Release/main/schedule.json"
+        ));
+        assert!(!is_immutable("https://example.com/index.json?v=v20.6.1"));
+    }
+
+    #[test]
+    fn it_encodes_and_decodes_validators() {
+        let validators = Validators {
+            etag: Some("\"abc123\"".to_string()),
+            last_modified: Some("Tue, 12 Sep 2023 22:01:31 GMT".to_string()),
+        };
+        assert_eq!(Validators::decode(&validators.encode()), validators);
+
+        let validators = Validators {
+            etag: None,
+            last_modified: Some("Tue, 12 Sep 2023 22:01:31 GMT".to_string()),
+        };
+        assert_eq!(Validators::decode(&validators.encode()), validators);
+        assert!(Validators::decode(&Validators::default().encode()).is_empty());
+        assert_eq!(
+            Validators::key("https://nodejs.org/dist/index.json"),
+            "https://nodejs.org/dist/index.json#validators"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fetches_a_url_through_a_proxy() {
+        let mut server = Server::new_async().await;
+        let url = "http://nodejs.invalid/proxied.txt";
+        let settings = Settings {
+            proxy: Some(server.url()),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", Matcher::Any)
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        let body = get(url, &settings).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(body, "hello");
+    }
+
+    #[test]
+    fn it_fails_to_build_client_when_proxy_is_invalid() {
+        let settings = Settings {
+            proxy: Some("not a url".to_string()),
+            ..Default::default()
+        };
+        let err = settings.client().unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::HttpError(_)));
+    }
+
+    #[tokio::test]
+    async fn it_only_retries_requests_when_configured_and_retryable() {
+        let mut server = Server::new_async().await;
+        let down = server
+            .mock("GET", "/down.txt")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let missing = server
+            .mock("GET", "/missing.txt")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let settings = Settings {
+            retries: 2,
+            ..Default::default()
+        };
+
+        let url = format!("{}/down.txt", server.url());
+        let err = get(&url, &Settings::default()).await.unwrap_err();
+        down.assert_async().await;
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus { status: 503, .. }
+        ));
+
+        let url = format!("{}/missing.txt", server.url());
+        let err = get(&url, &settings).await.unwrap_err();
+        missing.assert_async().await;
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus { status: 404, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_gives_up_after_configured_retries() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/down.txt")
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+        let settings = Settings {
+            retries: 2,
+            ..Default::default()
+        };
+
+        let err = get(format!("{}/down.txt", server.url()), &settings)
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus { status: 500, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_times_out_slow_requests() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/slow.txt", listener.local_addr().unwrap());
+        let settings = Settings {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+
+        // accepts the connection but never responds
+        let err = get(&url, &settings).await.unwrap_err();
+        drop(listener);
+
+        assert!(matches!(err, NodeJSRelInfoError::HttpError(ref e) if e.is_timeout()));
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn it_shares_clients_per_proxy_and_user_agent() {
+        let settings = Settings {
+            user_agent: Some("it-shares-clients/1.0.0".to_string()),
+            ..Default::default()
+        };
+        let key = (None, settings.user_agent.clone());
+        assert!(!clients().lock().unwrap().contains_key(&key));
+
+        settings.client().unwrap();
+        settings.client().unwrap();
+
+        assert!(clients().lock().unwrap().contains_key(&key));
+    }
+
+    #[tokio::test]
+    async fn it_uses_provided_client() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/hello.txt")
+            .match_header("user-agent", "custom-client/1.0.0")
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .user_agent("custom-client/1.0.0")
+            .build()
+            .unwrap();
+        let handle = ClientHandle(Arc::new(client));
+        let settings = Settings {
+            user_agent: Some("ignored/1.0.0".to_string()),
+            client: Some(handle.clone()),
+            ..Default::default()
+        };
+
+        let body = get(format!("{}/hello.txt", server.url()), &settings)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(body, "hello");
+        assert_eq!(handle, handle.clone());
+        assert_ne!(handle, ClientHandle(Arc::new(Client::new())));
+    }
+
+    #[tokio::test]
+    async fn it_downloads_a_url_reporting_progress() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/download.bin", server.url());
+        let dest = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-download.bin",
+            std::process::id()
+        ));
+        let body = vec![7u8; 64 * 1024];
+        let mock = server
+            .mock("GET", "/download.bin")
+            .with_body(&body)
+            .create_async()
+            .await;
+
+        let mut progress = Vec::new();
+        let size = download(&url, &dest, &Settings::default(), |done, total| {
+            progress.push((done, total))
+        })
+        .await
+        .unwrap();
+        mock.assert_async().await;
+
+        let total = Some(body.len() as u64);
+        assert_eq!(size, body.len() as u64);
+        assert_eq!(progress.first(), Some(&(0, total)));
+        assert_eq!(progress.last(), Some(&(size, total)));
+        assert!(progress.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert_eq!(std::fs::read(&dest).unwrap(), body);
+        std::fs::remove_file(&dest).unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_download_when_request_fails() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/missing.bin", server.url());
+        let dest = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-missing.bin",
+            std::process::id()
+        ));
+        let mock = server
+            .mock("GET", "/missing.bin")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let err = download(&url, &dest, &Settings::default(), |_, _| {})
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus { status: 404, .. }
+        ));
+        assert!(!dest.exists());
+    }
+
+    #[tokio::test]
+    async fn it_downloads_slow_bodies_exceeding_timeout_overall() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/slow.bin", server.url());
+        let dest = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-slow.bin",
+            std::process::id()
+        ));
+        let settings = Settings {
+            timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", "/slow.bin")
+            .with_chunked_body(|w| {
+                for _ in 0..5 {
+                    std::thread::sleep(Duration::from_millis(100));
+                    w.write_all(&[7u8; 1024])?;
+                    w.flush()?;
+                }
+                Ok(())
+            })
+            .create_async()
+            .await;
+
+        let size = download(&url, &dest, &settings, |_, _| {}).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(size, 5 * 1024);
+        assert_eq!(std::fs::read(&dest).unwrap(), vec![7u8; 5 * 1024]);
+        std::fs::remove_file(&dest).unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_download_when_body_stalls() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/stalled.bin", server.url());
+        let dest = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-stalled.bin",
+            std::process::id()
+        ));
+        let settings = Settings {
+            timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", "/stalled.bin")
+            .with_chunked_body(|w| {
+                w.write_all(&[7u8; 1024])?;
+                w.flush()?;
+                std::thread::sleep(Duration::from_millis(500));
+                w.write_all(&[7u8; 1024])
+            })
+            .create_async()
+            .await;
+
+        let err = download(&url, &dest, &settings, |_, _| {})
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+        std::fs::remove_file(&dest).unwrap();
+
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::IoError(ref e) if e.kind() == std::io::ErrorKind::TimedOut
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_sends_default_user_agent() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/default-user-agent.txt", server.url());
+        let mock = server
+            .mock("GET", "/default-user-agent.txt")
+            .match_header("user-agent", DEFAULT_USER_AGENT)
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        get(&url, &Settings::default()).await.unwrap();
+        mock.assert_async().await;
+
+        assert!(DEFAULT_USER_AGENT.starts_with("node-js-release-info/"));
+    }
+
+    #[tokio::test]
+    async fn it_sends_custom_user_agent() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/custom-user-agent.txt", server.url());
+        let settings = Settings {
+            user_agent: Some("my-tool/1.0.0".to_string()),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", "/custom-user-agent.txt")
+            .match_header("user-agent", "my-tool/1.0.0")
+            .with_body("hello")
+            .create_async()
+            .await;
+
+        get(&url, &settings).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_stores_responses_in_configured_cache() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/v20.6.1/cached.txt", server.url());
+        let cache = Arc::new(MemoryReleaseInfoCache::new());
+        let settings = Settings {
+            cache: CacheHandle(cache.clone()),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", "/v20.6.1/cached.txt")
+            .with_body("hello")
+            .expect(1)
+            .create_async()
+            .await;
+
+        assert_eq!(get(&url, &settings).await.unwrap(), "hello");
+        assert_eq!(get(&url, &settings).await.unwrap(), "hello");
+        mock.assert_async().await;
+
+        assert_eq!(cache.get(&url), Some("hello".to_string()));
+    }
+
+    #[tokio::test]
+    async fn it_uses_cached_response_without_issuing_request() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/v20.6.1/pre-cached.txt", server.url());
+        let cache = MemoryReleaseInfoCache::new();
+        cache.set(&url, "from cache");
+        let settings = Settings {
+            cache: CacheHandle(Arc::new(cache)),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", "/v20.6.1/pre-cached.txt")
+            .expect(0)
+            .create_async()
+            .await;
+
+        assert_eq!(get(&url, &settings).await.unwrap(), "from cache");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_throttles_requests_when_rate_limited() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/rate-limit.txt", server.url());
+        let settings = Settings {
+            rate_limit: Some(20),
+            ..Default::default()
+        };
+        let mock = server
+            .mock("GET", "/rate-limit.txt")
+            .with_body("hello")
+            .expect(5)
+            .create_async()
+            .await;
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            get(&url, &settings).await.unwrap();
+        }
+        mock.assert_async().await;
+
+        // 5 requests @ 20/sec need at least 4 x 50ms gaps
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn it_does_not_throttle_when_rate_limit_is_unset() {
+        let start = Instant::now();
+        for _ in 0..100 {
+            throttle(&Settings::default()).await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn it_returns_status_details_when_request_fails() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/missing.txt", server.url());
+        let mock = server
+            .mock("GET", "/missing.txt")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let err = get(&url, &Settings::default()).await.unwrap_err();
+        mock.assert_async().await;
+
+        match err {
+            NodeJSRelInfoError::HttpStatus {
+                status,
+                url: received,
+                retry_after,
+            } => {
+                assert_eq!(status, 404);
+                assert_eq!(received, url);
+                assert_eq!(retry_after, None);
+            }
+            _ => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_returns_retry_after_when_rate_limited() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/rate-limited.txt", server.url());
+        let mock = server
+            .mock("GET", "/rate-limited.txt")
+            .with_status(429)
+            .with_header("retry-after", "120")
+            .create_async()
+            .await;
+
+        let err = get(&url, &Settings::default()).await.unwrap_err();
+        mock.assert_async().await;
+
+        assert!(err.is_retryable());
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus {
+                status: 429,
+                retry_after: Some(120),
+                ..
+            }
+        ));
+    }
+}
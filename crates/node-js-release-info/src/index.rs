@@ -0,0 +1,291 @@
+use crate::error::NodeJSRelInfoError;
+use crate::http;
+use crate::url::NodeJSURLFormatter;
+use semver::Version;
+use serde::de::Deserializer;
+use serde::Deserialize;
+#[cfg(feature = "json")]
+use serde::Serialize;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct NodeJSVersionInfo {
+    /// The version of Node.js as a [semver](https://semver.org) string
+    #[serde(deserialize_with = "deserialize_version")]
+    pub version: String,
+    /// The date the version was released (`YYYY-MM-DD`)
+    pub date: String,
+    /// The version of npm bundled with the release
+    pub npm: Option<String>,
+    /// The version of V8 bundled with the release
+    pub v8: Option<String>,
+    /// The LTS codename (e.g. `Hydrogen`) when the version is part of an LTS line
+    #[serde(deserialize_with = "deserialize_lts")]
+    pub lts: Option<String>,
+    /// Whether the version was a security release
+    pub security: bool,
+    /// The platform identifiers (e.g. `darwin-arm64-tar`) of the published distributables
+    pub files: Vec<String>,
+}
+
+impl NodeJSVersionInfo {
+    /// Whether the version was a security release
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSVersionInfo;
+    /// let info = NodeJSVersionInfo { security: true, ..Default::default() };
+    /// assert!(info.is_security_release());
+    /// ```
+    pub fn is_security_release(&self) -> bool {
+        self.security
+    }
+}
+
+fn deserialize_version<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let version = String::deserialize(deserializer)?;
+    Ok(version.trim_start_matches('v').to_owned())
+}
+
+// `lts` is either `false` or the LTS codename
+fn deserialize_lts<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Lts {
+        Flag(#[allow(dead_code)] bool),
+        Codename(String),
+    }
+
+    match Lts::deserialize(deserializer)? {
+        Lts::Flag(_) => Ok(None),
+        Lts::Codename(name) => Ok(Some(name)),
+    }
+}
+
+pub async fn fetch(
+    url_fmt: &NodeJSURLFormatter,
+    settings: &http::Settings,
+) -> Result<Vec<NodeJSVersionInfo>, NodeJSRelInfoError> {
+    let index_url = url_fmt.index();
+    let body = match http::get(&index_url, settings).await {
+        Err(NodeJSRelInfoError::HttpStatus { status: 404, .. }) => {
+            return Err(NodeJSRelInfoError::InvalidReleaseIndex(index_url));
+        }
+        res => res?,
+    };
+
+    parse(&index_url, &body)
+}
+
+// finds the newest release within any LTS line or, when provided, the LTS
+// line with the given codename (case-insensitive)
+pub fn latest_lts(
+    versions: Vec<NodeJSVersionInfo>,
+    codename: Option<&str>,
+) -> Option<NodeJSVersionInfo> {
+    versions
+        .into_iter()
+        .filter(|v| match (&v.lts, codename) {
+            (Some(lts), Some(codename)) => lts.eq_ignore_ascii_case(codename),
+            (Some(_), None) => true,
+            (None, _) => false,
+        })
+        .max_by(|a, b| compare_versions(&a.version, &b.version))
+}
+
+// finds the newest release within the given major version
+pub fn latest_in_major(versions: Vec<NodeJSVersionInfo>, major: u64) -> Option<NodeJSVersionInfo> {
+    versions
+        .into_iter()
+        .filter(|v| match Version::parse(&v.version) {
+            Ok(version) => version.major == major,
+            Err(_) => false,
+        })
+        .max_by(|a, b| compare_versions(&a.version, &b.version))
+}
+
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    match (Version::parse(a), Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+pub fn parse<U: AsRef<str>>(
+    index_url: U,
+    index: &str,
+) -> Result<Vec<NodeJSVersionInfo>, NodeJSRelInfoError> {
+    match serde_json::from_str(index) {
+        Ok(versions) => Ok(versions),
+        Err(_) => Err(NodeJSRelInfoError::InvalidReleaseIndex(
+            index_url.as_ref().to_owned(),
+        )),
+    }
+}
+
+#[cfg(test)]
+use mockito::{Mock, Server};
+
+#[cfg(test)]
+pub fn setup_server_mock(url_fmt: &mut NodeJSURLFormatter, server: &mut Server) -> Mock {
+    url_fmt.host = server.host_with_port();
+    url_fmt.protocol = "http:".to_string();
+    server.mock("GET", url_fmt.index_pathname().as_str())
+}
+
+#[cfg(test)]
+pub fn get_fake_index() -> &'static str {
+    r#"[
+    {"version":"v20.7.0","date":"2023-09-18","files":["aix-ppc64","headers","linux-arm64","linux-armv7l","linux-ppc64le","linux-s390x","linux-x64","osx-arm64-tar","osx-x64-pkg","osx-x64-tar","src","win-arm64-7z","win-arm64-zip","win-x64-7z","win-x64-exe","win-x64-msi","win-x64-zip","win-x86-7z","win-x86-exe","win-x86-msi","win-x86-zip"],"npm":"10.1.0","v8":"11.3.244.8","uv":"1.46.0","zlib":"1.2.13.1-motley","openssl":"3.0.10+quic","modules":"115","lts":false,"security":false},
+    {"version":"v20.6.1","date":"2023-09-08","files":["aix-ppc64","headers","linux-arm64","linux-armv7l","linux-ppc64le","linux-s390x","linux-x64","osx-arm64-tar","osx-x64-pkg","osx-x64-tar","src","win-arm64-7z","win-arm64-zip","win-x64-7z","win-x64-exe","win-x64-msi","win-x64-zip","win-x86-7z","win-x86-exe","win-x86-msi","win-x86-zip"],"npm":"9.8.1","v8":"11.3.244.8","uv":"1.46.0","zlib":"1.2.13.1-motley","openssl":"3.0.10+quic","modules":"115","lts":false,"security":false},
+    {"version":"v18.18.0","date":"2023-09-18","files":["aix-ppc64","headers","linux-arm64","linux-armv7l","linux-ppc64le","linux-s390x","linux-x64","osx-arm64-tar","osx-x64-pkg","osx-x64-tar","src","win-x64-7z","win-x64-exe","win-x64-msi","win-x64-zip","win-x86-7z","win-x86-exe","win-x86-msi","win-x86-zip"],"npm":"9.8.1","v8":"10.2.154.26","uv":"1.44.2","zlib":"1.2.13.1-motley","openssl":"3.0.10+quic","modules":"108","lts":"Hydrogen","security":false},
+    {"version":"v18.17.1","date":"2023-08-09","files":["aix-ppc64","headers","linux-arm64","linux-armv7l","linux-ppc64le","linux-s390x","linux-x64","osx-arm64-tar","osx-x64-pkg","osx-x64-tar","src","win-x64-7z","win-x64-exe","win-x64-msi","win-x64-zip","win-x86-7z","win-x86-exe","win-x86-msi","win-x86-zip"],"npm":"9.6.7","v8":"10.2.154.26","uv":"1.44.2","zlib":"1.2.13.1-motley","openssl":"3.0.10+quic","modules":"108","lts":"Hydrogen","security":true},
+    {"version":"v16.20.2","date":"2023-08-09","files":["aix-ppc64","headers","linux-arm64","linux-armv7l","linux-ppc64le","linux-s390x","linux-x64","osx-arm64-tar","osx-x64-pkg","osx-x64-tar","src","win-x64-7z","win-x64-exe","win-x64-msi","win-x64-zip","win-x86-7z","win-x86-exe","win-x86-msi","win-x86-zip"],"npm":"8.19.4","v8":"9.4.146.26","uv":"1.43.0","zlib":"1.2.11","openssl":"1.1.1v+quic","modules":"93","lts":"Gallium","security":true}
+]"#
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_node_js_release_index() {
+        let versions = parse("index.json", get_fake_index()).unwrap();
+        assert_eq!(versions.len(), 5);
+        assert_eq!(versions[0].version, "20.7.0");
+        assert_eq!(versions[0].date, "2023-09-18");
+        assert_eq!(versions[0].lts, None);
+        assert!(!versions[0].security);
+        assert_eq!(versions[0].files.len(), 21);
+        assert_eq!(versions[0].npm, Some("10.1.0".to_string()));
+        assert_eq!(versions[0].v8, Some("11.3.244.8".to_string()));
+        assert_eq!(versions[3].version, "18.17.1");
+        assert_eq!(versions[3].lts, Some("Hydrogen".to_string()));
+        assert!(versions[3].security);
+        assert!(versions[3].is_security_release());
+    }
+
+    #[test]
+    fn it_parses_node_js_release_index_when_optional_fields_are_missing() {
+        let index = r#"[{"version":"v0.1.14","date":"2011-08-26","files":["src"],"lts":false,"security":false}]"#;
+        let versions = parse("index.json", index).unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].version, "0.1.14");
+        assert_eq!(versions[0].npm, None);
+        assert_eq!(versions[0].v8, None);
+    }
+
+    #[test]
+    fn it_finds_latest_lts_release() {
+        let versions = parse("index.json", get_fake_index()).unwrap();
+        let info = latest_lts(versions, None).unwrap();
+        assert_eq!(info.version, "18.18.0");
+    }
+
+    #[test]
+    fn it_finds_latest_release_for_named_lts_line() {
+        let versions = parse("index.json", get_fake_index()).unwrap();
+        let info = latest_lts(versions.clone(), Some("hydrogen")).unwrap();
+
+        assert_eq!(info.version, "18.18.0");
+
+        let info = latest_lts(versions.clone(), Some("GALLIUM")).unwrap();
+
+        assert_eq!(info.version, "16.20.2");
+        assert_eq!(latest_lts(versions, Some("NOPE")), None);
+    }
+
+    #[test]
+    fn it_finds_latest_lts_release_regardless_of_order() {
+        let mut versions = parse("index.json", get_fake_index()).unwrap();
+        versions.reverse();
+        let info = latest_lts(versions, None).unwrap();
+        assert_eq!(info.version, "18.18.0");
+    }
+
+    #[test]
+    fn it_finds_latest_release_within_major_version() {
+        let versions = parse("index.json", get_fake_index()).unwrap();
+        let info = latest_in_major(versions.clone(), 20).unwrap();
+
+        assert_eq!(info.version, "20.7.0");
+
+        let info = latest_in_major(versions.clone(), 18).unwrap();
+
+        assert_eq!(info.version, "18.18.0");
+        assert_eq!(latest_in_major(versions, 99), None);
+    }
+
+    #[test]
+    fn it_parses_empty_node_js_release_index() {
+        let versions = parse("index.json", "[]").unwrap();
+        assert!(versions.is_empty());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidReleaseIndex(\"index.json\")"
+    )]
+    fn it_fails_to_parse_invalid_node_js_release_index() {
+        parse("index.json", "NOPE!").unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_index() {
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&mut url_fmt, &mut server)
+            .with_body(get_fake_index())
+            .create_async()
+            .await;
+
+        let versions = fetch(&url_fmt, &http::Settings::default()).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(versions.len(), 5);
+        assert_eq!(versions[1].version, "20.6.1");
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_fetch_node_js_release_index_when_not_found() {
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&mut url_fmt, &mut server)
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let err = fetch(&url_fmt, &http::Settings::default())
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+        assert_eq!(
+            format!("{err}"),
+            format!(
+                "Error: Invalid Release Index! Received: '{}'",
+                url_fmt.index()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_fetch_node_js_release_index_when_request_fails() {
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&mut url_fmt, &mut server)
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let err = fetch(&url_fmt, &http::Settings::default())
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+        assert!(err.is_retryable());
+        assert_eq!(
+            format!("{err}"),
+            format!("Error: HTTP Status 500! Received: '{}'", url_fmt.index())
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::error::NodeJSRelInfoError;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "json", derive(Deserialize, Serialize))]
+pub enum NodeJSArtifactKind {
+    #[cfg_attr(feature = "json", serde(rename = "binary"))]
+    Binary,
+    #[cfg_attr(feature = "json", serde(rename = "headers"))]
+    Headers,
+    #[cfg_attr(feature = "json", serde(rename = "source"))]
+    Source,
+    #[cfg_attr(feature = "json", serde(rename = "pkg"))]
+    Pkg,
+    #[cfg_attr(feature = "json", serde(rename = "exe"))]
+    Exe,
+    #[cfg_attr(feature = "json", serde(rename = "lib"))]
+    Lib,
+    #[cfg_attr(feature = "json", serde(rename = "pdb"))]
+    Pdb,
+}
+
+impl Default for NodeJSArtifactKind {
+    fn default() -> Self {
+        NodeJSArtifactKind::new()
+    }
+}
+
+impl NodeJSArtifactKind {
+    pub fn new() -> NodeJSArtifactKind {
+        NodeJSArtifactKind::Binary
+    }
+}
+
+impl Display for NodeJSArtifactKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            NodeJSArtifactKind::Binary => "binary",
+            NodeJSArtifactKind::Headers => "headers",
+            NodeJSArtifactKind::Source => "source",
+            NodeJSArtifactKind::Pkg => "pkg",
+            NodeJSArtifactKind::Exe => "exe",
+            NodeJSArtifactKind::Lib => "lib",
+            NodeJSArtifactKind::Pdb => "pdb",
+        };
+
+        write!(f, "{}", kind)
+    }
+}
+
+impl FromStr for NodeJSArtifactKind {
+    type Err = NodeJSRelInfoError;
+
+    fn from_str(s: &str) -> Result<NodeJSArtifactKind, NodeJSRelInfoError> {
+        match s {
+            "binary" => Ok(NodeJSArtifactKind::Binary),
+            "headers" => Ok(NodeJSArtifactKind::Headers),
+            "source" => Ok(NodeJSArtifactKind::Source),
+            "pkg" => Ok(NodeJSArtifactKind::Pkg),
+            "exe" => Ok(NodeJSArtifactKind::Exe),
+            "lib" => Ok(NodeJSArtifactKind::Lib),
+            "pdb" => Ok(NodeJSArtifactKind::Pdb),
+            _ => Err(NodeJSRelInfoError::UnrecognizedKind(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_initializes() {
+        let kind = NodeJSArtifactKind::new();
+        assert_eq!(kind, NodeJSArtifactKind::Binary);
+    }
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        let kind = NodeJSArtifactKind::default();
+        assert_eq!(kind, NodeJSArtifactKind::Binary);
+    }
+
+    #[test]
+    fn it_initializes_from_str() {
+        let kind = NodeJSArtifactKind::from_str("binary").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Binary);
+
+        let kind = NodeJSArtifactKind::from_str("headers").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Headers);
+
+        let kind = NodeJSArtifactKind::from_str("source").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Source);
+
+        let kind = NodeJSArtifactKind::from_str("pkg").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Pkg);
+
+        let kind = NodeJSArtifactKind::from_str("exe").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Exe);
+
+        let kind = NodeJSArtifactKind::from_str("lib").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Lib);
+
+        let kind = NodeJSArtifactKind::from_str("pdb").unwrap();
+
+        assert_eq!(kind, NodeJSArtifactKind::Pdb);
+    }
+
+    #[test]
+    fn it_serializes_to_str() {
+        let text = format!("{}", NodeJSArtifactKind::Binary);
+
+        assert_eq!(text, "binary");
+
+        let text = format!("{}", NodeJSArtifactKind::Headers);
+
+        assert_eq!(text, "headers");
+
+        let text = format!("{}", NodeJSArtifactKind::Source);
+
+        assert_eq!(text, "source");
+
+        let text = format!("{}", NodeJSArtifactKind::Pkg);
+
+        assert_eq!(text, "pkg");
+
+        let text = format!("{}", NodeJSArtifactKind::Exe);
+
+        assert_eq!(text, "exe");
+
+        let text = format!("{}", NodeJSArtifactKind::Lib);
+
+        assert_eq!(text, "lib");
+
+        let text = format!("{}", NodeJSArtifactKind::Pdb);
+
+        assert_eq!(text, "pdb");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedKind(\"NOPE!\")"
+    )]
+    fn it_fails_when_kind_is_unrecognized() {
+        NodeJSArtifactKind::from_str("NOPE!").unwrap();
+    }
+
+    #[test]
+    fn it_serializes_and_deserializes() {
+        let kind_json = serde_json::to_string(&NodeJSArtifactKind::Headers).unwrap();
+        let kind: NodeJSArtifactKind = serde_json::from_str(&kind_json).unwrap();
+        assert_eq!(kind, NodeJSArtifactKind::Headers);
+    }
+}
@@ -1,20 +1,79 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(all(feature = "gpg", target_arch = "wasm32"))]
+compile_error!("the `gpg` feature relies on `gpgv` and is not supported on wasm32 targets");
+
+// awaits the given future inside a `tracing` span when the `tracing` feature
+// is enabled - the span expression is compiled out otherwise
+macro_rules! traced {
+    ($span:expr, $fut:expr) => {{
+        #[cfg(feature = "tracing")]
+        let span = $span;
+        #[cfg(feature = "tracing")]
+        let fut = tracing::Instrument::instrument($fut, span);
+        #[cfg(not(feature = "tracing"))]
+        let fut = $fut;
+        fut.await
+    }};
+}
+
 mod arch;
+mod builder;
+mod cache;
+#[cfg(all(feature = "verify", not(target_arch = "wasm32")))]
+mod checksum;
+mod collection;
+mod config;
+mod diff;
 mod error;
 mod ext;
+#[cfg(feature = "json")]
+mod format;
+#[cfg(feature = "gpg")]
+mod gpg;
+mod http;
+mod index;
+mod kind;
 mod os;
+mod schedule;
+mod sha256;
 mod specs;
 mod url;
 
 pub use crate::arch::NodeJSArch;
-pub use crate::error::NodeJSRelInfoError;
+pub use crate::builder::NodeJSRelInfoBuilder;
+pub use crate::cache::{MemoryReleaseInfoCache, NoopReleaseInfoCache, ReleaseInfoCache};
+pub use crate::collection::NodeJSRelInfoCollection;
+pub use crate::config::NodeJSRelInfoConfig;
+pub use crate::diff::NodeJSRelInfoDiff;
+pub use crate::error::{NodeJSRelInfoError, NodeJSRelInfoErrorKind};
 pub use crate::ext::NodeJSPkgExt;
+#[cfg(feature = "json")]
+pub use crate::format::NodeJSRelInfoFormat;
+pub use crate::index::NodeJSVersionInfo;
+pub use crate::kind::NodeJSArtifactKind;
 pub use crate::os::NodeJSOS;
+pub use crate::schedule::{NodeJSReleaseSchedule, NodeJSSupportPhase};
+pub use crate::sha256::Sha256;
+pub use crate::specs::{parse_shasums, NodeJSShasumsEntry};
 use crate::url::NodeJSURLFormatter;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+#[cfg(any(feature = "gpg", not(target_arch = "wasm32")))]
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::string::ToString;
+use std::sync::Arc;
+use std::time::Duration;
+
+// upper bound on simultaneous requests issued by multi-version operations
+const MAX_CONCURRENT_REQUESTS: usize = 4;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Deserialize, Serialize))]
@@ -25,6 +84,10 @@ pub struct NodeJSRelInfo {
     pub arch: NodeJSArch,
     /// The file extension for the Node.js distributable you are targeting
     pub ext: NodeJSPkgExt,
+    /// The kind of Node.js artifact you are targeting - `binary` (default) for
+    /// the platform-specific distributable or e.g. `headers` for native addon builds
+    #[cfg_attr(feature = "json", serde(default))]
+    pub kind: NodeJSArtifactKind,
     /// The version of Node.js you are targeting as a [semver](https://semver.org) string
     pub version: String,
     /// The filename of the Node.js distributable (populated after fetching)
@@ -33,8 +96,30 @@ pub struct NodeJSRelInfo {
     pub sha256: String,
     /// The fully qualified url for the Node.js distributable (populated after fetching)
     pub url: String,
+    /// The date the version was released - `YYYY-MM-DD` (populated after fetching metadata)
+    pub date: Option<String>,
+    /// The version of npm bundled with the release (populated after fetching metadata)
+    pub npm: Option<String>,
+    /// The version of V8 bundled with the release (populated after fetching metadata)
+    pub v8: Option<String>,
+    /// The LTS codename (e.g. `Hydrogen`) when the version is part of an LTS
+    /// line (populated after fetching metadata)
+    pub lts: Option<String>,
+    /// Whether the version was a security release (populated after fetching metadata)
+    #[cfg_attr(feature = "json", serde(default))]
+    pub security: bool,
+    /// The [release schedule](https://github.com/nodejs/Release) for the
+    /// version's release line (populated after fetching schedule)
+    pub schedule: Option<NodeJSReleaseSchedule>,
     #[cfg_attr(feature = "json", serde(skip))]
     url_fmt: NodeJSURLFormatter,
+    #[cfg_attr(feature = "json", serde(skip))]
+    http: http::Settings,
+    #[cfg_attr(feature = "json", serde(skip))]
+    strict: bool,
+    #[cfg(feature = "gpg")]
+    #[cfg_attr(feature = "json", serde(skip))]
+    gpg: gpg::Settings,
 }
 
 impl NodeJSRelInfo {
@@ -57,7 +142,91 @@ impl NodeJSRelInfo {
         }
     }
 
-    /// Creates a new instance mirroring current environment based on `std::env::consts::OS` and `std::env::consts::ARCH`
+    /// Creates a new instance using the process-wide config - see:
+    /// [`NodeJSRelInfoConfig::set_global()`](crate::NodeJSRelInfoConfig::set_global)
+    ///
+    /// # Arguments
+    ///
+    /// * `semver` - The Node.js version you are targeting (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::from_global_config("20.6.1");
+    /// ```
+    pub fn from_global_config<T: AsRef<str>>(semver: T) -> Self {
+        NodeJSRelInfo::new(semver)
+            .config(&NodeJSRelInfoConfig::global())
+            .to_owned()
+    }
+
+    /// Creates a new [`NodeJSRelInfoBuilder`](crate::NodeJSRelInfoBuilder)
+    /// which validates settings before any network requests are made
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArch, NodeJSOS, NodeJSRelInfo};
+    ///
+    /// let info = NodeJSRelInfo::builder()
+    ///   .version("20.6.1")
+    ///   .os(NodeJSOS::Darwin)
+    ///   .arch(NodeJSArch::ARM64)
+    ///   .build()
+    ///   .unwrap();
+    /// ```
+    pub fn builder() -> NodeJSRelInfoBuilder {
+        NodeJSRelInfoBuilder::new()
+    }
+
+    /// Creates a new instance from a Node.js distributable filename (e.g.
+    /// `node-v20.6.1-darwin-arm64.tar.gz`) - leading directories are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - The distributable filename or path (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArch, NodeJSOS, NodeJSPkgExt, NodeJSRelInfo};
+    /// let info = NodeJSRelInfo::from_filename("node-v20.6.1-darwin-arm64.tar.gz").unwrap();
+    /// assert_eq!(info.version, "20.6.1");
+    /// assert_eq!(info.os, NodeJSOS::Darwin);
+    /// assert_eq!(info.arch, NodeJSArch::ARM64);
+    /// assert_eq!(info.ext, NodeJSPkgExt::Targz);
+    /// ```
+    pub fn from_filename<T: AsRef<str>>(filename: T) -> Result<Self, NodeJSRelInfoError> {
+        let filename = filename.as_ref();
+        let name = Path::new(filename)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(filename);
+
+        let (version, kind, os, arch, ext) = match specs::parse_filename(name) {
+            None => {
+                return Err(NodeJSRelInfoError::UnrecognizedConfiguration(
+                    filename.to_owned(),
+                ))
+            }
+            Some(parsed) => parsed,
+        };
+
+        Ok(NodeJSRelInfo {
+            os,
+            arch,
+            ext,
+            kind,
+            version,
+            filename: name.to_owned(),
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new instance mirroring current environment based on `std::env::consts::OS` and `std::env::consts::ARCH` -
+    /// fails with [`UnsupportedLibc`](crate::NodeJSRelInfoError::UnsupportedLibc)
+    /// on musl-based Linux hosts (e.g. Alpine) as official builds require glibc
     ///
     /// # Arguments
     ///
@@ -223,6 +392,45 @@ impl NodeJSRelInfo {
         self
     }
 
+    /// Sets instance `arch` field to `armv6l`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").armv6l();
+    /// ```
+    pub fn armv6l(&mut self) -> &mut Self {
+        self.arch = NodeJSArch::ARMV6L;
+        self
+    }
+
+    /// Sets instance `arch` field to `riscv64`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").riscv64();
+    /// ```
+    pub fn riscv64(&mut self) -> &mut Self {
+        self.arch = NodeJSArch::RISCV64;
+        self
+    }
+
+    /// Sets instance `arch` field to `loong64`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").loong64();
+    /// ```
+    pub fn loong64(&mut self) -> &mut Self {
+        self.arch = NodeJSArch::LOONG64;
+        self
+    }
+
     /// Sets instance `ext` field to `tar.gz`
     ///
     /// # Examples
@@ -275,7 +483,8 @@ impl NodeJSRelInfo {
         self
     }
 
-    /// Sets instance `ext` field to `msi`
+    /// Sets instance `ext` field to `msi` and `os` field to `win` - installers
+    /// are only published for Windows
     ///
     /// # Examples
     ///
@@ -285,359 +494,3518 @@ impl NodeJSRelInfo {
     /// ```
     pub fn msi(&mut self) -> &mut Self {
         self.ext = NodeJSPkgExt::Msi;
+        self.os = NodeJSOS::Windows;
         self
     }
 
-    /// Creates owned data from reference for convenience when chaining
+    /// Sets instance `kind` field to `binary`
     ///
     /// # Examples
     ///
     /// ```rust
     /// use node_js_release_info::NodeJSRelInfo;
-    /// let info = NodeJSRelInfo::new("20.6.1").windows().x64().zip().to_owned();
+    /// let info = NodeJSRelInfo::new("20.6.1").binary();
     /// ```
-    pub fn to_owned(&self) -> Self {
-        self.clone()
+    pub fn binary(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Binary;
+        self
     }
 
-    /// Fetches Node.js metadata for specified configuration from the
-    /// [releases download server](https://nodejs.org/download/release/)
+    /// Sets instance `kind` field to `headers` - targets the C/C++ headers
+    /// tarball (e.g. `node-v20.6.1-headers.tar.gz`) used by `node-gyp`
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
-    ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), NodeJSRelInfoError> {
-    ///   let info = NodeJSRelInfo::new("20.6.1").macos().arm64().fetch().await?;
-    ///   assert_eq!(info.version, "20.6.1");
-    ///   assert_eq!(info.filename, "node-v20.6.1-darwin-arm64.tar.gz");
-    ///   assert_eq!(info.sha256, "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46");
-    ///   assert_eq!(info.url, "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz");
-    ///   Ok(())
-    /// }
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").headers().to_owned();
+    /// assert_eq!(info.filename(), "node-v20.6.1-headers.tar.gz");
     /// ```
-    pub async fn fetch(&mut self) -> Result<Self, NodeJSRelInfoError> {
-        let version = specs::validate_version(self.version.as_str())?;
-        let specs = specs::fetch(&version, &self.url_fmt).await?;
-        let filename = self.filename();
-        let info = specs.lines().find(|&line| line.contains(filename.as_str()));
+    pub fn headers(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Headers;
+        self
+    }
 
-        let mut specs = match info {
-            None => return Err(NodeJSRelInfoError::UnrecognizedConfiguration(filename))?,
-            Some(s) => s.split_whitespace(),
-        };
+    /// Sets instance `kind` field to `source` - targets the source tarball
+    /// (e.g. `node-v20.6.1.tar.gz`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").source().tar_xz().to_owned();
+    /// assert_eq!(info.filename(), "node-v20.6.1.tar.xz");
+    /// ```
+    pub fn source(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Source;
+        self
+    }
 
-        self.filename = filename;
-        self.sha256 = specs.nth(0).unwrap().to_string();
-        self.url = self.url_fmt.pkg(&self.version, &self.filename);
-        Ok(self.to_owned())
+    /// Sets instance `kind` field to `pkg` and `os` field to `darwin` - targets
+    /// the macOS installer (e.g. `node-v20.6.1.pkg`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").pkg().to_owned();
+    /// assert_eq!(info.filename(), "node-v20.6.1.pkg");
+    /// ```
+    pub fn pkg(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Pkg;
+        self.os = NodeJSOS::Darwin;
+        self
     }
 
-    /// Fetches Node.js metadata for all supported configurations from the
-    /// [releases download server](https://nodejs.org/download/release/)
+    /// Sets instance `kind` field to `exe` and `os` field to `win` - targets
+    /// the standalone Windows executable (e.g. `win-x64/node.exe`)
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").exe().arm64().to_owned();
+    /// assert_eq!(info.filename(), "win-arm64/node.exe");
+    /// ```
+    pub fn exe(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Exe;
+        self.os = NodeJSOS::Windows;
+        self
+    }
+
+    /// Sets instance `kind` field to `lib` and `os` field to `win` - targets
+    /// the Windows import library used when embedding (e.g. `win-x64/node.lib`)
     ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), NodeJSRelInfoError> {
-    ///   let info = NodeJSRelInfo::new("20.6.1");
-    ///   let all = info.fetch_all().await?;
-    ///   assert_eq!(all.len(), 24);
-    ///   assert_eq!(all[2].version, "20.6.1");
-    ///   assert_eq!(all[2].filename, "node-v20.6.1-darwin-arm64.tar.gz");
-    ///   assert_eq!(all[2].sha256, "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46");
-    ///   assert_eq!(all[2].url, "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz");
-    ///   Ok(())
-    /// }
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").lib().to_owned();
+    /// assert_eq!(info.filename(), "win-x64/node.lib");
     /// ```
-    pub async fn fetch_all(&self) -> Result<Vec<NodeJSRelInfo>, NodeJSRelInfoError> {
-        let version = specs::validate_version(self.version.as_str())?;
-        let specs = specs::fetch(&version, &self.url_fmt).await?;
-        let specs = match specs::parse(&version, specs) {
-            Some(s) => s,
-            None => {
-                return Err(NodeJSRelInfoError::UnrecognizedVersion(version.clone()));
-            }
-        };
+    pub fn lib(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Lib;
+        self.os = NodeJSOS::Windows;
+        self
+    }
 
-        let mut all: Vec<NodeJSRelInfo> = vec![];
-        for (os, arch, ext, sha256, filename) in specs.into_iter() {
-            let version = version.clone();
-            let mut info = NodeJSRelInfo {
-                os,
-                arch,
-                version,
-                ext,
-                filename,
-                sha256,
-                ..Default::default()
-            };
-
-            info.url = info.url_fmt.pkg(&info.version, &info.filename);
-            all.push(info);
-        }
+    /// Sets instance `kind` field to `pdb` and `os` field to `win` - targets
+    /// the Windows debug symbols archive (e.g. `win-x64/node_pdb.zip`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").pdb().s7z().to_owned();
+    /// assert_eq!(info.filename(), "win-x64/node_pdb.7z");
+    /// ```
+    pub fn pdb(&mut self) -> &mut Self {
+        self.kind = NodeJSArtifactKind::Pdb;
+        self.os = NodeJSOS::Windows;
+        self
+    }
 
-        Ok(all)
+    /// Sets the proxy used when issuing HTTP requests - by default, the
+    /// `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables are
+    /// respected
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The fully qualified url of the proxy (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").proxy("http://proxy.internal:3128");
+    /// ```
+    pub fn proxy<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
+        self.http.proxy = Some(url.as_ref().to_owned());
+        self
     }
 
-    fn filename(&self) -> String {
-        let arch = self.arch.to_string();
-        let ext = self.ext.to_string();
+    /// Sets the `User-Agent` header sent when issuing HTTP requests - defaults
+    /// to `node-js-release-info/{version}`
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The `User-Agent` header value (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").user_agent("my-tool/1.0.0");
+    /// ```
+    pub fn user_agent<T: AsRef<str>>(&mut self, user_agent: T) -> &mut Self {
+        self.http.user_agent = Some(user_agent.as_ref().to_owned());
+        self
+    }
 
-        if self.ext == NodeJSPkgExt::Msi {
-            return format!("node-v{}-{}.{}", self.version, arch, ext);
-        }
+    /// Caps the number of HTTP requests issued per second so bulk operations
+    /// (e.g. [`fetch_many()`](NodeJSRelInfo::fetch_many)) don't overwhelm the
+    /// downloads server - the cap is shared by all requests in the process
+    /// using it (ignored on wasm32)
+    ///
+    /// # Arguments
+    ///
+    /// * `requests_per_second` - The max number of requests per second (`0` disables the cap)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").rate_limit(5);
+    /// ```
+    pub fn rate_limit(&mut self, requests_per_second: u32) -> &mut Self {
+        self.http.rate_limit = match requests_per_second {
+            0 => None,
+            n => Some(n),
+        };
+        self
+    }
 
-        format!("node-v{}-{}-{}.{}", self.version, self.os, arch, ext)
+    /// Sets the cache consulted before requesting release info (e.g.
+    /// `SHASUMS256.txt`, `index.json`) - responses are stored in it after
+    /// each successful request along with their `ETag` / `Last-Modified`
+    /// headers which are used to revalidate them via conditional requests -
+    /// responses without those headers are only reused for version-scoped
+    /// urls (e.g. `/v20.6.1/SHASUMS256.txt`) which never change (defaults to
+    /// [`NoopReleaseInfoCache`](crate::NoopReleaseInfoCache) which stores nothing)
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The cache to use - see: [`ReleaseInfoCache`](crate::ReleaseInfoCache)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{MemoryReleaseInfoCache, NodeJSRelInfo};
+    /// use std::sync::Arc;
+    /// let cache = Arc::new(MemoryReleaseInfoCache::new());
+    /// let info = NodeJSRelInfo::new("20.6.1").cache(cache.clone());
+    /// ```
+    pub fn cache(&mut self, cache: Arc<dyn ReleaseInfoCache>) -> &mut Self {
+        self.http.cache = cache::CacheHandle(cache);
+        self
     }
-}
 
-// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    /// Applies network settings (timeout, retries, mirror, proxy, and
+    /// `User-Agent`) in one go - settings left unset in the config (`None` /
+    /// `0`) keep their current values. To start from the process-wide config
+    /// see: [`NodeJSRelInfo::from_global_config()`](crate::NodeJSRelInfo::from_global_config)
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The settings to apply - see: [`NodeJSRelInfoConfig`](crate::NodeJSRelInfoConfig)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoConfig};
+    /// use std::time::Duration;
+    /// let config = NodeJSRelInfoConfig {
+    ///   timeout: Some(Duration::from_secs(30)),
+    ///   retries: 2,
+    ///   mirror: Some("https://npmmirror.com/mirrors/node".to_string()),
+    ///   ..NodeJSRelInfoConfig::new()
+    /// };
+    /// let info = NodeJSRelInfo::new("20.6.1").config(&config).to_owned();
+    /// assert_eq!(info.url(), "https://npmmirror.com/mirrors/node/v20.6.1/node-v20.6.1-linux-x64.tar.gz");
+    /// ```
+    pub fn config(&mut self, config: &NodeJSRelInfoConfig) -> &mut Self {
+        if let Some(timeout) = config.timeout {
+            self.http.timeout = Some(timeout);
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use mockito::Server;
+        if config.retries > 0 {
+            self.http.retries = config.retries;
+        }
 
-    fn is_thread_safe<T: Sized + Send + Sync + Unpin>() {}
+        if let Some(proxy) = &config.proxy {
+            self.http.proxy = Some(proxy.clone());
+        }
 
-    #[test]
-    fn it_initializes() {
-        let info = NodeJSRelInfo::new("1.0.0");
-        assert_eq!(info.os, NodeJSOS::Linux);
-        assert_eq!(info.arch, NodeJSArch::X64);
-        assert_eq!(info.ext, NodeJSPkgExt::Targz);
-        assert_eq!(info.version, "1.0.0".to_string());
-        assert_eq!(info.filename, "".to_string());
-        assert_eq!(info.sha256, "".to_string());
-        assert_eq!(info.url, "".to_string());
-        is_thread_safe::<NodeJSRelInfo>();
-    }
+        if let Some(user_agent) = &config.user_agent {
+            self.http.user_agent = Some(user_agent.clone());
+        }
 
-    #[test]
-    fn it_initializes_with_defaults() {
-        let info = NodeJSRelInfo::default();
-        assert_eq!(info.os, NodeJSOS::Linux);
-        assert_eq!(info.arch, NodeJSArch::X64);
-        assert_eq!(info.ext, NodeJSPkgExt::Targz);
-        assert_eq!(info.version, "".to_string());
-        assert_eq!(info.filename, "".to_string());
-        assert_eq!(info.sha256, "".to_string());
-        assert_eq!(info.url, "".to_string());
+        if let Some(mirror) = &config.mirror {
+            self.url_fmt = self.url_fmt.with_base(mirror);
+        }
+
+        self
     }
 
-    #[test]
-    #[cfg_attr(not(target_os = "macos"), ignore)]
+    /// Sets the max time to wait for each HTTP request to complete - unlimited
+    /// by default (ignored on wasm32) - when downloading it bounds the wait for
+    /// the response and each chunk of the body rather than the whole transfer
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The max time to wait
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// use std::time::Duration;
+    /// let info = NodeJSRelInfo::new("20.6.1").timeout(Duration::from_secs(30));
+    /// ```
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.http.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of times to retry requests which fail with a
+    /// [retryable](crate::NodeJSRelInfoError::is_retryable) error (`0` by default)
+    ///
+    /// # Arguments
+    ///
+    /// * `retries` - The max number of retries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").retries(3);
+    /// ```
+    pub fn retries(&mut self, retries: u32) -> &mut Self {
+        self.http.retries = retries;
+        self
+    }
+
+    /// Sets the HTTP client used when issuing requests - by default, a client
+    /// shared by all instances with the same [`proxy`](NodeJSRelInfo::proxy)
+    /// and [`user_agent`](NodeJSRelInfo::user_agent) is created on first use
+    /// so connections are reused. When set, `proxy` and `user_agent` are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A configured [`reqwest::Client`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let client = reqwest::Client::builder().build().unwrap();
+    /// let info = NodeJSRelInfo::new("20.6.1").http_client(client);
+    /// ```
+    pub fn http_client(&mut self, client: reqwest::Client) -> &mut Self {
+        self.http.client = Some(http::ClientHandle(Arc::new(client)));
+        self
+    }
+
+    /// Sets the protocol used when requesting the Node.js [downloads server](https://nodejs.org/download/release/)
+    /// (defaults to `https:`) - useful for pointing at a local mock server in tests
+    ///
+    /// # Arguments
+    ///
+    /// * `protocol` - The url protocol including the trailing colon (e.g. `http:`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").protocol("http:").to_owned();
+    /// assert_eq!(info.url(), "http://nodejs.org/download/release/v20.6.1/node-v20.6.1-linux-x64.tar.gz");
+    /// ```
+    pub fn protocol<T: AsRef<str>>(&mut self, protocol: T) -> &mut Self {
+        self.url_fmt.protocol = protocol.as_ref().to_owned();
+        self
+    }
+
+    /// Sets the host (and optional port) of the Node.js [downloads server](https://nodejs.org/download/release/)
+    /// (defaults to `nodejs.org`) - useful for mirrors or a local mock server in tests
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The url host (e.g. `127.0.0.1:1234`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").host("127.0.0.1:1234").to_owned();
+    /// assert_eq!(info.url(), "https://127.0.0.1:1234/download/release/v20.6.1/node-v20.6.1-linux-x64.tar.gz");
+    /// ```
+    pub fn host<T: AsRef<str>>(&mut self, host: T) -> &mut Self {
+        self.url_fmt.host = host.as_ref().to_owned();
+        self
+    }
+
+    /// Sets the pathname releases are served under (defaults to `/download/release`)
+    ///
+    /// # Arguments
+    ///
+    /// * `pathname` - The url pathname without a trailing slash (e.g. `/dist`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").pathname("/dist").to_owned();
+    /// assert_eq!(info.url(), "https://nodejs.org/dist/v20.6.1/node-v20.6.1-linux-x64.tar.gz");
+    /// ```
+    pub fn pathname<T: AsRef<str>>(&mut self, pathname: T) -> &mut Self {
+        self.url_fmt.pathname = pathname.as_ref().to_owned();
+        self
+    }
+
+    /// Sets the fully qualified url of the Node.js [release schedule](https://github.com/nodejs/Release)
+    /// (`schedule.json`)
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The schedule url (e.g. `http://127.0.0.1:1234/schedule.json`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").schedule_url("http://127.0.0.1:1234/schedule.json");
+    /// ```
+    pub fn schedule_url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
+        self.url_fmt.schedule = url.as_ref().to_owned();
+        self
+    }
+
+    /// Requests release info from the `latest-v{major}.x` alias directory
+    /// (e.g. `/download/release/latest-v20.x/`) so the newest release of a
+    /// major line can be targeted without resolving an exact version first -
+    /// `version` is populated from the alias when fetching and the fetched
+    /// `url` points into the alias directory (the computed `filename()` and
+    /// `url()` are only meaningful once `fetch()` has resolved the version)
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The major version of the release line (e.g. `20`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::default().linux().x64().use_latest_alias(20).fetch().await?;
+    ///   assert!(info.version.starts_with("20."));
+    ///   assert!(info.url.contains("/latest-v20.x/"));
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn use_latest_alias(&mut self, major: u64) -> &mut Self {
+        self.url_fmt.latest = Some(major);
+        self
+    }
+
+    /// Sets an ordered list of mirrors to fall back to when the primary
+    /// [downloads server](https://nodejs.org/download/release/) times out or
+    /// responds with a 5xx status - each is tried in turn before the error is
+    /// surfaced and fetched `url`s point at whichever host responded
+    ///
+    /// # Arguments
+    ///
+    /// * `mirrors` - Base urls releases are served under (e.g. `https://npmmirror.com/mirrors/node`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").mirrors(["https://npmmirror.com/mirrors/node"]);
+    /// ```
+    pub fn mirrors<I, T>(&mut self, mirrors: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        self.url_fmt.mirrors = mirrors.into_iter().map(|m| m.as_ref().to_owned()).collect();
+        self
+    }
+
+    /// Enables strict parsing of the Node.js release info (`SHASUMS256.txt`) -
+    /// by default, artifacts the parser doesn't recognize are skipped. In
+    /// strict mode, [`fetch_all()`](crate::NodeJSRelInfo::fetch_all) and friends
+    /// fail with [`UnrecognizedArtifacts`](crate::NodeJSRelInfoError::UnrecognizedArtifacts)
+    /// listing them so e.g. mirror operators notice newly introduced artifacts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").strict();
+    /// ```
+    pub fn strict(&mut self) -> &mut Self {
+        self.strict = true;
+        self
+    }
+
+    /// Enables verification of the Node.js release info signature
+    /// (`SHASUMS256.txt.sig`) using `gpgv` and the Node.js
+    /// [release keys](https://github.com/nodejs/release-keys) bundled with
+    /// this crate. Fetching fails with
+    /// [`InvalidSignature`](crate::NodeJSRelInfoError::InvalidSignature)
+    /// when the signature cannot be verified,
+    /// [`HttpStatus`](crate::NodeJSRelInfoError::HttpStatus) when it cannot be
+    /// downloaded, and [`MissingGpgv`](crate::NodeJSRelInfoError::MissingGpgv)
+    /// when `gpgv` is not installed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").verify_signature();
+    /// ```
+    #[cfg(feature = "gpg")]
+    pub fn verify_signature(&mut self) -> &mut Self {
+        self.gpg.enabled = true;
+        self
+    }
+
+    /// Enables verification of the Node.js release info signature
+    /// (`SHASUMS256.txt.sig`) using `gpgv` and the provided keyring instead of
+    /// the bundled Node.js release keys
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a keyring containing the Node.js release keys
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").gpg_keyring("./release-keys/gpg/pubring.kbx");
+    /// ```
+    #[cfg(feature = "gpg")]
+    pub fn gpg_keyring<P: Into<PathBuf>>(&mut self, path: P) -> &mut Self {
+        self.gpg.enabled = true;
+        self.gpg.keyring = Some(path.into());
+        self
+    }
+
+    /// Checks that the configured `kind`, `os`, `arch`, and `ext` describe an
+    /// artifact the Node.js project publishes - no network requests are made.
+    /// Called by [`fetch()`](crate::NodeJSRelInfo::fetch) so mistakes like
+    /// `linux().zip()` fail early with a targeted error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    /// assert!(NodeJSRelInfo::new("20.6.1").windows().zip().validate().is_ok());
+    /// let err = NodeJSRelInfo::new("20.6.1").linux().zip().validate().unwrap_err();
+    /// assert!(matches!(err, NodeJSRelInfoError::IncompatibleExt(_)));
+    /// let err = NodeJSRelInfo::new("20.6.1").aix().x64().validate().unwrap_err();
+    /// assert!(matches!(err, NodeJSRelInfoError::IncompatibleArch(_)));
+    /// ```
+    pub fn validate(&self) -> Result<(), NodeJSRelInfoError> {
+        specs::validate_configuration(&self.kind, &self.os, &self.arch, &self.ext)
+    }
+
+    /// Creates owned data from reference for convenience when chaining
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").windows().x64().zip().to_owned();
+    /// ```
+    pub fn to_owned(&self) -> Self {
+        self.clone()
+    }
+
+    /// Serializes to a human-readable document in the given format - `json`
+    /// is available via the `json` feature, `yaml` and `toml` via features of
+    /// the same name
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The output format - see: [`NodeJSRelInfoFormat`](crate::NodeJSRelInfoFormat)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoFormat};
+    /// let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+    /// let json = info.to_string_pretty(NodeJSRelInfoFormat::Json).unwrap();
+    /// assert!(json.contains("\"arch\": \"arm64\""));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn to_string_pretty(
+        &self,
+        format: NodeJSRelInfoFormat,
+    ) -> Result<String, NodeJSRelInfoError> {
+        format::to_string_pretty(self, format)
+    }
+
+    /// Computes the filename of the Node.js distributable from the configured
+    /// `version`, `os`, `arch`, and `ext` (no network requests are made) -
+    /// when using `use_latest_alias()` the version isn't known until fetching
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+    /// assert_eq!(info.filename(), "node-v20.6.1-darwin-arm64.tar.gz");
+    /// ```
+    pub fn filename(&self) -> String {
+        let arch = self.arch.to_string();
+        let ext = self.ext.to_string();
+
+        match self.kind {
+            NodeJSArtifactKind::Headers => {
+                return format!("node-v{}-headers.{}", self.version, ext)
+            }
+            NodeJSArtifactKind::Source => return format!("node-v{}.{}", self.version, ext),
+            NodeJSArtifactKind::Pkg => return format!("node-v{}.pkg", self.version),
+            NodeJSArtifactKind::Exe => return format!("win-{}/node.exe", arch),
+            NodeJSArtifactKind::Lib => return format!("win-{}/node.lib", arch),
+            NodeJSArtifactKind::Pdb => return format!("win-{}/node_pdb.{}", arch, ext),
+            NodeJSArtifactKind::Binary => {}
+        }
+
+        if self.ext == NodeJSPkgExt::Msi {
+            return format!("node-v{}-{}.{}", self.version, arch, ext);
+        }
+
+        format!("node-v{}-{}-{}.{}", self.version, self.os, arch, ext)
+    }
+
+    /// Computes the fully qualified url of the Node.js distributable from the
+    /// configured `version`, `os`, `arch`, and `ext` (no network requests are
+    /// made) - when using `use_latest_alias()` the version isn't known until
+    /// fetching so use the `url` of the fetched info instead
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").windows().msi().to_owned();
+    /// assert_eq!(info.url(), "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-x64.msi");
+    /// ```
+    pub fn url(&self) -> String {
+        self.url_fmt.pkg(&self.version, self.filename())
+    }
+
+    /// Computes the top-level directory created when extracting the Node.js
+    /// distributable (e.g. `node-v20.6.1-linux-x64`) - returns `None` for
+    /// artifacts which aren't binary archives (installers, headers, etc)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").linux().x64().tar_xz().to_owned();
+    /// assert_eq!(info.archive_root(), Some("node-v20.6.1-linux-x64".to_string()));
+    /// let info = NodeJSRelInfo::new("20.6.1").windows().msi().to_owned();
+    /// assert_eq!(info.archive_root(), None);
+    /// ```
+    pub fn archive_root(&self) -> Option<String> {
+        if self.kind != NodeJSArtifactKind::Binary || self.ext == NodeJSPkgExt::Msi {
+            return None;
+        }
+
+        let filename = self.filename();
+        let root = filename.strip_suffix(format!(".{}", self.ext).as_str())?;
+        Some(root.to_string())
+    }
+
+    /// Computes the path of the `node` executable inside the extracted Node.js
+    /// distributable (e.g. `node-v20.6.1-linux-x64/bin/node` or
+    /// `node-v20.6.1-win-x64/node.exe`) - returns `None` for artifacts which
+    /// aren't binary archives (installers, headers, etc)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("20.6.1").linux().x64().to_owned();
+    /// assert_eq!(info.bin_path_in_archive(), Some("node-v20.6.1-linux-x64/bin/node".to_string()));
+    /// let info = NodeJSRelInfo::new("20.6.1").windows().zip().to_owned();
+    /// assert_eq!(info.bin_path_in_archive(), Some("node-v20.6.1-win-x64/node.exe".to_string()));
+    /// ```
+    pub fn bin_path_in_archive(&self) -> Option<String> {
+        let root = self.archive_root()?;
+
+        match self.os {
+            NodeJSOS::Windows => Some(format!("{}/node.exe", root)),
+            _ => Some(format!("{}/bin/node", root)),
+        }
+    }
+
+    /// Formats the `sha256` checksum as a [Subresource Integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity)
+    /// string (`sha256-<base64>`) as used by npm's `package-lock.json` -
+    /// returns `None` when `sha256` is unset or is not a valid hex digest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let mut info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+    /// assert_eq!(info.integrity(), None);
+    /// info.sha256 = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string();
+    /// assert_eq!(info.integrity().unwrap(), "sha256-2LqAGNRbKUQpsadkbMvq6yrzzfRbXJHau9k+KiA1y0Y=");
+    /// ```
+    pub fn integrity(&self) -> Option<String> {
+        let digest = self.checksum().ok()?;
+        Some(format!("sha256-{}", BASE64.encode(digest.as_bytes())))
+    }
+
+    /// Parses `sha256` into a typed [`Sha256`](crate::Sha256) digest - fails
+    /// when `sha256` is unset (i.e. before fetching) or is not a valid hex digest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, Sha256};
+    /// let mut info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+    /// assert!(info.checksum().is_err());
+    /// info.sha256 = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string();
+    /// assert_eq!(info.checksum().unwrap().to_hex(), info.sha256);
+    /// ```
+    pub fn checksum(&self) -> Result<Sha256, NodeJSRelInfoError> {
+        Sha256::from_hex(&self.sha256)
+    }
+
+    /// Fetches Node.js metadata for specified configuration from the
+    /// [releases download server](https://nodejs.org/download/release/)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1").macos().arm64().fetch().await?;
+    ///   assert_eq!(info.version, "20.6.1");
+    ///   assert_eq!(info.filename, "node-v20.6.1-darwin-arm64.tar.gz");
+    ///   assert_eq!(info.sha256, "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46");
+    ///   assert_eq!(info.url, "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz");
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch(&mut self) -> Result<Self, NodeJSRelInfoError> {
+        traced!(
+            tracing::info_span!("fetch", version = %self.version, filename = %self.filename()),
+            async {
+                self.validate()?;
+                let (version, specs, url_fmt) = self.fetch_shasums().await?;
+                self.version = version;
+                self.select_spec(&specs, &url_fmt)
+            }
+        )
+    }
+
+    /// Populates Node.js metadata for specified configuration from a provided
+    /// `SHASUMS256.txt` document (e.g. one vendored for air-gapped environments)
+    /// instead of fetching it - no network requests are made and the document's
+    /// signature is not verified
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The contents of the release's `SHASUMS256.txt` file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let shasums = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46  node-v20.6.1-darwin-arm64.tar.gz";
+    /// let info = NodeJSRelInfo::new("20.6.1").macos().arm64().from_shasums(shasums).unwrap();
+    /// assert_eq!(info.filename, "node-v20.6.1-darwin-arm64.tar.gz");
+    /// assert_eq!(info.sha256, "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46");
+    /// assert_eq!(info.url, "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz");
+    /// ```
+    pub fn from_shasums(&mut self, text: &str) -> Result<Self, NodeJSRelInfoError> {
+        specs::validate_version(self.version.as_str())?;
+        let url_fmt = self.url_fmt.clone();
+        self.select_spec(text, &url_fmt)
+    }
+
+    /// Fetches Node.js metadata for all supported configurations from the
+    /// [releases download server](https://nodejs.org/download/release/) sorted
+    /// by filename - see: [`NodeJSRelInfoCollection`](crate::NodeJSRelInfoCollection)
+    /// for filtering and sorting helpers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSOS, NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1");
+    ///   let all = info.fetch_all().await?;
+    ///   assert_eq!(all.len(), 41);
+    ///   assert_eq!(all[2].version, "20.6.1");
+    ///   assert_eq!(all[2].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+    ///   assert_eq!(all[2].sha256, "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46");
+    ///   assert_eq!(all[2].url, "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz");
+    ///   let macos = all.for_os(NodeJSOS::Darwin).archives_only();
+    ///   assert_eq!(macos.len(), 4);
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_all(&self) -> Result<NodeJSRelInfoCollection, NodeJSRelInfoError> {
+        self.fetch_all_where(|_, _, _, _| true).await
+    }
+
+    /// Fetches Node.js metadata for supported configurations matching the
+    /// given predicate - configurations are skipped while parsing so only
+    /// matching items are created
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Called with each configuration's `kind`, `os`, `arch`,
+    ///   and `ext` - return `true` to keep it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSArtifactKind, NodeJSOS, NodeJSPkgExt, NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1");
+    ///   let all = info
+    ///     .fetch_all_where(|kind, os, _arch, ext| {
+    ///       *kind == NodeJSArtifactKind::Binary && *os == NodeJSOS::Linux && *ext == NodeJSPkgExt::Tarxz
+    ///     })
+    ///     .await?;
+    ///   assert_eq!(all.len(), 5);
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_all_where<F>(
+        &self,
+        predicate: F,
+    ) -> Result<NodeJSRelInfoCollection, NodeJSRelInfoError>
+    where
+        F: Fn(&NodeJSArtifactKind, &NodeJSOS, &NodeJSArch, &NodeJSPkgExt) -> bool,
+    {
+        traced!(
+            tracing::info_span!("fetch_all", version = %self.version),
+            async {
+                let (version, specs, url_fmt) = self.fetch_specs().await?;
+                let all: NodeJSRelInfoCollection = specs
+                    .into_iter()
+                    .filter(|(kind, os, arch, ext, _, _)| predicate(kind, os, arch, ext))
+                    .map(|spec| self.spec_info(&version, spec, &url_fmt))
+                    .collect();
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(count = all.len(), "collected release info");
+                Ok(all)
+            }
+        )
+    }
+
+    /// Creates Node.js metadata for all supported configurations from a
+    /// provided `SHASUMS256.txt` document (e.g. one vendored for air-gapped
+    /// environments) - no network requests are made and the document's
+    /// signature is not verified
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Node.js version the document belongs to
+    /// * `text` - The contents of the release's `SHASUMS256.txt` file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let shasums = [
+    ///   "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46  node-v20.6.1-darwin-arm64.tar.gz",
+    ///   "591f9f274104f266a8cf085d2c7d5d2848ba73b98ae323d501db2d4c4b7026e5  node-v20.6.1-linux-x64.tar.xz",
+    /// ].join("\n");
+    /// let all = NodeJSRelInfo::fetch_all_from_shasums("20.6.1", &shasums).unwrap();
+    /// assert_eq!(all.len(), 2);
+    /// assert_eq!(all[1].filename, "node-v20.6.1-linux-x64.tar.xz");
+    /// ```
+    pub fn fetch_all_from_shasums<T: AsRef<str>>(
+        version: T,
+        text: &str,
+    ) -> Result<NodeJSRelInfoCollection, NodeJSRelInfoError> {
+        let info = NodeJSRelInfo::new(version);
+        let version = specs::validate_version(info.version.as_str())?;
+        let specs = match specs::parse(&version, text.to_string()) {
+            Some(s) => s,
+            None => return Err(NodeJSRelInfoError::UnrecognizedVersion(version)),
+        };
+
+        Ok(specs
+            .into_iter()
+            .map(|spec| info.spec_info(&version, spec, &info.url_fmt))
+            .collect())
+    }
+
+    /// Fetches Node.js metadata for all supported configurations from the
+    /// [releases download server](https://nodejs.org/download/release/) as a
+    /// [`Stream`](futures_util::Stream) so entries can be processed as they
+    /// become available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_util::TryStreamExt;
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1");
+    ///   let mut all = Box::pin(info.fetch_all_stream());
+    ///   while let Some(info) = all.try_next().await? {
+    ///     println!("{}", info.url);
+    ///   }
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn fetch_all_stream(
+        &self,
+    ) -> impl Stream<Item = Result<NodeJSRelInfo, NodeJSRelInfoError>> + '_ {
+        stream::once(self.fetch_specs())
+            .map_ok(move |(version, specs, url_fmt)| {
+                stream::iter(specs).map(move |spec| Ok(self.spec_info(&version, spec, &url_fmt)))
+            })
+            .try_flatten()
+    }
+
+    /// Fetches Node.js metadata for all supported configurations of several
+    /// versions as a [`Stream`](futures_util::Stream) - each version's
+    /// `SHASUMS256.txt` is fetched concurrently while items are yielded in
+    /// version order (duplicate versions are only fetched once)
+    ///
+    /// # Arguments
+    ///
+    /// * `versions` - The Node.js versions you are targeting (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_util::TryStreamExt;
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::default();
+    ///   let matrix: Vec<NodeJSRelInfo> = info
+    ///     .fetch_matrix(&["20.6.1", "18.18.0"])
+    ///     .try_collect()
+    ///     .await?;
+    ///   assert_eq!(matrix[0].version, "20.6.1");
+    ///   assert_eq!(matrix[matrix.len() - 1].version, "18.18.0");
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn fetch_matrix<T: AsRef<str>>(
+        &self,
+        versions: &[T],
+    ) -> impl Stream<Item = Result<NodeJSRelInfo, NodeJSRelInfoError>> + '_ {
+        let mut unique: Vec<String> = vec![];
+        for version in versions {
+            let version = version.as_ref().to_owned();
+            if !unique.contains(&version) {
+                unique.push(version);
+            }
+        }
+
+        stream::iter(unique)
+            .map(move |version| {
+                let mut info = self.to_owned();
+                info.version = version;
+                async move { info.fetch_all().await }
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .map_ok(|all| stream::iter(all.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Fetches Node.js metadata for the specified configuration across several
+    /// versions concurrently (duplicate versions are only fetched once)
+    ///
+    /// # Arguments
+    ///
+    /// * `versions` - The Node.js versions you are targeting (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::default().macos().arm64().to_owned();
+    ///   let many = info.fetch_many(&["20.6.1", "18.18.0"]).await?;
+    ///   assert_eq!(many.len(), 2);
+    ///   assert_eq!(many["20.6.1"].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+    ///   assert_eq!(many["18.18.0"].filename, "node-v18.18.0-darwin-arm64.tar.gz");
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_many<T: AsRef<str>>(
+        &self,
+        versions: &[T],
+    ) -> Result<HashMap<String, NodeJSRelInfo>, NodeJSRelInfoError> {
+        let mut unique: Vec<String> = vec![];
+        for version in versions {
+            let version = specs::validate_version(version)?;
+            if !unique.contains(&version) {
+                unique.push(version);
+            }
+        }
+
+        let all: Vec<NodeJSRelInfo> = stream::iter(unique)
+            .map(|version| {
+                let mut info = self.to_owned();
+                info.version = version;
+                async move { info.fetch().await }
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        Ok(all
+            .into_iter()
+            .map(|info| (info.version.clone(), info))
+            .collect())
+    }
+
+    /// Fetches Node.js metadata for all supported configurations of two
+    /// versions concurrently and reports which configurations were added,
+    /// removed, or had their checksum change - see: [`NodeJSRelInfoDiff`](crate::NodeJSRelInfoDiff)
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The older Node.js version (`String` / `&str`)
+    /// * `to` - The newer Node.js version (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let diff = NodeJSRelInfo::default().diff_versions("20.6.1", "20.7.0").await?;
+    ///   assert!(diff.added.is_empty());
+    ///   assert!(diff.removed.is_empty());
+    ///   assert_eq!(diff.changed.len(), 41);
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn diff_versions<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        from: A,
+        to: B,
+    ) -> Result<NodeJSRelInfoDiff, NodeJSRelInfoError> {
+        let mut from_info = self.to_owned();
+        from_info.version = from.as_ref().to_owned();
+        let mut to_info = self.to_owned();
+        to_info.version = to.as_ref().to_owned();
+
+        let (from_all, to_all) =
+            futures_util::future::try_join(from_info.fetch_all(), to_info.fetch_all()).await?;
+
+        Ok(NodeJSRelInfoDiff::new(&from_all, &to_all))
+    }
+
+    /// Fetches release metadata (date, npm version, V8 version, LTS codename)
+    /// for the specified version from the
+    /// [release index](https://nodejs.org/download/release/index.json)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("18.18.0").fetch_metadata().await?;
+    ///   assert_eq!(info.date, Some("2023-09-18".to_string()));
+    ///   assert_eq!(info.npm, Some("9.8.1".to_string()));
+    ///   assert_eq!(info.v8, Some("10.2.154.26".to_string()));
+    ///   assert_eq!(info.lts, Some("Hydrogen".to_string()));
+    ///   assert!(!info.is_security_release());
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_metadata(&mut self) -> Result<Self, NodeJSRelInfoError> {
+        let version = specs::validate_version(self.version.as_str())?;
+        let versions = index::fetch(&self.url_fmt, &self.http).await?;
+        let info = match versions.into_iter().find(|v| v.version == version) {
+            None => return Err(NodeJSRelInfoError::UnrecognizedVersion(version)),
+            Some(v) => v,
+        };
+
+        self.set_metadata(info);
+        Ok(self.to_owned())
+    }
+
+    /// Resolves the newest LTS release from the
+    /// [release index](https://nodejs.org/download/release/index.json) - sets
+    /// `version` and populates metadata
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::default().macos().arm64().fetch_latest_lts().await?;
+    ///   assert!(info.lts.is_some());
+    ///   let info = info.to_owned().fetch().await?;
+    ///   println!("{}", info.url);
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_latest_lts(&mut self) -> Result<Self, NodeJSRelInfoError> {
+        let versions = index::fetch(&self.url_fmt, &self.http).await?;
+        let info = match index::latest_lts(versions, None) {
+            None => return Err(NodeJSRelInfoError::UnrecognizedVersion("lts".to_string())),
+            Some(v) => v,
+        };
+
+        self.version = info.version.clone();
+        self.set_metadata(info);
+        Ok(self.to_owned())
+    }
+
+    /// Resolves the newest release within the named LTS line (e.g. `hydrogen`)
+    /// from the [release index](https://nodejs.org/download/release/index.json) -
+    /// sets `version` and populates metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `codename` - The LTS codename, case-insensitive (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::default().fetch_latest_lts_named("hydrogen").await?;
+    ///   assert!(info.version.starts_with("18."));
+    ///   assert_eq!(info.lts, Some("Hydrogen".to_string()));
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_latest_lts_named<T: AsRef<str>>(
+        &mut self,
+        codename: T,
+    ) -> Result<Self, NodeJSRelInfoError> {
+        let codename = codename.as_ref();
+        let versions = index::fetch(&self.url_fmt, &self.http).await?;
+        let info = match index::latest_lts(versions, Some(codename)) {
+            None => return Err(NodeJSRelInfoError::UnrecognizedVersion(codename.to_owned())),
+            Some(v) => v,
+        };
+
+        self.version = info.version.clone();
+        self.set_metadata(info);
+        Ok(self.to_owned())
+    }
+
+    /// Whether the version was a security release - always `false` until
+    /// metadata has been fetched via [`fetch_metadata()`](crate::NodeJSRelInfo::fetch_metadata)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("18.17.1");
+    /// assert!(!info.is_security_release());
+    /// ```
+    pub fn is_security_release(&self) -> bool {
+        self.security
+    }
+
+    /// Whether the version was released as part of an LTS line - always
+    /// `false` until metadata has been fetched via [`fetch_metadata()`](crate::NodeJSRelInfo::fetch_metadata)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let mut info = NodeJSRelInfo::new("18.18.0");
+    /// assert!(!info.is_lts());
+    /// info.lts = Some("Hydrogen".to_string());
+    /// assert!(info.is_lts());
+    /// ```
+    pub fn is_lts(&self) -> bool {
+        self.lts.is_some()
+    }
+
+    /// The LTS codename (e.g. `Hydrogen`) of the version's release line when
+    /// it was released as LTS (available after fetching metadata)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let mut info = NodeJSRelInfo::new("18.18.0");
+    /// assert_eq!(info.lts_codename(), None);
+    /// info.lts = Some("Hydrogen".to_string());
+    /// assert_eq!(info.lts_codename(), Some("Hydrogen"));
+    /// ```
+    pub fn lts_codename(&self) -> Option<&str> {
+        self.lts.as_deref()
+    }
+
+    /// Whether the version was released as a "Current" (non-LTS) release -
+    /// always `false` until metadata has been fetched via [`fetch_metadata()`](crate::NodeJSRelInfo::fetch_metadata)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let mut info = NodeJSRelInfo::new("20.6.1");
+    /// assert!(!info.is_current());
+    /// info.date = Some("2023-09-04".to_string());
+    /// assert!(info.is_current());
+    /// ```
+    pub fn is_current(&self) -> bool {
+        self.date.is_some() && self.lts.is_none()
+    }
+
+    /// Fetches the [release schedule](https://github.com/nodejs/Release) for
+    /// the specified version's release line enabling [`is_eol()`](crate::NodeJSRelInfo::is_eol),
+    /// [`eol_date()`](crate::NodeJSRelInfo::eol_date), and
+    /// [`support_phase()`](crate::NodeJSRelInfo::support_phase)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("16.20.2").fetch_schedule().await?;
+    ///   assert!(info.is_eol());
+    ///   assert_eq!(info.eol_date(), Some("2023-09-11"));
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_schedule(&mut self) -> Result<Self, NodeJSRelInfoError> {
+        let version = specs::validate_version(self.version.as_str())?;
+        let schedule = schedule::fetch(&version, &self.url_fmt, &self.http).await?;
+        self.schedule = Some(schedule);
+        Ok(self.to_owned())
+    }
+
+    /// Whether the version's release line has reached end-of-life - always
+    /// `false` until the schedule has been fetched via
+    /// [`fetch_schedule()`](crate::NodeJSRelInfo::fetch_schedule)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("16.20.2");
+    /// assert!(!info.is_eol());
+    /// ```
+    pub fn is_eol(&self) -> bool {
+        match &self.schedule {
+            Some(s) => s.is_eol(),
+            None => false,
+        }
+    }
+
+    /// The date (`YYYY-MM-DD`) the version's release line reaches end-of-life
+    /// (available after fetching schedule)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("16.20.2");
+    /// assert_eq!(info.eol_date(), None);
+    /// ```
+    pub fn eol_date(&self) -> Option<&str> {
+        self.schedule.as_ref().map(|s| s.end.as_str())
+    }
+
+    /// The current support phase of the version's release line (available
+    /// after fetching schedule)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::NodeJSRelInfo;
+    /// let info = NodeJSRelInfo::new("16.20.2");
+    /// assert_eq!(info.support_phase(), None);
+    /// ```
+    pub fn support_phase(&self) -> Option<NodeJSSupportPhase> {
+        self.schedule.as_ref().map(|s| s.support_phase())
+    }
+
+    /// Fetches the list of all published Node.js versions from the
+    /// [release index](https://nodejs.org/download/release/index.json) - newest first
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let versions = NodeJSRelInfo::default().fetch_versions().await?;
+    ///   let v20_6_1 = versions.iter().find(|v| v.version == "20.6.1").unwrap();
+    ///   assert_eq!(v20_6_1.date, "2023-09-08");
+    ///   assert_eq!(v20_6_1.lts, None);
+    ///   assert!(!v20_6_1.security);
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_versions(&self) -> Result<Vec<NodeJSVersionInfo>, NodeJSRelInfoError> {
+        index::fetch(&self.url_fmt, &self.http).await
+    }
+
+    /// Creates a new instance targeting the newest release within the given
+    /// major version (e.g. `20` resolves to the newest `20.x.y`) as listed in
+    /// the [release index](https://nodejs.org/download/release/index.json)
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The Node.js major version you are targeting
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::latest_in_major(20).await?.macos().arm64().fetch().await?;
+    ///   assert!(info.version.starts_with("20."));
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn latest_in_major(major: u64) -> Result<Self, NodeJSRelInfoError> {
+        NodeJSRelInfo::default().fetch_latest_in_major(major).await
+    }
+
+    /// Resolves the newest release within the given major version from the
+    /// [release index](https://nodejs.org/download/release/index.json) - sets
+    /// `version` and populates metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The Node.js major version you are targeting
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::default().windows().fetch_latest_in_major(18).await?;
+    ///   assert!(info.version.starts_with("18."));
+    ///   Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_latest_in_major(&mut self, major: u64) -> Result<Self, NodeJSRelInfoError> {
+        let versions = index::fetch(&self.url_fmt, &self.http).await?;
+        let info = match index::latest_in_major(versions, major) {
+            None => {
+                return Err(NodeJSRelInfoError::UnrecognizedVersion(format!(
+                    "{}.x",
+                    major
+                )))
+            }
+            Some(v) => v,
+        };
+
+        self.version = info.version.clone();
+        self.set_metadata(info);
+        Ok(self.to_owned())
+    }
+
+    /// Downloads the Node.js distributable into the given directory - returns
+    /// the path to the downloaded file (unavailable on wasm32)
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory to download into (`&str` / `Path` / `PathBuf`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1").macos().arm64().fetch().await?;
+    ///   let path = info.download(std::env::temp_dir()).await?;
+    ///   assert!(path.ends_with("node-v20.6.1-darwin-arm64.tar.gz"));
+    ///   Ok(())
+    /// }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download<P: AsRef<Path>>(&self, dir: P) -> Result<PathBuf, NodeJSRelInfoError> {
+        self.download_with_progress(dir, |_, _| {}).await
+    }
+
+    /// Downloads the Node.js distributable into the given directory reporting
+    /// progress as each chunk is written - returns the path to the downloaded
+    /// file (unavailable on wasm32)
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory to download into (`&str` / `Path` / `PathBuf`)
+    /// * `on_progress` - Called with bytes downloaded so far and total bytes (when known)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1").linux().fetch().await?;
+    ///   let path = info
+    ///     .download_with_progress(std::env::temp_dir(), |done, total| match total {
+    ///       Some(total) => println!("{} / {} bytes", done, total),
+    ///       None => println!("{} bytes", done),
+    ///     })
+    ///     .await?;
+    ///   println!("{}", path.display());
+    ///   Ok(())
+    /// }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_with_progress<P, F>(
+        &self,
+        dir: P,
+        on_progress: F,
+    ) -> Result<PathBuf, NodeJSRelInfoError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(u64, Option<u64>),
+    {
+        traced!(
+            tracing::info_span!("download", version = %self.version, dir = %dir.as_ref().display()),
+            async {
+                specs::validate_version(self.version.as_str())?;
+                let (filename, url) = match self.url.is_empty() {
+                    true => (self.filename(), self.url()),
+                    false => (self.filename.clone(), self.url.clone()),
+                };
+
+                // sub-path artifacts (e.g. `win-x64/node.exe`) keep their directory
+                // but must never escape it (e.g. `../node.tar.gz` or `/etc/node`)
+                let escapes = Path::new(&filename)
+                    .components()
+                    .any(|c| !matches!(c, std::path::Component::Normal(_)));
+
+                if filename.is_empty() || escapes {
+                    return Err(NodeJSRelInfoError::InvalidFilename(filename));
+                }
+
+                let dest = dir.as_ref().join(filename);
+                if let Some(parent) = dest.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+
+                http::download(url, &dest, &self.http, on_progress).await?;
+                Ok(dest)
+            }
+        )
+    }
+
+    /// Verifies a local copy of the Node.js distributable (e.g. one downloaded
+    /// via other means) by streaming it through SHA-256 and comparing the
+    /// result with `sha256` - requires the `verify` feature (unavailable on wasm32)
+    /// and fails w/ the error from [`checksum()`](NodeJSRelInfo::checksum) when
+    /// `sha256` is unset (i.e. before fetching) or is not a valid hex digest
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the local file (`&str` / `Path` / `PathBuf`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use node_js_release_info::{NodeJSRelInfo, NodeJSRelInfoError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NodeJSRelInfoError> {
+    ///   let info = NodeJSRelInfo::new("20.6.1").macos().arm64().fetch().await?;
+    ///   info.verify_file("./node-v20.6.1-darwin-arm64.tar.gz").await?;
+    ///   Ok(())
+    /// }
+    /// ```
+    #[cfg(all(feature = "verify", not(target_arch = "wasm32")))]
+    pub async fn verify_file<P: AsRef<Path>>(&self, path: P) -> Result<(), NodeJSRelInfoError> {
+        let path = path.as_ref();
+        let expected = self.checksum()?;
+        let digest = checksum::sha256_file(path).await?;
+
+        if digest != expected {
+            return Err(NodeJSRelInfoError::InvalidChecksum(
+                path.display().to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_specs(
+        &self,
+    ) -> Result<(String, specs::ParsedSpecs, NodeJSURLFormatter), NodeJSRelInfoError> {
+        let (version, specs, url_fmt) = self.fetch_shasums().await?;
+
+        if self.strict {
+            let unrecognized = specs::unrecognized(&version, &specs);
+            if !unrecognized.is_empty() {
+                return Err(NodeJSRelInfoError::UnrecognizedArtifacts(unrecognized));
+            }
+        }
+
+        match specs::parse(&version, specs) {
+            Some(s) => Ok((version, s, url_fmt)),
+            None => Err(NodeJSRelInfoError::UnrecognizedVersion(version)),
+        }
+    }
+
+    // fetches (and verifies when enabled) `SHASUMS256.txt` returning the
+    // version it describes - resolved from the listed filenames when using a
+    // `latest-v{major}.x` alias - along w/ the formatter for the host which served it
+    async fn fetch_shasums(
+        &self,
+    ) -> Result<(String, String, NodeJSURLFormatter), NodeJSRelInfoError> {
+        let version = match self.url_fmt.latest {
+            Some(_) => self.url_fmt.dir(&self.version),
+            None => specs::validate_version(self.version.as_str())?,
+        };
+        let (specs, url_fmt) = specs::fetch(&version, &self.url_fmt, &self.http).await?;
+        #[cfg(feature = "gpg")]
+        self.verify(&version, &specs, &url_fmt).await?;
+        let version = match self.url_fmt.latest {
+            Some(_) => match specs::version_of(&specs) {
+                Some(v) => v,
+                None => return Err(NodeJSRelInfoError::UnrecognizedVersion(version)),
+            },
+            None => version,
+        };
+        Ok((version, specs, url_fmt))
+    }
+
+    fn select_spec(
+        &mut self,
+        specs: &str,
+        url_fmt: &NodeJSURLFormatter,
+    ) -> Result<Self, NodeJSRelInfoError> {
+        let filename = self.filename();
+        let info = specs.lines().find(|&line| line.contains(filename.as_str()));
+
+        let mut specs = match info {
+            None => return Err(NodeJSRelInfoError::UnrecognizedConfiguration(filename))?,
+            Some(s) => s.split_whitespace(),
+        };
+
+        self.filename = filename;
+        self.sha256 = specs.nth(0).unwrap().to_string();
+        self.url = url_fmt.pkg(&self.version, &self.filename);
+        Ok(self.to_owned())
+    }
+
+    fn spec_info(
+        &self,
+        version: &str,
+        spec: specs::ParsedSpec,
+        url_fmt: &NodeJSURLFormatter,
+    ) -> NodeJSRelInfo {
+        let (kind, os, arch, ext, sha256, filename) = spec;
+        let mut info = NodeJSRelInfo {
+            kind,
+            os,
+            arch,
+            version: version.to_owned(),
+            ext,
+            filename,
+            sha256,
+            date: self.date.clone(),
+            npm: self.npm.clone(),
+            v8: self.v8.clone(),
+            lts: self.lts.clone(),
+            security: self.security,
+            schedule: self.schedule.clone(),
+            url_fmt: url_fmt.clone(),
+            http: self.http.clone(),
+            strict: self.strict,
+            #[cfg(feature = "gpg")]
+            gpg: self.gpg.clone(),
+            ..Default::default()
+        };
+
+        info.url = info.url_fmt.pkg(&info.version, &info.filename);
+        info
+    }
+
+    fn set_metadata(&mut self, info: NodeJSVersionInfo) {
+        self.date = Some(info.date);
+        self.npm = info.npm;
+        self.v8 = info.v8;
+        self.lts = info.lts;
+        self.security = info.security;
+    }
+
+    #[cfg(feature = "gpg")]
+    async fn verify(
+        &self,
+        version: &String,
+        specs: &str,
+        url_fmt: &NodeJSURLFormatter,
+    ) -> Result<(), NodeJSRelInfoError> {
+        gpg::verify(version, specs, url_fmt, &self.http, &self.gpg).await
+    }
+}
+
+impl TryFrom<&str> for NodeJSRelInfo {
+    type Error = NodeJSRelInfoError;
+
+    fn try_from(filename: &str) -> Result<Self, Self::Error> {
+        NodeJSRelInfo::from_filename(filename)
+    }
+}
+
+// compact `{version}-{os}-{arch}.{ext}` form (e.g. `20.6.1-darwin-arm64.tar.gz`),
+// `{version}-{kind}.{ext}` for platform-independent artifacts (e.g.
+// `20.6.1-headers.tar.gz`, `20.6.1-source.tar.xz`, `20.6.1-pkg`), or
+// `{version}-{kind}-{arch}.{ext}` for Windows sub-path artifacts (e.g.
+// `20.6.1-exe-x64`, `20.6.1-pdb-x64.zip`)
+impl Display for NodeJSRelInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            NodeJSArtifactKind::Binary => {
+                write!(f, "{}-{}-{}.{}", self.version, self.os, self.arch, self.ext)
+            }
+            NodeJSArtifactKind::Pkg => write!(f, "{}-{}", self.version, self.kind),
+            NodeJSArtifactKind::Headers | NodeJSArtifactKind::Source => {
+                write!(f, "{}-{}.{}", self.version, self.kind, self.ext)
+            }
+            NodeJSArtifactKind::Exe | NodeJSArtifactKind::Lib => {
+                write!(f, "{}-{}-{}", self.version, self.kind, self.arch)
+            }
+            NodeJSArtifactKind::Pdb => {
+                write!(
+                    f,
+                    "{}-{}-{}.{}",
+                    self.version, self.kind, self.arch, self.ext
+                )
+            }
+        }
+    }
+}
+
+impl FromStr for NodeJSRelInfo {
+    type Err = NodeJSRelInfoError;
+
+    fn from_str(s: &str) -> Result<NodeJSRelInfo, NodeJSRelInfoError> {
+        let unrecognized = || NodeJSRelInfoError::UnrecognizedConfiguration(s.to_string());
+        let (rest, last) = s.rsplit_once('-').ok_or_else(unrecognized)?;
+        let (head, ext) = match last.split_once('.') {
+            Some((h, e)) => (h, Some(e)),
+            None => (last, None),
+        };
+
+        if let Ok(kind) = NodeJSArtifactKind::from_str(head) {
+            let mut info = NodeJSRelInfo::new(specs::validate_version(rest)?);
+            info.kind = kind;
+            match (&info.kind, ext) {
+                (NodeJSArtifactKind::Pkg, None) => info.os = NodeJSOS::Darwin,
+                (NodeJSArtifactKind::Headers | NodeJSArtifactKind::Source, Some(ext)) => {
+                    info.ext = NodeJSPkgExt::from_str(ext)?
+                }
+                _ => return Err(unrecognized()),
+            }
+            return Ok(info);
+        }
+
+        let (version, os) = rest.rsplit_once('-').ok_or_else(unrecognized)?;
+
+        if let Ok(kind) = NodeJSArtifactKind::from_str(os) {
+            let mut info = NodeJSRelInfo::new(specs::validate_version(version)?);
+            info.kind = kind;
+            info.os = NodeJSOS::Windows;
+            info.arch = NodeJSArch::from_str(head)?;
+            match (&info.kind, ext) {
+                (NodeJSArtifactKind::Exe | NodeJSArtifactKind::Lib, None) => {}
+                (NodeJSArtifactKind::Pdb, Some(ext)) => info.ext = NodeJSPkgExt::from_str(ext)?,
+                _ => return Err(unrecognized()),
+            }
+            return Ok(info);
+        }
+
+        let ext = ext.ok_or_else(unrecognized)?;
+        let mut info = NodeJSRelInfo::new(specs::validate_version(version)?);
+        info.os = NodeJSOS::from_str(os)?;
+        info.arch = NodeJSArch::from_str(head)?;
+        info.ext = NodeJSPkgExt::from_str(ext)?;
+        Ok(info)
+    }
+}
+
+// - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    fn is_thread_safe<T: Sized + Send + Sync + Unpin>() {}
+
+    #[test]
+    fn it_initializes() {
+        let info = NodeJSRelInfo::new("1.0.0");
+        assert_eq!(info.os, NodeJSOS::Linux);
+        assert_eq!(info.arch, NodeJSArch::X64);
+        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+        assert_eq!(info.version, "1.0.0".to_string());
+        assert_eq!(info.filename, "".to_string());
+        assert_eq!(info.sha256, "".to_string());
+        assert_eq!(info.url, "".to_string());
+        assert_eq!(info.date, None);
+        assert_eq!(info.npm, None);
+        assert_eq!(info.v8, None);
+        assert_eq!(info.lts, None);
+        assert!(!info.security);
+        assert_eq!(info.schedule, None);
+        is_thread_safe::<NodeJSRelInfo>();
+    }
+
+    #[test]
+    fn it_initializes_with_defaults() {
+        let info = NodeJSRelInfo::default();
+        assert_eq!(info.os, NodeJSOS::Linux);
+        assert_eq!(info.arch, NodeJSArch::X64);
+        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+        assert_eq!(info.version, "".to_string());
+        assert_eq!(info.filename, "".to_string());
+        assert_eq!(info.sha256, "".to_string());
+        assert_eq!(info.url, "".to_string());
+    }
+
+    #[test]
+    #[cfg_attr(not(target_os = "macos"), ignore)]
     fn it_initializes_using_current_environment_on_macos() {
         let info = NodeJSRelInfo::from_env("1.0.0").unwrap();
         assert_eq!(info.ext, NodeJSPkgExt::Targz);
     }
 
-    #[test]
-    #[cfg_attr(not(target_os = "linux"), ignore)]
-    fn it_initializes_using_current_environment_on_linux() {
-        let info = NodeJSRelInfo::from_env("1.0.0").unwrap();
-        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+    #[test]
+    #[cfg_attr(not(target_os = "linux"), ignore)]
+    fn it_initializes_using_current_environment_on_linux() {
+        let info = NodeJSRelInfo::from_env("1.0.0").unwrap();
+        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_os = "windows"), ignore)]
+    fn it_initializes_using_current_environment_on_windows() {
+        let info = NodeJSRelInfo::from_env("1.0.0").unwrap();
+        assert_eq!(info.ext, NodeJSPkgExt::Zip);
+    }
+
+    #[test]
+    fn it_initializes_from_filename() {
+        let info = NodeJSRelInfo::from_filename("node-v20.6.1-win-arm64.7z").unwrap();
+        assert_eq!(info.version, "20.6.1");
+        assert_eq!(info.os, NodeJSOS::Windows);
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.ext, NodeJSPkgExt::S7z);
+        assert_eq!(info.filename, "node-v20.6.1-win-arm64.7z");
+        assert_eq!(info.sha256, "");
+        assert_eq!(info.url, "");
+
+        let info = NodeJSRelInfo::from_filename("./downloads/node-v18.18.0-x64.msi").unwrap();
+        assert_eq!(info.version, "18.18.0");
+        assert_eq!(info.os, NodeJSOS::Windows);
+        assert_eq!(info.arch, NodeJSArch::X64);
+        assert_eq!(info.ext, NodeJSPkgExt::Msi);
+        assert_eq!(info.filename, "node-v18.18.0-x64.msi");
+    }
+
+    #[test]
+    fn it_initializes_from_filename_using_try_from() {
+        let info = NodeJSRelInfo::try_from("node-v20.6.1-linux-s390x.tar.xz").unwrap();
+        assert_eq!(info.os, NodeJSOS::Linux);
+        assert_eq!(info.arch, NodeJSArch::S390X);
+        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+        assert_eq!(info.filename, info.filename());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedConfiguration(\"node-v20.6.1-NOPE.tar.gz\")"
+    )]
+    fn it_fails_to_initialize_from_filename_when_unrecognized() {
+        NodeJSRelInfo::from_filename("node-v20.6.1-NOPE.tar.gz").unwrap();
+    }
+
+    #[test]
+    fn it_initializes_from_headers_filename() {
+        let info = NodeJSRelInfo::from_filename("node-v20.6.1-headers.tar.xz").unwrap();
+        assert_eq!(info.version, "20.6.1");
+        assert_eq!(info.kind, NodeJSArtifactKind::Headers);
+        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+        assert_eq!(info.filename, info.filename());
+
+        let info = NodeJSRelInfo::from_filename("node-v20.6.1.tar.xz").unwrap();
+        assert_eq!(info.kind, NodeJSArtifactKind::Source);
+        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+        assert_eq!(info.filename, info.filename());
+
+        let info = NodeJSRelInfo::from_filename("node-v20.6.1.pkg").unwrap();
+        assert_eq!(info.kind, NodeJSArtifactKind::Pkg);
+        assert_eq!(info.os, NodeJSOS::Darwin);
+        assert_eq!(info.filename, info.filename());
+    }
+
+    #[test]
+    fn it_formats_compact_configuration_string() {
+        let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-darwin-arm64.tar.gz");
+
+        let info = NodeJSRelInfo::new("20.6.1")
+            .windows()
+            .x86()
+            .msi()
+            .to_owned();
+        assert_eq!(format!("{info}"), "20.6.1-win-x86.msi");
+
+        let info = NodeJSRelInfo::new("20.6.1").headers().tar_xz().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-headers.tar.xz");
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+
+        let info = NodeJSRelInfo::new("20.6.1").source().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-source.tar.gz");
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+
+        let info = NodeJSRelInfo::new("20.6.1").pkg().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-pkg");
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+
+        let info = NodeJSRelInfo::new("20.6.1").exe().x86().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-exe-x86");
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+
+        let info = NodeJSRelInfo::new("20.6.1").lib().arm64().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-lib-arm64");
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+
+        let info = NodeJSRelInfo::new("20.6.1").pdb().zip().to_owned();
+        assert_eq!(info.to_string(), "20.6.1-pdb-x64.zip");
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+    }
+
+    #[test]
+    fn it_parses_compact_configuration_string() {
+        let info = NodeJSRelInfo::from_str("20.6.1-darwin-arm64.tar.gz").unwrap();
+        assert_eq!(info.version, "20.6.1");
+        assert_eq!(info.os, NodeJSOS::Darwin);
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+
+        let info: NodeJSRelInfo = "18.18.0-win-x64.7z".parse().unwrap();
+        assert_eq!(info.to_string(), "18.18.0-win-x64.7z");
+
+        let info = NodeJSRelInfo::new("20.6.1")
+            .windows()
+            .arm64()
+            .msi()
+            .to_owned();
+        assert_eq!(NodeJSRelInfo::from_str(&info.to_string()).unwrap(), info);
+    }
+
+    #[test]
+    fn it_fails_to_parse_invalid_compact_configuration_string() {
+        let err = NodeJSRelInfo::from_str("NOPE").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized Configuration! Received: 'NOPE'"
+        );
+
+        let err = NodeJSRelInfo::from_str("20.6.1-darwin-arm64").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized Configuration! Received: '20.6.1-darwin-arm64'"
+        );
+
+        let err = NodeJSRelInfo::from_str("NOPE-darwin-arm64.tar.gz").unwrap_err();
+        assert_eq!(err.to_string(), "Error: Invalid Version! Received: 'NOPE'");
+
+        let err = NodeJSRelInfo::from_str("20.6.1-NOPE-arm64.tar.gz").unwrap_err();
+        assert_eq!(err.to_string(), "Error: Unrecognized OS! Received: 'NOPE'");
+
+        let err = NodeJSRelInfo::from_str("20.6.1-darwin-NOPE.tar.gz").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized Arch! Received: 'NOPE'"
+        );
+
+        let err = NodeJSRelInfo::from_str("20.6.1-pkg.tar.gz").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized Configuration! Received: '20.6.1-pkg.tar.gz'"
+        );
+
+        let err = NodeJSRelInfo::from_str("20.6.1-exe-x64.zip").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized Configuration! Received: '20.6.1-exe-x64.zip'"
+        );
+
+        let err = NodeJSRelInfo::from_str("20.6.1-source").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized Configuration! Received: '20.6.1-source'"
+        );
+
+        let err = NodeJSRelInfo::from_str("20.6.1-darwin-arm64.NOPE").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: Unrecognized File Extension! Received: 'NOPE'"
+        );
+    }
+
+    #[test]
+    fn it_sets_os() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        assert_eq!(info.os, NodeJSOS::Linux);
+
+        info.windows();
+
+        assert_eq!(info.os, NodeJSOS::Windows);
+
+        info.macos();
+
+        assert_eq!(info.os, NodeJSOS::Darwin);
+
+        info.linux();
+
+        assert_eq!(info.os, NodeJSOS::Linux);
+
+        info.aix();
+
+        assert_eq!(info.os, NodeJSOS::AIX);
+    }
+
+    #[test]
+    fn it_sets_arch() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        info.x86();
+
+        assert_eq!(info.arch, NodeJSArch::X86);
+
+        info.x64();
+
+        assert_eq!(info.arch, NodeJSArch::X64);
+
+        info.arm64();
+
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+
+        info.armv7l();
+
+        assert_eq!(info.arch, NodeJSArch::ARMV7L);
+
+        info.ppc64();
+
+        assert_eq!(info.arch, NodeJSArch::PPC64);
+
+        info.ppc64le();
+
+        assert_eq!(info.arch, NodeJSArch::PPC64LE);
+
+        info.s390x();
+
+        assert_eq!(info.arch, NodeJSArch::S390X);
+
+        info.armv6l();
+
+        assert_eq!(info.arch, NodeJSArch::ARMV6L);
+
+        info.riscv64();
+
+        assert_eq!(info.arch, NodeJSArch::RISCV64);
+
+        info.loong64();
+
+        assert_eq!(info.arch, NodeJSArch::LOONG64);
+    }
+
+    #[test]
+    fn it_sets_ext() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        info.zip();
+
+        assert_eq!(info.ext, NodeJSPkgExt::Zip);
+
+        info.tar_gz();
+
+        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+
+        info.tar_xz();
+
+        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+
+        info.msi();
+
+        assert_eq!(info.ext, NodeJSPkgExt::Msi);
+
+        info.s7z();
+
+        assert_eq!(info.ext, NodeJSPkgExt::S7z);
+    }
+
+    #[test]
+    fn it_sets_kind() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Binary);
+
+        info.headers();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Headers);
+
+        info.source();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Source);
+
+        info.pkg();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Pkg);
+        assert_eq!(info.os, NodeJSOS::Darwin);
+
+        info.exe();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Exe);
+        assert_eq!(info.os, NodeJSOS::Windows);
+
+        info.lib();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Lib);
+
+        info.pdb();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Pdb);
+
+        info.binary();
+
+        assert_eq!(info.kind, NodeJSArtifactKind::Binary);
+    }
+
+    #[test]
+    fn it_sets_proxy() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        assert_eq!(info.http.proxy, None);
+
+        info.proxy("http://proxy.internal:3128");
+
+        assert_eq!(
+            info.http.proxy,
+            Some("http://proxy.internal:3128".to_string())
+        );
+    }
+
+    #[test]
+    fn it_sets_user_agent() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        assert_eq!(info.http.user_agent, None);
+
+        info.user_agent("my-tool/1.0.0");
+
+        assert_eq!(info.http.user_agent, Some("my-tool/1.0.0".to_string()));
+    }
+
+    #[test]
+    fn it_sets_url_configuration() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        info.protocol("http:")
+            .host("127.0.0.1:1234")
+            .pathname("/dist")
+            .schedule_url("http://127.0.0.1:1234/schedule.json");
+
+        assert_eq!(info.url_fmt.protocol, "http:");
+        assert_eq!(info.url_fmt.host, "127.0.0.1:1234");
+        assert_eq!(info.url_fmt.pathname, "/dist");
+        assert_eq!(info.url_fmt.schedule, "http://127.0.0.1:1234/schedule.json");
+        assert_eq!(
+            info.url(),
+            "http://127.0.0.1:1234/dist/v1.0.0/node-v1.0.0-linux-x64.tar.gz"
+        );
+    }
+
+    #[test]
+    fn it_sets_rate_limit() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        assert_eq!(info.http.rate_limit, None);
+
+        info.rate_limit(5);
+
+        assert_eq!(info.http.rate_limit, Some(5));
+
+        info.rate_limit(0);
+
+        assert_eq!(info.http.rate_limit, None);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        names: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for RecordingSubscriber {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut names = self.names.lock().unwrap();
+            names.push(span.metadata().name().to_string());
+            tracing::span::Id::from_u64(names.len() as u64)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut names = self.names.lock().unwrap();
+            names.push(event.metadata().level().to_string());
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn it_emits_tracing_spans_and_events() {
+        let subscriber = RecordingSubscriber::default();
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .expect(2)
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        info.fetch_all().await.unwrap();
+        mock.assert_async().await;
+
+        let names = subscriber.names.lock().unwrap();
+        assert!(names.contains(&"fetch".to_string()));
+        assert!(names.contains(&"fetch_all".to_string()));
+        assert!(names.contains(&"DEBUG".to_string()));
+    }
+
+    #[tokio::test]
+    async fn it_diffs_node_js_release_info_between_versions() {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        let from_specs: Vec<&str> = specs::get_fake_specs()
+            .lines()
+            .filter(|line| !line.contains("linux-x64.tar.xz"))
+            .collect();
+        let to_specs = specs::get_fake_specs()
+            .replace("20.6.1", "20.7.0")
+            .replace("d8ba8018", "00000000")
+            .lines()
+            .filter(|line| !line.contains("aix-ppc64"))
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let from_mock = specs::setup_server_mock("20.6.1", &mut info.url_fmt, &mut server)
+            .with_body(from_specs.join("\n"))
+            .create_async()
+            .await;
+        let to_mock = specs::setup_server_mock("20.7.0", &mut info.url_fmt, &mut server)
+            .with_body(to_specs)
+            .create_async()
+            .await;
+
+        let diff = info.diff_versions("20.6.1", "20.7.0").await.unwrap();
+        from_mock.assert_async().await;
+        to_mock.assert_async().await;
+
+        let filenames = |all: &NodeJSRelInfoCollection| -> Vec<String> {
+            all.iter().map(|info| info.filename.clone()).collect()
+        };
+        assert_eq!(
+            filenames(&diff.added),
+            vec!["node-v20.7.0-linux-x64.tar.xz"]
+        );
+        assert_eq!(
+            filenames(&diff.removed),
+            vec!["node-v20.6.1-aix-ppc64.tar.gz"]
+        );
+        assert_eq!(
+            filenames(&diff.changed),
+            vec!["node-v20.7.0-darwin-arm64.tar.gz"]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_using_latest_alias() {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        info.use_latest_alias(20);
+        let mock = specs::setup_server_mock("", &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let result = info.macos().arm64().fetch().await.unwrap();
+        let all = info.fetch_all().await.unwrap();
+        mock.assert_async().await;
+
+        let url = format!(
+            "{}/download/release/latest-v20.x/node-v20.6.1-darwin-arm64.tar.gz",
+            server.url()
+        );
+        assert_eq!(result.version, "20.6.1");
+        assert_eq!(result.filename, "node-v20.6.1-darwin-arm64.tar.gz");
+        assert_eq!(
+            result.sha256,
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
+        );
+        assert_eq!(result.url, url);
+        assert_eq!(all.len(), 41);
+        assert_eq!(all[0].version, "20.6.1");
+        assert_eq!(all[2].url, url);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"latest-v99.x\")"
+    )]
+    async fn it_fails_to_fetch_node_js_release_info_using_latest_alias_when_unrecognized() {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        info.use_latest_alias(99);
+        let mock = specs::setup_server_mock("", &mut info.url_fmt, &mut server)
+            .with_status(404)
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn it_applies_config() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let config = NodeJSRelInfoConfig {
+            timeout: Some(Duration::from_secs(5)),
+            retries: 3,
+            mirror: Some("http://127.0.0.1:1234/node".to_string()),
+            proxy: Some("http://proxy.internal:3128".to_string()),
+            user_agent: Some("my-tool/1.0.0".to_string()),
+        };
+
+        info.mirrors(["https://npmmirror.com/mirrors/node"]);
+        info.config(&config);
+
+        assert_eq!(info.http.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(info.http.retries, 3);
+        assert_eq!(info.http.proxy, config.proxy);
+        assert_eq!(info.http.user_agent, config.user_agent);
+        assert_eq!(
+            info.url(),
+            "http://127.0.0.1:1234/node/v20.6.1/node-v20.6.1-linux-x64.tar.gz"
+        );
+        assert_eq!(info.url_fmt.mirrors.len(), 1);
+
+        info.config(&NodeJSRelInfoConfig::new());
+
+        assert_eq!(info.http.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(info.http.retries, 3);
+        assert_eq!(info.http.proxy, config.proxy);
+        assert_eq!(info.http.user_agent, config.user_agent);
+        assert_eq!(info.url_fmt.host, "127.0.0.1:1234");
+        assert_eq!(info.url_fmt.mirrors.len(), 1);
+    }
+
+    #[test]
+    fn it_applies_only_settings_present_in_config() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        info.url_fmt.protocol = "http:".to_string();
+        info.url_fmt.host = "127.0.0.1:1234".to_string();
+        info.url_fmt.pathname = "/node".to_string();
+        info.retries(2).user_agent("my-tool/1.0.0");
+
+        info.config(&NodeJSRelInfoConfig {
+            timeout: Some(Duration::from_secs(5)),
+            ..NodeJSRelInfoConfig::new()
+        });
+
+        assert_eq!(info.http.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(info.http.retries, 2);
+        assert_eq!(info.http.user_agent, Some("my-tool/1.0.0".to_string()));
+        assert_eq!(
+            info.url(),
+            "http://127.0.0.1:1234/node/v20.6.1/node-v20.6.1-linux-x64.tar.gz"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_retries_fetching_node_js_release_info() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let err = info.retries(1).fetch().await.unwrap_err();
+        mock.assert_async().await;
+
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus { status: 503, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_from_mirror_when_primary_fails() {
+        let mut primary = Server::new_async().await;
+        let mut mirror = Server::new_async().await;
+        let primary_mock = primary
+            .mock("GET", "/download/release/v20.6.1/SHASUMS256.txt")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let mirror_mock = mirror
+            .mock("GET", "/mirrors/node/v20.6.1/SHASUMS256.txt")
+            .with_body(specs::get_fake_specs())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        info.protocol("http:")
+            .host(primary.host_with_port())
+            .mirrors([format!("{}/mirrors/node", mirror.url())]);
+
+        let result = info.macos().arm64().fetch().await.unwrap();
+        let all = info.fetch_all().await.unwrap();
+        primary_mock.assert_async().await;
+        mirror_mock.assert_async().await;
+
+        let url = format!(
+            "{}/mirrors/node/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz",
+            mirror.url()
+        );
+        assert_eq!(result.url, url);
+        assert_eq!(
+            all.find(NodeJSOS::Darwin, NodeJSArch::ARM64, NodeJSPkgExt::Targz)
+                .unwrap()
+                .url,
+            url
+        );
+        assert_eq!(info.url_fmt.host, primary.host_with_port());
+    }
+
+    #[tokio::test]
+    async fn it_does_not_try_mirror_when_primary_fails_with_non_retryable_error() {
+        let mut primary = Server::new_async().await;
+        let mut mirror = Server::new_async().await;
+        let primary_mock = primary
+            .mock("GET", "/download/release/v20.6.1/SHASUMS256.txt")
+            .with_status(404)
+            .create_async()
+            .await;
+        let mirror_mock = mirror
+            .mock("GET", "/download/release/v20.6.1/SHASUMS256.txt")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let err = NodeJSRelInfo::new("20.6.1")
+            .protocol("http:")
+            .host(primary.host_with_port())
+            .mirrors([mirror.url()])
+            .fetch()
+            .await
+            .unwrap_err();
+        primary_mock.assert_async().await;
+        mirror_mock.assert_async().await;
+
+        assert!(matches!(err, NodeJSRelInfoError::UnrecognizedVersion(v) if v == "20.6.1"));
+    }
+
+    #[tokio::test]
+    async fn it_fails_when_all_mirrors_fail() {
+        let mut primary = Server::new_async().await;
+        let mut mirror = Server::new_async().await;
+        let primary_mock = primary
+            .mock("GET", "/download/release/v20.6.1/SHASUMS256.txt")
+            .with_status(500)
+            .create_async()
+            .await;
+        let mirror_mock = mirror
+            .mock("GET", "/download/release/v20.6.1/SHASUMS256.txt")
+            .with_status(502)
+            .create_async()
+            .await;
+
+        let err = NodeJSRelInfo::new("20.6.1")
+            .protocol("http:")
+            .host(primary.host_with_port())
+            .mirrors([mirror.url()])
+            .fetch()
+            .await
+            .unwrap_err();
+        primary_mock.assert_async().await;
+        mirror_mock.assert_async().await;
+
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::HttpStatus { status: 502, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_using_cache() {
+        let cache = Arc::new(MemoryReleaseInfoCache::new());
+        let mut info = NodeJSRelInfo::new("20.6.1").cache(cache.clone()).to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .expect(1)
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        let all = info.fetch_all().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(all.len(), 41);
+        assert!(cache.get(&info.url_fmt.info(&info.version)).is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "gpg")]
+    fn it_enables_signature_verification() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+
+        assert!(!info.gpg.enabled);
+
+        info.verify_signature();
+
+        assert!(info.gpg.enabled);
+        assert_eq!(info.gpg.keyring, None);
+
+        info.gpg_keyring("pubring.kbx");
+
+        assert!(info.gpg.enabled);
+        assert_eq!(info.gpg.keyring, Some(PathBuf::from("pubring.kbx")));
+    }
+
+    #[test]
+    fn it_gets_owned_copy() {
+        let mut info1 = NodeJSRelInfo::new("1.0.0");
+        let info2 = info1.to_owned();
+
+        assert_eq!(info1, info2);
+
+        info1.windows();
+
+        assert_ne!(info1, info2);
+    }
+
+    #[test]
+    fn it_formats_filename() {
+        let info = NodeJSRelInfo::new("1.0.0").macos().x64().zip().to_owned();
+
+        assert_eq!(info.filename(), "node-v1.0.0-darwin-x64.zip");
+
+        let info = NodeJSRelInfo::new("1.0.0").windows().x64().msi().to_owned();
+
+        assert_eq!(info.filename(), "node-v1.0.0-x64.msi");
+
+        let info = NodeJSRelInfo::new("1.0.0").headers().tar_xz().to_owned();
+
+        assert_eq!(info.filename(), "node-v1.0.0-headers.tar.xz");
+
+        let info = NodeJSRelInfo::new("1.0.0").source().to_owned();
+
+        assert_eq!(info.filename(), "node-v1.0.0.tar.gz");
+
+        let info = NodeJSRelInfo::new("1.0.0").pkg().arm64().to_owned();
+
+        assert_eq!(info.filename(), "node-v1.0.0.pkg");
+
+        let info = NodeJSRelInfo::new("1.0.0").exe().x86().to_owned();
+
+        assert_eq!(info.filename(), "win-x86/node.exe");
+
+        let info = NodeJSRelInfo::new("1.0.0").lib().to_owned();
+
+        assert_eq!(info.filename(), "win-x64/node.lib");
+
+        let info = NodeJSRelInfo::new("1.0.0").pdb().zip().to_owned();
+
+        assert_eq!(info.filename(), "win-x64/node_pdb.zip");
+    }
+
+    #[test]
+    fn it_formats_url() {
+        let info = NodeJSRelInfo::new("1.0.0").macos().x64().zip().to_owned();
+
+        assert_eq!(
+            info.url(),
+            "https://nodejs.org/download/release/v1.0.0/node-v1.0.0-darwin-x64.zip"
+        );
+
+        let info = NodeJSRelInfo::new("1.0.0").windows().x64().msi().to_owned();
+
+        assert_eq!(
+            info.url(),
+            "https://nodejs.org/download/release/v1.0.0/node-v1.0.0-x64.msi"
+        );
+        assert_eq!(info.url, "");
+    }
+
+    #[test]
+    fn it_formats_archive_paths() {
+        let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+        assert_eq!(
+            info.archive_root(),
+            Some("node-v20.6.1-darwin-arm64".to_string())
+        );
+        assert_eq!(
+            info.bin_path_in_archive(),
+            Some("node-v20.6.1-darwin-arm64/bin/node".to_string())
+        );
+
+        let info = NodeJSRelInfo::new("20.6.1").aix().ppc64().to_owned();
+        assert_eq!(
+            info.bin_path_in_archive(),
+            Some("node-v20.6.1-aix-ppc64/bin/node".to_string())
+        );
+
+        let info = NodeJSRelInfo::new("20.6.1")
+            .windows()
+            .x86()
+            .s7z()
+            .to_owned();
+        assert_eq!(
+            info.archive_root(),
+            Some("node-v20.6.1-win-x86".to_string())
+        );
+        assert_eq!(
+            info.bin_path_in_archive(),
+            Some("node-v20.6.1-win-x86/node.exe".to_string())
+        );
+
+        for info in [
+            NodeJSRelInfo::new("20.6.1").windows().msi().to_owned(),
+            NodeJSRelInfo::new("20.6.1").headers().to_owned(),
+            NodeJSRelInfo::new("20.6.1").source().to_owned(),
+            NodeJSRelInfo::new("20.6.1").pkg().to_owned(),
+            NodeJSRelInfo::new("20.6.1").exe().to_owned(),
+        ] {
+            assert_eq!(info.archive_root(), None);
+            assert_eq!(info.bin_path_in_archive(), None);
+        }
+    }
+
+    #[test]
+    fn it_formats_integrity() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        assert_eq!(info.integrity(), None);
+
+        info.sha256 =
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string();
+        assert_eq!(
+            info.integrity().unwrap(),
+            "sha256-2LqAGNRbKUQpsadkbMvq6yrzzfRbXJHau9k+KiA1y0Y="
+        );
+
+        info.sha256 =
+            "D8BA8018D45B294429B1A7646CCBEAEB2AF3CDF45B5C91DABBD93E2A2035CB46".to_string();
+        assert_eq!(
+            info.integrity().unwrap(),
+            "sha256-2LqAGNRbKUQpsadkbMvq6yrzzfRbXJHau9k+KiA1y0Y="
+        );
+
+        info.sha256 = "+f".repeat(32);
+        assert_eq!(info.integrity(), None);
+
+        info.sha256 = "zz".repeat(32);
+        assert_eq!(info.integrity(), None);
+
+        info.sha256 = "d8ba".to_string();
+        assert_eq!(info.integrity(), None);
+    }
+
+    #[test]
+    fn it_parses_checksum() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let err = info.checksum().unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::InvalidChecksum(h) if h.is_empty()));
+
+        info.sha256 =
+            "D8BA8018D45B294429B1A7646CCBEAEB2AF3CDF45B5C91DABBD93E2A2035CB46".to_string();
+        assert_eq!(
+            info.checksum().unwrap().to_hex(),
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
+        );
+    }
+
+    #[test]
+    fn it_serializes_and_deserializes() {
+        let version = "20.6.1".to_string();
+        let filename = "node-v20.6.1-darwin-arm64.tar.gz".to_string();
+        let sha256 = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string();
+        let url = "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
+            .to_string();
+        let info_orig = NodeJSRelInfo {
+            os: NodeJSOS::Darwin,
+            arch: NodeJSArch::ARM64,
+            ext: NodeJSPkgExt::Targz,
+            version: version.clone(),
+            filename: filename.clone(),
+            sha256: sha256.clone(),
+            url: url.clone(),
+            ..Default::default()
+        };
+        let info_json = serde_json::to_string(&info_orig).unwrap();
+        let info: NodeJSRelInfo = serde_json::from_str(&info_json).unwrap();
+        assert_eq!(info.os, NodeJSOS::Darwin);
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+        assert_eq!(info.version, "20.6.1".to_string());
+        assert_eq!(
+            info.filename,
+            "node-v20.6.1-darwin-arm64.tar.gz".to_string()
+        );
+        assert_eq!(
+            info.sha256,
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string()
+        );
+        assert_eq!(
+            info.url,
+            "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn it_serializes_to_pretty_json() {
+        let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+        let json = info.to_string_pretty(NodeJSRelInfoFormat::Json).unwrap();
+        assert!(json.contains("\n  \"version\": \"20.6.1\",\n"));
+        assert_eq!(serde_json::from_str::<NodeJSRelInfo>(&json).unwrap(), info);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn it_serializes_to_yaml() {
+        let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+        let doc = info.to_string_pretty(NodeJSRelInfoFormat::Yaml).unwrap();
+        assert!(doc.contains("arch: arm64\n"));
+        assert_eq!(serde_yaml::from_str::<NodeJSRelInfo>(&doc).unwrap(), info);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn it_serializes_to_toml() {
+        let info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+        let doc = info.to_string_pretty(NodeJSRelInfoFormat::Toml).unwrap();
+        assert!(doc.contains("arch = \"arm64\"\n"));
+        assert_eq!(toml::from_str::<NodeJSRelInfo>(&doc).unwrap(), info);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidVersion(\"NOPE!\")"
+    )]
+    async fn it_fails_to_fetch_info_when_version_is_invalid() {
+        let mut info = NodeJSRelInfo::new("NOPE!");
+        info.fetch().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"1.0.0\")"
+    )]
+    async fn it_fails_to_fetch_info_when_version_is_unrecognized() {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .with_status(404)
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedConfiguration(\"node-v20.6.1-linux-x86.tar.gz\")"
+    )]
+    async fn it_fails_to_fetch_info_when_configuration_is_unrecognized() {
+        let mut server = Server::new_async().await;
+        let mut info = NodeJSRelInfo::new("20.6.1").linux().x86().to_owned();
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_fails_to_fetch_info_without_network_request_when_configuration_is_incompatible() {
+        let mut server = Server::new_async().await;
+        let mut info = NodeJSRelInfo::new("20.6.1").linux().zip().to_owned();
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let err = info.fetch().await.unwrap_err();
+        mock.assert_async().await;
+
+        assert!(matches!(err, NodeJSRelInfoError::IncompatibleExt(e) if e == "zip (linux)"));
+    }
+
+    #[test]
+    fn it_validates_configuration() {
+        assert!(NodeJSRelInfo::new("20.6.1").validate().is_ok());
+        assert!(NodeJSRelInfo::new("20.6.1").msi().validate().is_ok());
+        assert!(NodeJSRelInfo::new("20.6.1").exe().validate().is_ok());
+
+        let err = NodeJSRelInfo::new("20.6.1")
+            .msi()
+            .linux()
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::IncompatibleExt(e) if e == "msi (linux)"));
+
+        let err = NodeJSRelInfo::new("20.6.1").aix().validate().unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::IncompatibleArch(e) if e == "x64 (aix)"));
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(info.filename, "node-v20.6.1-linux-x64.tar.gz");
+        assert_eq!(
+            info.url,
+            format!(
+                "{}{}",
+                server.url(),
+                "/download/release/v20.6.1/node-v20.6.1-linux-x64.tar.gz"
+            )
+        );
+        assert_eq!(
+            info.sha256,
+            "26dd13a6f7253f0ab9bcab561353985a297d927840771d905566735b792868da"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires gpgv"]
+    #[cfg(feature = "gpg")]
+    async fn it_fetches_node_js_release_info_with_verified_signature() {
+        let mut info = NodeJSRelInfo::new("20.6.1")
+            .gpg_keyring(gpg::get_fake_keyring())
+            .to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+        let sig_mock = server
+            .mock(
+                "GET",
+                info.url_fmt.info_sig_pathname(&info.version).as_str(),
+            )
+            .with_body(gpg::get_fake_specs_sig())
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+        sig_mock.assert_async().await;
+
+        assert_eq!(info.filename, "node-v20.6.1-linux-x64.tar.gz");
+        assert_eq!(
+            info.sha256,
+            "26dd13a6f7253f0ab9bcab561353985a297d927840771d905566735b792868da"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires gpgv"]
+    #[cfg(feature = "gpg")]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidSignature(\"20.6.1\")"
+    )]
+    async fn it_fails_to_fetch_info_when_signature_is_invalid() {
+        let mut info = NodeJSRelInfo::new("20.6.1")
+            .gpg_keyring(gpg::get_fake_keyring())
+            .to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+        let sig_mock = server
+            .mock(
+                "GET",
+                info.url_fmt.info_sig_pathname(&info.version).as_str(),
+            )
+            .with_body("NOPE!")
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+        sig_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_downloads_node_js_distributable() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let specs_mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        specs_mock.assert_async().await;
+
+        let pkg_pathname = info.url_fmt.pkg_pathname(&info.version, &info.filename);
+        let pkg_mock = server
+            .mock("GET", pkg_pathname.as_str())
+            .with_body("FAKE TARBALL")
+            .create_async()
+            .await;
+
+        let dir = std::env::temp_dir();
+        let mut progress = vec![];
+        let path = info
+            .download_with_progress(&dir, |done, total| progress.push((done, total)))
+            .await
+            .unwrap();
+        pkg_mock.assert_async().await;
+
+        assert_eq!(path, dir.join("node-v20.6.1-linux-x64.tar.gz"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "FAKE TARBALL");
+        assert_eq!(progress.last(), Some(&(12, Some(12))));
+        std::fs::remove_file(&path).unwrap();
     }
 
-    #[test]
-    #[cfg_attr(not(target_os = "windows"), ignore)]
-    fn it_initializes_using_current_environment_on_windows() {
-        let info = NodeJSRelInfo::from_env("1.0.0").unwrap();
-        assert_eq!(info.ext, NodeJSPkgExt::Zip);
+    #[tokio::test]
+    async fn it_downloads_node_js_distributable_without_fetching() {
+        let mut info = NodeJSRelInfo::new("20.6.1").windows().zip().to_owned();
+        let mut server = Server::new_async().await;
+        info.url_fmt.host = server.host_with_port();
+        info.url_fmt.protocol = "http:".to_string();
+        let pkg_pathname = info.url_fmt.pkg_pathname(&info.version, info.filename());
+        let mock = server
+            .mock("GET", pkg_pathname.as_str())
+            .with_body("FAKE ZIP")
+            .create_async()
+            .await;
+
+        let dir = std::env::temp_dir();
+        let path = info.download(&dir).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(path, dir.join("node-v20.6.1-win-x64.zip"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "FAKE ZIP");
+        std::fs::remove_file(&path).unwrap();
     }
 
-    #[test]
-    fn it_sets_os() {
-        let mut info = NodeJSRelInfo::new("1.0.0");
+    #[tokio::test]
+    async fn it_downloads_windows_sub_path_artifact() {
+        let mut info = NodeJSRelInfo::new("20.6.1").exe().to_owned();
+        let mut server = Server::new_async().await;
+        info.url_fmt.host = server.host_with_port();
+        info.url_fmt.protocol = "http:".to_string();
+        let pkg_pathname = info.url_fmt.pkg_pathname(&info.version, info.filename());
+        let mock = server
+            .mock("GET", pkg_pathname.as_str())
+            .with_body("FAKE EXE")
+            .create_async()
+            .await;
 
-        assert_eq!(info.os, NodeJSOS::Linux);
+        let dir = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-sub-path",
+            std::process::id()
+        ));
+        let path = info.download(&dir).await.unwrap();
+        mock.assert_async().await;
 
-        info.windows();
+        assert_eq!(path, dir.join("win-x64/node.exe"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "FAKE EXE");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 
-        assert_eq!(info.os, NodeJSOS::Windows);
+    #[tokio::test]
+    async fn it_fails_to_download_when_filename_escapes_dir() {
+        let dir = std::env::temp_dir();
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        info.url = "http://127.0.0.1:1/node-v20.6.1.tar.gz".to_string();
 
-        info.macos();
+        for filename in [
+            "../node-v20.6.1.tar.gz",
+            "win-x64/../../node.exe",
+            "/tmp/node.exe",
+        ] {
+            info.filename = filename.to_string();
+            let err = info.download(&dir).await.unwrap_err();
+            assert!(
+                matches!(err, NodeJSRelInfoError::InvalidFilename(ref f) if f == filename),
+                "{}",
+                err
+            );
+        }
+    }
 
-        assert_eq!(info.os, NodeJSOS::Darwin);
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidVersion(\"NOPE!\")"
+    )]
+    async fn it_fails_to_download_when_version_is_invalid() {
+        let info = NodeJSRelInfo::new("NOPE!");
+        info.download(std::env::temp_dir()).await.unwrap();
+    }
 
-        info.linux();
+    #[cfg(feature = "verify")]
+    #[tokio::test]
+    async fn it_verifies_local_file_against_sha256() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let path = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-verify.tar.gz",
+            std::process::id()
+        ));
+        std::fs::write(&path, "FAKE TARBALL").unwrap();
 
-        assert_eq!(info.os, NodeJSOS::Linux);
+        info.sha256 =
+            "5070eae3abe756f1f3c98c8baa658f40a1e62451544ceed0cb1037782d80790a".to_string();
+        info.verify_file(&path).await.unwrap();
 
-        info.aix();
+        info.sha256 = specs::get_fake_specs()[..64].to_string();
+        let err = info.verify_file(&path).await.unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            format!("{err}"),
+            format!("Error: Invalid Checksum! Received: '{}'", path.display())
+        );
+    }
+
+    #[cfg(feature = "verify")]
+    #[tokio::test]
+    async fn it_fails_to_verify_local_file_when_sha256_is_unset() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let path = std::env::temp_dir().join(format!(
+            "node-js-release-info-{}-verify-unset.tar.gz",
+            std::process::id()
+        ));
+        std::fs::write(&path, "FAKE TARBALL").unwrap();
+
+        let err = info.verify_file(&path).await.unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::InvalidChecksum(ref h) if h.is_empty()));
+
+        info.sha256 = "NOPE!".to_string();
+        let err = info.verify_file(&path).await.unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, NodeJSRelInfoError::InvalidChecksum(ref h) if h == "NOPE!"));
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_when_kind_is_headers() {
+        let mut info = NodeJSRelInfo::new("20.6.1").headers().to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(info.filename, "node-v20.6.1-headers.tar.gz");
+        assert_eq!(
+            info.url,
+            format!(
+                "{}{}",
+                server.url(),
+                "/download/release/v20.6.1/node-v20.6.1-headers.tar.gz"
+            )
+        );
+        assert_eq!(
+            info.sha256,
+            "d8271461ced2887f65af413949caee19db3e80d22bbefdaf01252ca998570052"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_when_kind_is_source_or_pkg() {
+        let mut info = NodeJSRelInfo::new("20.6.1").source().tar_xz().to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .expect(2)
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+
+        assert_eq!(info.filename, "node-v20.6.1.tar.xz");
+        assert_eq!(
+            info.sha256,
+            "3aec5e728daa38800c343b129221d3488064a2529a39bb5467bc55be226c6a2b"
+        );
+
+        info.pkg().fetch().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(info.filename, "node-v20.6.1.pkg");
+        assert_eq!(
+            info.sha256,
+            "d9acf82d9576dd0350c8e66b55f6fc2750fa9f4aa23d6453ffc58e32af995894"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_when_kind_is_windows_sub_path() {
+        let mut info = NodeJSRelInfo::new("20.6.1").exe().arm64().to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .expect(3)
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+
+        assert_eq!(info.filename, "win-arm64/node.exe");
+        assert_eq!(
+            info.url,
+            format!(
+                "{}{}",
+                server.url(),
+                "/download/release/v20.6.1/win-arm64/node.exe"
+            )
+        );
+        assert_eq!(
+            info.sha256,
+            "7cc3240fd7ce7926eef1cbbad33b033f7c5d97b3f3e527d65ff1e2c3f7638a11"
+        );
+
+        info.lib().x64().fetch().await.unwrap();
+
+        assert_eq!(info.filename, "win-x64/node.lib");
+        assert_eq!(
+            info.sha256,
+            "d275cfc4d637d2feaf4c39e1a5f5cd84f5b474fa713c15013e940c329feed13b"
+        );
+
+        info.pdb().x86().s7z().fetch().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(info.filename, "win-x86/node_pdb.7z");
+        assert_eq!(
+            info.sha256,
+            "516ac820f05eb8478be541ac12386c3b5b5c07624f73934bcf0b11a3fcdb1c95"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_when_ext_is_msi() {
+        let mut info = NodeJSRelInfo::new("20.6.1").arm64().msi().to_owned();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+
+        info.fetch().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(info.filename, "node-v20.6.1-arm64.msi");
+        assert_eq!(
+            info.url,
+            format!(
+                "{}{}",
+                server.url(),
+                "/download/release/v20.6.1/node-v20.6.1-arm64.msi"
+            )
+        );
+        assert_eq!(
+            info.sha256,
+            "9471bd6dc491e09c31b0f831f5953284b8a6842ed4ccb98f5c62d13e6086c471"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_for_many_versions() {
+        let mut info = NodeJSRelInfo::default().macos().arm64().to_owned();
+        let mut server = Server::new_async().await;
+        let mock1 = specs::setup_server_mock("20.6.1", &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .expect(1)
+            .create_async()
+            .await;
+        let mock2 = specs::setup_server_mock("20.7.0", &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs().replace("20.6.1", "20.7.0"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let many = info
+            .fetch_many(&["20.6.1", "20.7.0", "20.6.1"])
+            .await
+            .unwrap();
+        mock1.assert_async().await;
+        mock2.assert_async().await;
+
+        assert_eq!(many.len(), 2);
+        assert_eq!(many["20.6.1"].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+        assert_eq!(
+            many["20.6.1"].sha256,
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
+        );
+        assert_eq!(many["20.7.0"].filename, "node-v20.7.0-darwin-arm64.tar.gz");
+        assert_eq!(many["20.7.0"].os, NodeJSOS::Darwin);
+        assert_eq!(many["20.7.0"].arch, NodeJSArch::ARM64);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidVersion(\"NOPE!\")"
+    )]
+    async fn it_fails_to_fetch_node_js_release_info_for_many_versions_when_version_is_invalid() {
+        let info = NodeJSRelInfo::default();
+        info.fetch_many(&["20.6.1", "NOPE!"]).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"1.0.0\")"
+    )]
+    async fn it_fails_to_fetch_node_js_release_info_for_many_versions_when_version_is_unrecognized()
+    {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock("1.0.0", &mut info.url_fmt, &mut server)
+            .with_status(404)
+            .create_async()
+            .await;
+
+        info.fetch_many(&["1.0.0"]).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_fetches_all_supported_node_js_configurations() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
+
+        let all = info.fetch_all().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(all.len(), 41);
+        assert_eq!(all[2].version, "20.6.1");
+        assert_eq!(all[2].os, NodeJSOS::Darwin);
+        assert_eq!(all[2].arch, NodeJSArch::ARM64);
+        assert_eq!(all[2].ext, NodeJSPkgExt::Targz);
+        assert_eq!(all[2].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+        assert_eq!(
+            all[2].sha256,
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
+        );
+        assert_eq!(
+            all[2].url,
+            format!(
+                "{}/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz",
+                server.url()
+            )
+        );
+    }
+
+    #[test]
+    fn it_initializes_from_shasums() {
+        let mut info = NodeJSRelInfo::new("20.6.1").linux().tar_xz().to_owned();
+        let info = info.from_shasums(specs::get_fake_specs()).unwrap();
+
+        assert_eq!(info.filename, "node-v20.6.1-linux-x64.tar.xz");
+        assert_eq!(
+            info.sha256,
+            "591f9f274104f266a8cf085d2c7d5d2848ba73b98ae323d501db2d4c4b7026e5"
+        );
+        assert_eq!(
+            info.url,
+            "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-linux-x64.tar.xz"
+        );
+    }
+
+    #[test]
+    fn it_fails_to_initialize_from_shasums_when_configuration_is_unrecognized() {
+        let mut info = NodeJSRelInfo::new("20.6.1").linux().zip().to_owned();
+        let err = info.from_shasums(specs::get_fake_specs()).unwrap_err();
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::UnrecognizedConfiguration(f) if f == "node-v20.6.1-linux-x64.zip"
+        ));
+
+        let mut info = NodeJSRelInfo::new("NOPE");
+        let err = info.from_shasums(specs::get_fake_specs()).unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::InvalidVersion(_)));
+    }
+
+    #[test]
+    fn it_creates_all_supported_node_js_configurations_from_shasums() {
+        let all = NodeJSRelInfo::fetch_all_from_shasums("20.6.1", specs::get_fake_specs()).unwrap();
+
+        assert_eq!(all.len(), 41);
+        assert_eq!(all[2].version, "20.6.1");
+        assert_eq!(all[2].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+        assert_eq!(
+            all[2].url,
+            "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
+        );
 
-        assert_eq!(info.os, NodeJSOS::AIX);
+        let err =
+            NodeJSRelInfo::fetch_all_from_shasums("1.0.0", specs::get_fake_specs()).unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::UnrecognizedVersion(v) if v == "1.0.0"));
     }
 
-    #[test]
-    fn it_sets_arch() {
-        let mut info = NodeJSRelInfo::new("1.0.0");
-
-        info.x86();
+    #[tokio::test]
+    async fn it_fetches_node_js_release_info_for_configurations_matching_predicate() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs())
+            .create_async()
+            .await;
 
-        assert_eq!(info.arch, NodeJSArch::X86);
+        let all = info
+            .fetch_all_where(|kind, os, arch, _| {
+                *kind == NodeJSArtifactKind::Binary
+                    && *os == NodeJSOS::Windows
+                    && *arch == NodeJSArch::X86
+            })
+            .await
+            .unwrap();
+        mock.assert_async().await;
 
-        info.x64();
+        let filenames: Vec<&str> = all.iter().map(|i| i.filename.as_str()).collect();
+        assert_eq!(
+            filenames,
+            vec![
+                "node-v20.6.1-win-x86.7z",
+                "node-v20.6.1-win-x86.zip",
+                "node-v20.6.1-x86.msi"
+            ]
+        );
+    }
 
-        assert_eq!(info.arch, NodeJSArch::X64);
+    #[tokio::test]
+    async fn it_fetches_node_js_release_metadata() {
+        let mut info = NodeJSRelInfo::new("18.18.0");
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        info.arm64();
+        info.fetch_metadata().await.unwrap();
+        mock.assert_async().await;
 
-        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.date, Some("2023-09-18".to_string()));
+        assert_eq!(info.npm, Some("9.8.1".to_string()));
+        assert_eq!(info.v8, Some("10.2.154.26".to_string()));
+        assert_eq!(info.lts, Some("Hydrogen".to_string()));
+        assert!(!info.is_security_release());
+    }
 
-        info.armv7l();
+    #[tokio::test]
+    async fn it_labels_lts_and_current_releases_after_fetching_metadata() {
+        let mut lts = NodeJSRelInfo::new("18.18.0");
+        let mut current = NodeJSRelInfo::new("20.6.1");
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut lts.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .expect(2)
+            .create_async()
+            .await;
+        current.url_fmt = lts.url_fmt.clone();
 
-        assert_eq!(info.arch, NodeJSArch::ARMV7L);
+        assert!(!lts.is_lts());
+        assert!(!lts.is_current());
+        assert!(!current.is_current());
 
-        info.ppc64();
+        lts.fetch_metadata().await.unwrap();
+        current.fetch_metadata().await.unwrap();
+        mock.assert_async().await;
 
-        assert_eq!(info.arch, NodeJSArch::PPC64);
+        assert!(lts.is_lts());
+        assert!(!lts.is_current());
+        assert_eq!(lts.lts_codename(), Some("Hydrogen"));
+        assert!(!current.is_lts());
+        assert!(current.is_current());
+        assert_eq!(current.lts_codename(), None);
+    }
 
-        info.ppc64le();
+    #[tokio::test]
+    async fn it_fetches_node_js_release_metadata_for_security_release() {
+        let mut info = NodeJSRelInfo::new("18.17.1");
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        assert_eq!(info.arch, NodeJSArch::PPC64LE);
+        assert!(!info.is_security_release());
 
-        info.s390x();
+        info.fetch_metadata().await.unwrap();
+        mock.assert_async().await;
 
-        assert_eq!(info.arch, NodeJSArch::S390X);
+        assert!(info.security);
+        assert!(info.is_security_release());
     }
 
-    #[test]
-    fn it_sets_ext() {
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"1.0.0\")"
+    )]
+    async fn it_fails_to_fetch_node_js_release_metadata_when_version_is_unrecognized() {
         let mut info = NodeJSRelInfo::new("1.0.0");
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        info.zip();
-
-        assert_eq!(info.ext, NodeJSPkgExt::Zip);
-
-        info.tar_gz();
+        info.fetch_metadata().await.unwrap();
+        mock.assert_async().await;
+    }
 
-        assert_eq!(info.ext, NodeJSPkgExt::Targz);
+    #[tokio::test]
+    async fn it_fetches_node_js_release_schedule() {
+        let mut info = NodeJSRelInfo::new("16.20.2");
+        let mut server = Server::new_async().await;
+        let mock = schedule::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(schedule::get_fake_schedule())
+            .create_async()
+            .await;
 
-        info.tar_xz();
+        assert!(!info.is_eol());
+        assert_eq!(info.eol_date(), None);
+        assert_eq!(info.support_phase(), None);
 
-        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+        info.fetch_schedule().await.unwrap();
+        mock.assert_async().await;
 
-        info.msi();
+        assert!(info.is_eol());
+        assert_eq!(info.eol_date(), Some("2023-09-11"));
+        assert_eq!(info.support_phase(), Some(NodeJSSupportPhase::EndOfLife));
+        assert_eq!(info.schedule.unwrap().codename, Some("Gallium".to_string()));
+    }
 
-        assert_eq!(info.ext, NodeJSPkgExt::Msi);
+    #[tokio::test]
+    async fn it_fetches_latest_lts_node_js_release() {
+        let mut info = NodeJSRelInfo::default().macos().arm64().to_owned();
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        info.s7z();
+        info.fetch_latest_lts().await.unwrap();
+        mock.assert_async().await;
 
-        assert_eq!(info.ext, NodeJSPkgExt::S7z);
+        assert_eq!(info.version, "18.18.0");
+        assert_eq!(info.os, NodeJSOS::Darwin);
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.lts, Some("Hydrogen".to_string()));
+        assert_eq!(info.date, Some("2023-09-18".to_string()));
     }
 
-    #[test]
-    fn it_gets_owned_copy() {
-        let mut info1 = NodeJSRelInfo::new("1.0.0");
-        let info2 = info1.to_owned();
-
-        assert_eq!(info1, info2);
+    #[tokio::test]
+    async fn it_fetches_latest_node_js_release_for_named_lts_line() {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        info1.windows();
+        info.fetch_latest_lts_named("gallium").await.unwrap();
+        mock.assert_async().await;
 
-        assert_ne!(info1, info2);
+        assert_eq!(info.version, "16.20.2");
+        assert_eq!(info.lts, Some("Gallium".to_string()));
+        assert!(info.security);
     }
 
-    #[test]
-    fn it_formats_filename() {
-        let info = NodeJSRelInfo::new("1.0.0").macos().x64().zip().to_owned();
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"NOPE\")"
+    )]
+    async fn it_fails_to_fetch_latest_node_js_release_when_lts_line_is_unrecognized() {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        assert_eq!(info.filename(), "node-v1.0.0-darwin-x64.zip");
+        info.fetch_latest_lts_named("NOPE").await.unwrap();
+        mock.assert_async().await;
+    }
 
-        let info = NodeJSRelInfo::new("1.0.0").windows().x64().msi().to_owned();
+    #[tokio::test]
+    async fn it_fetches_latest_node_js_release_within_major_version() {
+        let mut info = NodeJSRelInfo::default().windows().to_owned();
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
 
-        assert_eq!(info.filename(), "node-v1.0.0-x64.msi");
-    }
+        info.fetch_latest_in_major(20).await.unwrap();
+        mock.assert_async().await;
 
-    #[test]
-    fn it_serializes_and_deserializes() {
-        let version = "20.6.1".to_string();
-        let filename = "node-v20.6.1-darwin-arm64.tar.gz".to_string();
-        let sha256 = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string();
-        let url = "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
-            .to_string();
-        let info_orig = NodeJSRelInfo {
-            os: NodeJSOS::Darwin,
-            arch: NodeJSArch::ARM64,
-            ext: NodeJSPkgExt::Targz,
-            version: version.clone(),
-            filename: filename.clone(),
-            sha256: sha256.clone(),
-            url: url.clone(),
-            ..Default::default()
-        };
-        let info_json = serde_json::to_string(&info_orig).unwrap();
-        let info: NodeJSRelInfo = serde_json::from_str(&info_json).unwrap();
-        assert_eq!(info.os, NodeJSOS::Darwin);
-        assert_eq!(info.arch, NodeJSArch::ARM64);
-        assert_eq!(info.ext, NodeJSPkgExt::Targz);
-        assert_eq!(info.version, "20.6.1".to_string());
-        assert_eq!(
-            info.filename,
-            "node-v20.6.1-darwin-arm64.tar.gz".to_string()
-        );
-        assert_eq!(
-            info.sha256,
-            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46".to_string()
-        );
-        assert_eq!(
-            info.url,
-            "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
-                .to_string()
-        );
+        assert_eq!(info.version, "20.7.0");
+        assert_eq!(info.os, NodeJSOS::Windows);
+        assert_eq!(info.npm, Some("10.1.0".to_string()));
     }
 
     #[tokio::test]
     #[should_panic(
-        expected = "called `Result::unwrap()` on an `Err` value: InvalidVersion(\"NOPE!\")"
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"99.x\")"
     )]
-    async fn it_fails_to_fetch_info_when_version_is_invalid() {
-        let mut info = NodeJSRelInfo::new("NOPE!");
-        info.fetch().await.unwrap();
+    async fn it_fails_to_fetch_latest_node_js_release_when_major_version_is_unrecognized() {
+        let mut info = NodeJSRelInfo::default();
+        let mut server = Server::new_async().await;
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
+            .create_async()
+            .await;
+
+        info.fetch_latest_in_major(99).await.unwrap();
+        mock.assert_async().await;
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"1.0.0\")"
-    )]
-    async fn it_fails_to_fetch_info_when_version_is_unrecognized() {
-        let mut info = NodeJSRelInfo::new("1.0.0");
+    async fn it_fetches_all_published_node_js_versions() {
+        let mut info = NodeJSRelInfo::default();
         let mut server = Server::new_async().await;
-        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
-            .with_body(specs::get_fake_specs())
-            .with_status(404)
+        let mock = index::setup_server_mock(&mut info.url_fmt, &mut server)
+            .with_body(index::get_fake_index())
             .create_async()
             .await;
 
-        info.fetch().await.unwrap();
+        let versions = info.fetch_versions().await.unwrap();
         mock.assert_async().await;
+
+        assert_eq!(versions.len(), 5);
+        assert_eq!(versions[1].version, "20.6.1");
+        assert_eq!(versions[1].date, "2023-09-08");
+        assert_eq!(versions[1].lts, None);
+        assert!(!versions[1].security);
+        assert_eq!(versions[4].version, "16.20.2");
+        assert_eq!(versions[4].lts, Some("Gallium".to_string()));
+        assert!(versions[4].security);
     }
 
     #[tokio::test]
-    #[should_panic(
-        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedConfiguration(\"node-v20.6.1-linux-x64.zip\")"
-    )]
-    async fn it_fails_to_fetch_info_when_configuration_is_unrecognized() {
+    async fn it_streams_matrix_of_versions_and_configurations() {
+        let mut info = NodeJSRelInfo::default();
         let mut server = Server::new_async().await;
-        let mut info = NodeJSRelInfo::new("20.6.1").linux().zip().to_owned();
-        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+        let mock1 = specs::setup_server_mock("20.6.1", &mut info.url_fmt, &mut server)
             .with_body(specs::get_fake_specs())
+            .expect(1)
+            .create_async()
+            .await;
+        let mock2 = specs::setup_server_mock("20.7.0", &mut info.url_fmt, &mut server)
+            .with_body(specs::get_fake_specs().replace("20.6.1", "20.7.0"))
+            .expect(1)
             .create_async()
             .await;
 
-        info.fetch().await.unwrap();
-        mock.assert_async().await;
+        let matrix: Vec<NodeJSRelInfo> = info
+            .fetch_matrix(&["20.7.0", "20.6.1", "20.7.0"])
+            .try_collect()
+            .await
+            .unwrap();
+        mock1.assert_async().await;
+        mock2.assert_async().await;
+
+        assert_eq!(matrix.len(), 82);
+        assert!(matrix[..41].iter().all(|i| i.version == "20.7.0"));
+        assert!(matrix[41..].iter().all(|i| i.version == "20.6.1"));
+        assert_eq!(matrix[41].filename, "node-v20.6.1-aix-ppc64.tar.gz");
+        let darwin = matrix
+            .iter()
+            .find(|i| i.filename == "node-v20.6.1-darwin-arm64.tar.gz")
+            .unwrap();
+        assert_eq!(
+            darwin.sha256,
+            "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
+        );
     }
 
     #[tokio::test]
-    async fn it_fetches_node_js_release_info() {
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidVersion(\"NOPE!\")"
+    )]
+    async fn it_fails_to_stream_matrix_when_version_is_invalid() {
+        let info = NodeJSRelInfo::default();
+        let _: Vec<NodeJSRelInfo> = info.fetch_matrix(&["NOPE!"]).try_collect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_fetches_all_supported_node_js_configurations_in_strict_mode() {
         let mut info = NodeJSRelInfo::new("20.6.1");
         let mut server = Server::new_async().await;
         let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
@@ -645,53 +4013,41 @@ mod tests {
             .create_async()
             .await;
 
-        info.fetch().await.unwrap();
+        let all = info.strict().fetch_all().await.unwrap();
         mock.assert_async().await;
 
-        assert_eq!(info.filename, "node-v20.6.1-linux-x64.tar.gz");
-        assert_eq!(
-            info.url,
-            format!(
-                "{}{}",
-                server.url(),
-                "/download/release/v20.6.1/node-v20.6.1-linux-x64.tar.gz"
-            )
-        );
-        assert_eq!(
-            info.sha256,
-            "26dd13a6f7253f0ab9bcab561353985a297d927840771d905566735b792868da"
-        );
+        assert_eq!(all.len(), 41);
+        assert!(all.iter().all(|i| i.strict));
     }
 
     #[tokio::test]
-    async fn it_fetches_node_js_release_info_when_ext_is_msi() {
-        let mut info = NodeJSRelInfo::new("20.6.1").arm64().msi().to_owned();
+    async fn it_fails_to_fetch_all_when_artifacts_are_unrecognized_in_strict_mode() {
+        let mut info = NodeJSRelInfo::new("20.6.1");
         let mut server = Server::new_async().await;
+        let specs = format!(
+            "{}\nabc123  node-v20.6.1-linux-wasm32.tar.gz",
+            specs::get_fake_specs()
+        );
         let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
-            .with_body(specs::get_fake_specs())
+            .with_body(specs)
+            .expect(2)
             .create_async()
             .await;
 
-        info.fetch().await.unwrap();
+        let all = info.fetch_all().await.unwrap();
+        assert_eq!(all.len(), 41);
+
+        let err = info.strict().fetch_all().await.unwrap_err();
         mock.assert_async().await;
 
-        assert_eq!(info.filename, "node-v20.6.1-arm64.msi");
-        assert_eq!(
-            info.url,
-            format!(
-                "{}{}",
-                server.url(),
-                "/download/release/v20.6.1/node-v20.6.1-arm64.msi"
-            )
-        );
-        assert_eq!(
-            info.sha256,
-            "9471bd6dc491e09c31b0f831f5953284b8a6842ed4ccb98f5c62d13e6086c471"
-        );
+        assert!(matches!(
+            err,
+            NodeJSRelInfoError::UnrecognizedArtifacts(a) if a == vec!["node-v20.6.1-linux-wasm32.tar.gz"]
+        ));
     }
 
     #[tokio::test]
-    async fn it_fetches_all_supported_node_js_configurations() {
+    async fn it_streams_all_supported_node_js_configurations() {
         let mut info = NodeJSRelInfo::new("20.6.1");
         let mut server = Server::new_async().await;
         let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
@@ -699,23 +4055,40 @@ mod tests {
             .create_async()
             .await;
 
-        let all = info.fetch_all().await.unwrap();
+        let mut stream = Box::pin(info.fetch_all_stream());
+        let first = stream.try_next().await.unwrap().unwrap();
+
+        assert_eq!(first.filename, "node-v20.6.1-aix-ppc64.tar.gz");
+
+        let rest: Vec<NodeJSRelInfo> = stream.try_collect().await.unwrap();
         mock.assert_async().await;
 
-        assert_eq!(all.len(), 24);
-        assert_eq!(all[2].version, "20.6.1");
-        assert_eq!(all[2].os, NodeJSOS::Darwin);
-        assert_eq!(all[2].arch, NodeJSArch::ARM64);
-        assert_eq!(all[2].ext, NodeJSPkgExt::Targz);
-        assert_eq!(all[2].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+        assert_eq!(rest.len(), 40);
+        assert_eq!(rest[1].os, NodeJSOS::Darwin);
+        assert_eq!(rest[1].arch, NodeJSArch::ARM64);
+        assert_eq!(rest[1].filename, "node-v20.6.1-darwin-arm64.tar.gz");
         assert_eq!(
-            all[2].sha256,
+            rest[1].sha256,
             "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
         );
-        assert_eq!(
-            all[2].url,
-            "https://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
-        );
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"1.0.0\")"
+    )]
+    async fn it_fails_to_stream_all_supported_node_js_configurations_when_version_is_unrecognized()
+    {
+        let mut info = NodeJSRelInfo::new("1.0.0");
+        let mut server = Server::new_async().await;
+        let mock = specs::setup_server_mock(&info.version, &mut info.url_fmt, &mut server)
+            .with_body(String::from(""))
+            .create_async()
+            .await;
+
+        let all: Vec<NodeJSRelInfo> = info.fetch_all_stream().try_collect().await.unwrap();
+        mock.assert_async().await;
+        assert!(all.is_empty());
     }
 
     #[tokio::test]
@@ -0,0 +1,291 @@
+use node_js_release_info::{
+    NodeJSArch, NodeJSArtifactKind, NodeJSOS, NodeJSPkgExt, NodeJSRelInfo, NodeJSRelInfoCollection,
+    NodeJSRelInfoFormat,
+};
+use std::env;
+use std::error::Error;
+use std::str::FromStr;
+
+type DynError = Box<dyn Error>;
+
+const USAGE: &str = "Usage: node-js-release-info <version> [options]
+
+Options:
+  --os <os>        Operating system (linux, darwin / macos, windows / win, aix) - defaults to host
+  --arch <arch>    CPU architecture (x64, x86, arm64, armv7l, ppc64, ppc64le, s390x, ...) - defaults to host
+  --ext <ext>      File extension (tar.gz, tar.xz, zip, 7z, msi) - defaults to zip on windows, tar.gz otherwise
+  --kind <kind>    Artifact kind (binary, headers, source, pkg, exe, lib, pdb)
+  --all            List all supported configurations (narrowed by --os, --arch, and --ext)
+  --json           Print results as json
+  -h, --help       Print this message";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Options {
+    version: String,
+    os: Option<NodeJSOS>,
+    arch: Option<NodeJSArch>,
+    ext: Option<NodeJSPkgExt>,
+    kind: Option<NodeJSArtifactKind>,
+    all: bool,
+    json: bool,
+    help: bool,
+}
+
+impl Options {
+    fn new(args: Vec<String>) -> Result<Self, DynError> {
+        let mut opts = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--os" => opts.os = Some(NodeJSOS::from_str(&value(&arg, args.next())?)?),
+                "--arch" => opts.arch = Some(NodeJSArch::from_str(&value(&arg, args.next())?)?),
+                "--ext" => opts.ext = Some(NodeJSPkgExt::from_str(&value(&arg, args.next())?)?),
+                "--kind" => {
+                    opts.kind = Some(NodeJSArtifactKind::from_str(&value(&arg, args.next())?)?)
+                }
+                "--all" => opts.all = true,
+                "--json" => opts.json = true,
+                "-h" | "--help" => opts.help = true,
+                _ if arg.starts_with('-') || !opts.version.is_empty() => {
+                    return Err(format!("Unrecognized argument! Received: '{}'", arg).into());
+                }
+                _ => opts.version = arg,
+            }
+        }
+
+        if opts.version.is_empty() && !opts.help {
+            return Err(format!("Missing version!\n\n{}", USAGE).into());
+        }
+
+        Ok(opts)
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, DynError> {
+    match value {
+        Some(v) if !v.starts_with('-') => Ok(v),
+        _ => Err(format!("Missing value for '{}'!", flag).into()),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = try_main().await {
+        eprintln!("{}", e);
+
+        let mut source = e.source();
+
+        while let Some(cause) = source {
+            eprintln!("  Caused by: {}", cause);
+            source = cause.source();
+        }
+
+        std::process::exit(1);
+    }
+}
+
+async fn try_main() -> Result<(), DynError> {
+    let mut args: Vec<String> = env::args().collect();
+
+    args.remove(0); // drop executable path
+
+    let opts = Options::new(args)?;
+
+    if opts.help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    if opts.all {
+        let mut all = NodeJSRelInfo::new(&opts.version).fetch_all().await?;
+
+        if let Some(kind) = opts.kind {
+            all = all.for_kind(kind);
+        }
+
+        if let Some(os) = opts.os {
+            all = all.for_os(os);
+        }
+
+        if let Some(arch) = opts.arch {
+            all = all.for_arch(arch);
+        }
+
+        if let Some(ext) = opts.ext {
+            all = all.for_ext(ext);
+        }
+
+        return print_all(&all, opts.json);
+    }
+
+    let info = configure(&opts)?.fetch().await?;
+
+    match opts.json {
+        true => println!("{}", info.to_string_pretty(NodeJSRelInfoFormat::Json)?),
+        false => println!("{}", line(&info)),
+    }
+
+    Ok(())
+}
+
+// resolves the requested configuration - host os / arch unless overridden, and
+// the os' usual archive format (same as `NodeJSRelInfo::from_env()`) unless
+// `--ext` is provided
+fn configure(opts: &Options) -> Result<NodeJSRelInfo, DynError> {
+    let os = match opts.os.clone() {
+        Some(os) => os,
+        None => NodeJSOS::from_env()?,
+    };
+
+    let arch = match opts.arch.clone() {
+        Some(arch) => arch,
+        None => NodeJSArch::from_env()?,
+    };
+
+    let ext = match (opts.ext.clone(), &os) {
+        (Some(ext), _) => ext,
+        (None, NodeJSOS::Windows) => NodeJSPkgExt::Zip,
+        (None, _) => NodeJSPkgExt::Targz,
+    };
+
+    let mut builder = NodeJSRelInfo::builder()
+        .version(&opts.version)
+        .os(os)
+        .arch(arch)
+        .ext(ext);
+
+    if let Some(kind) = opts.kind.clone() {
+        builder = builder.kind(kind);
+    }
+
+    Ok(builder.build()?)
+}
+
+fn print_all(all: &NodeJSRelInfoCollection, json: bool) -> Result<(), DynError> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(all)?);
+        return Ok(());
+    }
+
+    for info in all {
+        println!("{}", line(info));
+    }
+
+    Ok(())
+}
+
+// tab-separated `filename`, `sha256`, and `url` for easy use w/ `cut`, `awk`, etc
+fn line(info: &NodeJSRelInfo) -> String {
+    format!("{}\t{}\t{}", info.filename, info.sha256, info.url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn it_parses_version() {
+        let opts = Options::new(to_args(&["20.6.1"])).unwrap();
+        assert_eq!(
+            opts,
+            Options {
+                version: "20.6.1".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_parses_options() {
+        let args = to_args(&[
+            "--os", "macos", "20.6.1", "--arch", "arm64", "--ext", "tar.xz", "--kind", "binary",
+            "--json",
+        ]);
+        let opts = Options::new(args).unwrap();
+        assert_eq!(opts.version, "20.6.1");
+        assert_eq!(opts.os, Some(NodeJSOS::Darwin));
+        assert_eq!(opts.arch, Some(NodeJSArch::ARM64));
+        assert_eq!(opts.ext, Some(NodeJSPkgExt::Tarxz));
+        assert_eq!(opts.kind, Some(NodeJSArtifactKind::Binary));
+        assert!(opts.json);
+        assert!(!opts.all);
+    }
+
+    #[test]
+    fn it_parses_flags() {
+        let opts = Options::new(to_args(&["20.6.1", "--all"])).unwrap();
+        assert!(opts.all);
+
+        let opts = Options::new(to_args(&["--help"])).unwrap();
+        assert!(opts.help);
+    }
+
+    #[test]
+    fn it_fails_when_version_is_missing() {
+        let err = Options::new(to_args(&["--json"])).unwrap_err();
+        assert!(err.to_string().starts_with("Missing version!"));
+    }
+
+    #[test]
+    fn it_fails_when_argument_is_unrecognized() {
+        let err = Options::new(to_args(&["20.6.1", "--nope"])).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized argument! Received: '--nope'");
+
+        let err = Options::new(to_args(&["20.6.1", "20.6.2"])).unwrap_err();
+        assert_eq!(err.to_string(), "Unrecognized argument! Received: '20.6.2'");
+    }
+
+    #[test]
+    fn it_fails_when_option_value_is_missing() {
+        let err = Options::new(to_args(&["20.6.1", "--os"])).unwrap_err();
+        assert_eq!(err.to_string(), "Missing value for '--os'!");
+
+        let err = Options::new(to_args(&["20.6.1", "--arch", "--json"])).unwrap_err();
+        assert_eq!(err.to_string(), "Missing value for '--arch'!");
+    }
+
+    #[test]
+    fn it_fails_when_option_value_is_unrecognized() {
+        let err = Options::new(to_args(&["20.6.1", "--os", "nope"])).unwrap_err();
+        assert_eq!(err.to_string(), "Error: Unrecognized OS! Received: 'nope'");
+    }
+
+    #[test]
+    fn it_configures_release_info() {
+        let args = to_args(&[
+            "20.6.1", "--os", "linux", "--arch", "arm64", "--ext", "tar.xz",
+        ]);
+        let info = configure(&Options::new(args).unwrap()).unwrap();
+        assert_eq!(info.os, NodeJSOS::Linux);
+        assert_eq!(info.arch, NodeJSArch::ARM64);
+        assert_eq!(info.ext, NodeJSPkgExt::Tarxz);
+    }
+
+    #[test]
+    fn it_defaults_ext_based_on_os() {
+        let args = to_args(&["20.6.1", "--os", "windows", "--arch", "x64"]);
+        let info = configure(&Options::new(args).unwrap()).unwrap();
+        assert_eq!(info.filename(), "node-v20.6.1-win-x64.zip");
+
+        let args = to_args(&["20.6.1", "--os", "macos", "--arch", "arm64"]);
+        let info = configure(&Options::new(args).unwrap()).unwrap();
+        assert_eq!(info.filename(), "node-v20.6.1-darwin-arm64.tar.gz");
+    }
+
+    #[test]
+    fn it_formats_line() {
+        let mut info = NodeJSRelInfo::new("20.6.1").macos().arm64().to_owned();
+        info.filename = info.filename();
+        info.sha256 = "abc123".to_string();
+        info.url = info.url();
+        assert_eq!(
+            line(&info),
+            "node-v20.6.1-darwin-arm64.tar.gz\tabc123\thttps://nodejs.org/download/release/v20.6.1/node-v20.6.1-darwin-arm64.tar.gz"
+        );
+    }
+}
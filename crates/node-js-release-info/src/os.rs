@@ -30,10 +30,43 @@ impl NodeJSOS {
     }
 
     pub fn from_env() -> Result<NodeJSOS, NodeJSRelInfoError> {
-        NodeJSOS::from_str(OS)
+        NodeJSOS::from_host(OS, is_musl())
+    }
+
+    // official Linux builds link against glibc so a musl host can't run them
+    fn from_host(os: &str, musl: bool) -> Result<NodeJSOS, NodeJSRelInfoError> {
+        let os = NodeJSOS::from_str(os)?;
+
+        if os == NodeJSOS::Linux && musl {
+            return Err(NodeJSRelInfoError::UnsupportedLibc("musl".to_string()));
+        }
+
+        Ok(os)
     }
 }
 
+// built against musl or running on a host whose dynamic loader is musl's
+// (`/lib/ld-musl-{arch}.so.1` exists) - e.g. Alpine
+fn is_musl() -> bool {
+    if cfg!(target_env = "musl") {
+        return true;
+    }
+
+    #[cfg(target_os = "linux")]
+    if let Ok(entries) = std::fs::read_dir("/lib") {
+        return entries
+            .flatten()
+            .any(|entry| is_musl_loader(&entry.file_name().to_string_lossy()));
+    }
+
+    false
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn is_musl_loader(filename: &str) -> bool {
+    filename.starts_with("ld-musl-") && filename.ends_with(".so.1")
+}
+
 impl Display for NodeJSOS {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let os = match self {
@@ -128,6 +161,37 @@ mod tests {
         NodeJSOS::from_env().unwrap();
     }
 
+    #[test]
+    fn it_initializes_from_host() {
+        let os = NodeJSOS::from_host("linux", false).unwrap();
+        assert_eq!(os, NodeJSOS::Linux);
+
+        let os = NodeJSOS::from_host("macos", true).unwrap();
+        assert_eq!(os, NodeJSOS::Darwin);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnsupportedLibc(\"musl\")"
+    )]
+    fn it_fails_when_host_uses_musl() {
+        NodeJSOS::from_host("linux", true).unwrap();
+    }
+
+    #[test]
+    fn it_recognizes_musl_loader() {
+        assert!(is_musl_loader("ld-musl-x86_64.so.1"));
+        assert!(is_musl_loader("ld-musl-aarch64.so.1"));
+        assert!(!is_musl_loader("ld-linux-x86-64.so.2"));
+        assert!(!is_musl_loader("libc.musl-x86_64.so.1"));
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    fn it_does_not_detect_musl_on_glibc_host() {
+        assert!(!is_musl());
+    }
+
     #[test]
     #[should_panic(
         expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedOs(\"NOPE!\")"
@@ -0,0 +1,364 @@
+use crate::error::NodeJSRelInfoError;
+use crate::http;
+use crate::url::NodeJSURLFormatter;
+use semver::Version;
+use serde::Deserialize;
+#[cfg(feature = "json")]
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "json", derive(Deserialize, Serialize))]
+pub enum NodeJSSupportPhase {
+    /// The release line has been scheduled but not yet released
+    #[cfg_attr(feature = "json", serde(rename = "pending"))]
+    Pending,
+    /// The release line is the current release and receives active development
+    #[cfg_attr(feature = "json", serde(rename = "current"))]
+    Current,
+    /// The release line is in active long term support
+    #[cfg_attr(feature = "json", serde(rename = "active-lts"))]
+    ActiveLTS,
+    /// The release line only receives critical bug fixes and security updates
+    #[cfg_attr(feature = "json", serde(rename = "maintenance"))]
+    Maintenance,
+    /// The release line is no longer supported
+    #[cfg_attr(feature = "json", serde(rename = "end-of-life"))]
+    EndOfLife,
+}
+
+impl Display for NodeJSSupportPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let phase = match self {
+            NodeJSSupportPhase::Pending => "pending",
+            NodeJSSupportPhase::Current => "current",
+            NodeJSSupportPhase::ActiveLTS => "active-lts",
+            NodeJSSupportPhase::Maintenance => "maintenance",
+            NodeJSSupportPhase::EndOfLife => "end-of-life",
+        };
+
+        write!(f, "{}", phase)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[cfg_attr(feature = "json", derive(Serialize))]
+pub struct NodeJSReleaseSchedule {
+    /// The date the release line was first released (`YYYY-MM-DD`)
+    pub start: String,
+    /// The date the release line entered active long term support (`YYYY-MM-DD`)
+    pub lts: Option<String>,
+    /// The date the release line entered maintenance (`YYYY-MM-DD`)
+    pub maintenance: Option<String>,
+    /// The date the release line reaches end-of-life (`YYYY-MM-DD`)
+    pub end: String,
+    /// The LTS codename (e.g. `Hydrogen`) for the release line
+    pub codename: Option<String>,
+}
+
+impl NodeJSReleaseSchedule {
+    /// Determines the support phase of the release line on a given date
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to check as a `YYYY-MM-DD` string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::{NodeJSReleaseSchedule, NodeJSSupportPhase};
+    /// let schedule = NodeJSReleaseSchedule {
+    ///   start: "2022-04-19".to_string(),
+    ///   lts: Some("2022-10-25".to_string()),
+    ///   maintenance: Some("2023-10-18".to_string()),
+    ///   end: "2025-04-30".to_string(),
+    ///   codename: Some("Hydrogen".to_string()),
+    /// };
+    /// assert_eq!(schedule.support_phase_on("2023-01-01"), NodeJSSupportPhase::ActiveLTS);
+    /// assert_eq!(schedule.support_phase_on("2025-05-01"), NodeJSSupportPhase::EndOfLife);
+    /// ```
+    pub fn support_phase_on<D: AsRef<str>>(&self, date: D) -> NodeJSSupportPhase {
+        let date = date.as_ref();
+
+        if date < self.start.as_str() {
+            return NodeJSSupportPhase::Pending;
+        }
+
+        if date >= self.end.as_str() {
+            return NodeJSSupportPhase::EndOfLife;
+        }
+
+        if let Some(maintenance) = &self.maintenance {
+            if date >= maintenance.as_str() {
+                return NodeJSSupportPhase::Maintenance;
+            }
+        }
+
+        if let Some(lts) = &self.lts {
+            if date >= lts.as_str() {
+                return NodeJSSupportPhase::ActiveLTS;
+            }
+        }
+
+        NodeJSSupportPhase::Current
+    }
+
+    /// Determines the support phase of the release line today
+    pub fn support_phase(&self) -> NodeJSSupportPhase {
+        self.support_phase_on(today())
+    }
+
+    /// Whether the release line has reached end-of-life
+    pub fn is_eol(&self) -> bool {
+        self.support_phase() == NodeJSSupportPhase::EndOfLife
+    }
+}
+
+// schedule keys are `v{major}` except for pre-1.0 lines which are `v0.{minor}`
+pub fn key<T: AsRef<str>>(version: T) -> Option<String> {
+    let version = Version::parse(version.as_ref()).ok()?;
+
+    if version.major == 0 {
+        return Some(format!("v0.{}", version.minor));
+    }
+
+    Some(format!("v{}", version.major))
+}
+
+pub async fn fetch(
+    version: &String,
+    url_fmt: &NodeJSURLFormatter,
+    settings: &http::Settings,
+) -> Result<NodeJSReleaseSchedule, NodeJSRelInfoError> {
+    let body = match http::get(&url_fmt.schedule, settings).await {
+        Err(NodeJSRelInfoError::HttpStatus { status: 404, .. }) => {
+            return Err(NodeJSRelInfoError::InvalidReleaseSchedule(
+                url_fmt.schedule.clone(),
+            ));
+        }
+        res => res?,
+    };
+
+    let mut schedule = parse(&url_fmt.schedule, &body)?;
+    let key = key(version).unwrap_or_default();
+
+    match schedule.remove(&key) {
+        Some(s) => Ok(s),
+        None => Err(NodeJSRelInfoError::UnrecognizedVersion(version.clone())),
+    }
+}
+
+pub fn parse<U: AsRef<str>>(
+    schedule_url: U,
+    schedule: &str,
+) -> Result<HashMap<String, NodeJSReleaseSchedule>, NodeJSRelInfoError> {
+    match serde_json::from_str(schedule) {
+        Ok(schedule) => Ok(schedule),
+        Err(_) => Err(NodeJSRelInfoError::InvalidReleaseSchedule(
+            schedule_url.as_ref().to_owned(),
+        )),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// `SystemTime::now()` panics in the browser so ask JS instead
+#[cfg(target_arch = "wasm32")]
+fn now_secs() -> u64 {
+    (js_sys::Date::now() / 1_000.0) as u64
+}
+
+// today's date (UTC) as `YYYY-MM-DD`
+// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub fn today() -> String {
+    let secs = now_secs();
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+use mockito::{Mock, Server};
+
+#[cfg(test)]
+pub fn setup_server_mock(url_fmt: &mut NodeJSURLFormatter, server: &mut Server) -> Mock {
+    url_fmt.schedule = format!("{}/schedule.json", server.url());
+    server.mock("GET", "/schedule.json")
+}
+
+#[cfg(test)]
+pub fn get_fake_schedule() -> &'static str {
+    r#"{
+    "v0.10": {"start": "2013-03-11", "end": "2016-10-31"},
+    "v16": {"start": "2021-04-20", "lts": "2021-10-26", "maintenance": "2022-10-18", "end": "2023-09-11", "codename": "Gallium"},
+    "v18": {"start": "2022-04-19", "lts": "2022-10-25", "maintenance": "2023-10-18", "end": "2025-04-30", "codename": "Hydrogen"},
+    "v20": {"start": "2023-04-18", "lts": "2023-10-24", "maintenance": "2024-10-22", "end": "2026-04-30", "codename": ""},
+    "v21": {"start": "2023-10-17", "maintenance": "2024-04-01", "end": "2024-06-01"}
+}"#
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_fake_release_line(key: &str) -> NodeJSReleaseSchedule {
+        let mut schedule = parse("schedule.json", get_fake_schedule()).unwrap();
+        schedule.remove(key).unwrap()
+    }
+
+    #[test]
+    fn it_builds_schedule_key_from_version() {
+        assert_eq!(key("20.6.1"), Some("v20".to_string()));
+        assert_eq!(key("0.10.48"), Some("v0.10".to_string()));
+        assert_eq!(key("NOPE"), None);
+    }
+
+    #[test]
+    fn it_parses_node_js_release_schedule() {
+        let schedule = parse("schedule.json", get_fake_schedule()).unwrap();
+        assert_eq!(schedule.len(), 5);
+
+        let v18 = &schedule["v18"];
+
+        assert_eq!(v18.start, "2022-04-19");
+        assert_eq!(v18.lts, Some("2022-10-25".to_string()));
+        assert_eq!(v18.maintenance, Some("2023-10-18".to_string()));
+        assert_eq!(v18.end, "2025-04-30");
+        assert_eq!(v18.codename, Some("Hydrogen".to_string()));
+
+        let v0_10 = &schedule["v0.10"];
+
+        assert_eq!(v0_10.lts, None);
+        assert_eq!(v0_10.maintenance, None);
+        assert_eq!(v0_10.codename, None);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: InvalidReleaseSchedule(\"schedule.json\")"
+    )]
+    fn it_fails_to_parse_invalid_node_js_release_schedule() {
+        parse("schedule.json", "NOPE!").unwrap();
+    }
+
+    #[test]
+    fn it_determines_support_phase_for_lts_release_line() {
+        let v18 = get_fake_release_line("v18");
+        assert_eq!(
+            v18.support_phase_on("2022-01-01"),
+            NodeJSSupportPhase::Pending
+        );
+        assert_eq!(
+            v18.support_phase_on("2022-04-19"),
+            NodeJSSupportPhase::Current
+        );
+        assert_eq!(
+            v18.support_phase_on("2022-10-25"),
+            NodeJSSupportPhase::ActiveLTS
+        );
+        assert_eq!(
+            v18.support_phase_on("2023-10-18"),
+            NodeJSSupportPhase::Maintenance
+        );
+        assert_eq!(
+            v18.support_phase_on("2025-04-30"),
+            NodeJSSupportPhase::EndOfLife
+        );
+    }
+
+    #[test]
+    fn it_determines_support_phase_for_non_lts_release_line() {
+        let v21 = get_fake_release_line("v21");
+        assert_eq!(
+            v21.support_phase_on("2023-10-17"),
+            NodeJSSupportPhase::Current
+        );
+        assert_eq!(
+            v21.support_phase_on("2024-04-01"),
+            NodeJSSupportPhase::Maintenance
+        );
+        assert_eq!(
+            v21.support_phase_on("2024-06-01"),
+            NodeJSSupportPhase::EndOfLife
+        );
+    }
+
+    #[test]
+    fn it_determines_whether_release_line_is_eol() {
+        let v0_10 = get_fake_release_line("v0.10");
+        assert!(v0_10.is_eol());
+    }
+
+    #[test]
+    fn it_serializes_support_phase_to_str() {
+        assert_eq!(format!("{}", NodeJSSupportPhase::Pending), "pending");
+        assert_eq!(format!("{}", NodeJSSupportPhase::Current), "current");
+        assert_eq!(format!("{}", NodeJSSupportPhase::ActiveLTS), "active-lts");
+        assert_eq!(
+            format!("{}", NodeJSSupportPhase::Maintenance),
+            "maintenance"
+        );
+        assert_eq!(format!("{}", NodeJSSupportPhase::EndOfLife), "end-of-life");
+    }
+
+    #[test]
+    fn it_formats_today() {
+        let today = today();
+        assert_eq!(today.len(), 10);
+        assert!(today.as_str() > "2023-01-01");
+        assert_eq!(&today[4..5], "-");
+        assert_eq!(&today[7..8], "-");
+    }
+
+    #[tokio::test]
+    async fn it_fetches_node_js_release_schedule() {
+        let version = String::from("18.18.0");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&mut url_fmt, &mut server)
+            .with_body(get_fake_schedule())
+            .create_async()
+            .await;
+
+        let schedule = fetch(&version, &url_fmt, &http::Settings::default())
+            .await
+            .unwrap();
+        mock.assert_async().await;
+        assert_eq!(schedule.end, "2025-04-30");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "called `Result::unwrap()` on an `Err` value: UnrecognizedVersion(\"99.0.0\")"
+    )]
+    async fn it_fails_to_fetch_node_js_release_schedule_when_version_is_unrecognized() {
+        let version = String::from("99.0.0");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&mut url_fmt, &mut server)
+            .with_body(get_fake_schedule())
+            .create_async()
+            .await;
+
+        fetch(&version, &url_fmt, &http::Settings::default())
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,218 @@
+use crate::error::NodeJSRelInfoError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A SHA-256 digest as published in `SHASUMS256.txt` - equality checks run in
+/// constant time
+#[derive(Clone, Copy, Debug, Default, Eq)]
+pub struct Sha256([u8; 32]);
+
+impl Sha256 {
+    /// Creates a new instance from the given digest bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The 32 byte digest
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::Sha256;
+    /// let sha256 = Sha256::new([0; 32]);
+    /// assert_eq!(sha256.as_bytes(), &[0; 32]);
+    /// ```
+    pub fn new(bytes: [u8; 32]) -> Sha256 {
+        Sha256(bytes)
+    }
+
+    /// Parses a hex encoded digest (case-insensitive)
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - The 64 character hex string (`String` / `&str`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::Sha256;
+    /// let sha256 = Sha256::from_hex("d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46").unwrap();
+    /// assert_eq!(sha256.as_bytes()[0], 0xd8);
+    /// ```
+    pub fn from_hex<T: AsRef<str>>(hex: T) -> Result<Sha256, NodeJSRelInfoError> {
+        let hex = hex.as_ref();
+        let input = hex.as_bytes();
+
+        if input.len() != 64 || !input.iter().all(u8::is_ascii_hexdigit) {
+            return Err(NodeJSRelInfoError::InvalidChecksum(hex.to_string()));
+        }
+
+        let mut bytes = [0u8; 32];
+
+        for (byte, pair) in bytes.iter_mut().zip(input.chunks(2)) {
+            *byte = (nibble(pair[0]) << 4) | nibble(pair[1]);
+        }
+
+        Ok(Sha256(bytes))
+    }
+
+    /// Formats the digest as a lowercase hex string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use node_js_release_info::Sha256;
+    /// let sha256 = Sha256::from_hex("D8BA8018D45B294429B1A7646CCBEAEB2AF3CDF45B5C91DABBD93E2A2035CB46").unwrap();
+    /// assert_eq!(sha256.to_hex(), "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Returns the raw digest bytes
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Computes the digest of the given data - requires the `verify` feature
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to hash
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use node_js_release_info::Sha256;
+    /// let sha256 = Sha256::digest(b"hello");
+    /// assert_eq!(sha256.to_hex(), "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+    /// ```
+    #[cfg(feature = "verify")]
+    pub fn digest<T: AsRef<[u8]>>(data: T) -> Sha256 {
+        use sha2::Digest;
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&sha2::Sha256::digest(data.as_ref()));
+        Sha256(bytes)
+    }
+
+    /// Checks whether the given data hashes to this digest (in constant time) -
+    /// requires the `verify` feature
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to verify
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use node_js_release_info::Sha256;
+    /// let sha256 = Sha256::from_hex("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824").unwrap();
+    /// assert!(sha256.verify(b"hello"));
+    /// ```
+    #[cfg(feature = "verify")]
+    pub fn verify<T: AsRef<[u8]>>(&self, data: T) -> bool {
+        *self == Sha256::digest(data)
+    }
+}
+
+// `pair` bytes are validated as ascii hex digits up front
+fn nibble(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        _ => c - b'A' + 10,
+    }
+}
+
+// compares every byte regardless of where the first difference is so timing
+// doesn't leak how much of a digest matched
+impl PartialEq for Sha256 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
+}
+
+impl Display for Sha256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl FromStr for Sha256 {
+    type Err = NodeJSRelInfoError;
+
+    fn from_str(s: &str) -> Result<Sha256, NodeJSRelInfoError> {
+        Sha256::from_hex(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46";
+
+    #[test]
+    fn it_initializes() {
+        let sha256 = Sha256::new([1; 32]);
+        assert_eq!(sha256.as_bytes(), &[1; 32]);
+        assert_eq!(Sha256::default().as_bytes(), &[0; 32]);
+    }
+
+    #[test]
+    fn it_initializes_from_hex() {
+        let sha256 = Sha256::from_hex(HEX).unwrap();
+        assert_eq!(sha256.as_bytes()[..4], [0xd8, 0xba, 0x80, 0x18]);
+        assert_eq!(sha256, Sha256::from_hex(HEX.to_uppercase()).unwrap());
+    }
+
+    #[test]
+    fn it_fails_to_initialize_from_hex_when_invalid() {
+        for hex in [
+            "",
+            "d8ba",
+            &"zz".repeat(32),
+            &"+f".repeat(32),
+            &HEX.repeat(2),
+        ] {
+            let err = Sha256::from_hex(hex).unwrap_err();
+            assert!(matches!(err, NodeJSRelInfoError::InvalidChecksum(h) if h == hex));
+        }
+    }
+
+    #[test]
+    fn it_formats_to_hex() {
+        let sha256 = Sha256::from_hex(HEX.to_uppercase()).unwrap();
+        assert_eq!(sha256.to_hex(), HEX);
+        assert_eq!(sha256.to_string(), HEX);
+    }
+
+    #[test]
+    fn it_initializes_from_str() {
+        let sha256 = Sha256::from_str(HEX).unwrap();
+        assert_eq!(sha256.to_hex(), HEX);
+    }
+
+    #[test]
+    fn it_compares_digests() {
+        let a = Sha256::from_hex(HEX).unwrap();
+        let mut bytes = *a.as_bytes();
+        bytes[31] ^= 1;
+        let b = Sha256::new(bytes);
+        assert_eq!(a, a);
+        assert_ne!(a, b);
+    }
+
+    #[cfg(feature = "verify")]
+    #[test]
+    fn it_computes_and_verifies_digests() {
+        let hello = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let sha256 = Sha256::digest(b"hello");
+        assert_eq!(sha256.to_hex(), hello);
+        assert!(sha256.verify("hello"));
+        assert!(!sha256.verify("goodbye"));
+    }
+}
@@ -1,47 +1,249 @@
 use crate::arch::NodeJSArch;
 use crate::error::NodeJSRelInfoError;
 use crate::ext::NodeJSPkgExt;
+use crate::http;
+use crate::kind::NodeJSArtifactKind;
 use crate::os::NodeJSOS;
 use crate::url::NodeJSURLFormatter;
 use semver::Version;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 pub fn validate_version<T: AsRef<str>>(semver: T) -> Result<String, NodeJSRelInfoError> {
     match Version::parse(semver.as_ref()) {
         Ok(v) => Ok(v.to_string()),
-        Err(_) => {
-            return Err(NodeJSRelInfoError::InvalidVersion(
-                semver.as_ref().to_owned(),
-            ))
+        Err(_) => Err(NodeJSRelInfoError::InvalidVersion(
+            semver.as_ref().to_owned(),
+        )),
+    }
+}
+
+// rejects kind / os / arch / ext combinations the Node.js project has never
+// published an artifact for - intentionally permissive w/ respect to arch
+// since older release lines shipped configurations (e.g. `darwin-x86`) that
+// newer ones do not
+pub fn validate_configuration(
+    kind: &NodeJSArtifactKind,
+    os: &NodeJSOS,
+    arch: &NodeJSArch,
+    ext: &NodeJSPkgExt,
+) -> Result<(), NodeJSRelInfoError> {
+    let bad_os = || NodeJSRelInfoError::IncompatibleOs(format!("{} ({})", os, kind));
+    let bad_arch = |of: String| NodeJSRelInfoError::IncompatibleArch(format!("{} ({})", arch, of));
+    let bad_ext = |of: String| NodeJSRelInfoError::IncompatibleExt(format!("{} ({})", ext, of));
+    let is_win_arch = matches!(arch, NodeJSArch::X64 | NodeJSArch::X86 | NodeJSArch::ARM64);
+
+    match kind {
+        NodeJSArtifactKind::Headers | NodeJSArtifactKind::Source => {
+            if !matches!(ext, NodeJSPkgExt::Targz | NodeJSPkgExt::Tarxz) {
+                return Err(bad_ext(kind.to_string()));
+            }
+            return Ok(());
+        }
+        NodeJSArtifactKind::Pkg => {
+            if *os != NodeJSOS::Darwin {
+                return Err(bad_os());
+            }
+            return Ok(());
+        }
+        NodeJSArtifactKind::Exe | NodeJSArtifactKind::Lib | NodeJSArtifactKind::Pdb => {
+            if *os != NodeJSOS::Windows {
+                return Err(bad_os());
+            }
+            if !is_win_arch {
+                return Err(bad_arch(kind.to_string()));
+            }
+            if *kind == NodeJSArtifactKind::Pdb
+                && !matches!(ext, NodeJSPkgExt::Zip | NodeJSPkgExt::S7z)
+            {
+                return Err(bad_ext(kind.to_string()));
+            }
+            return Ok(());
+        }
+        NodeJSArtifactKind::Binary => {}
+    }
+
+    let ext_ok = match os {
+        NodeJSOS::Windows => matches!(
+            ext,
+            NodeJSPkgExt::Zip | NodeJSPkgExt::S7z | NodeJSPkgExt::Msi
+        ),
+        NodeJSOS::AIX => matches!(ext, NodeJSPkgExt::Targz),
+        NodeJSOS::Linux | NodeJSOS::Darwin => {
+            matches!(ext, NodeJSPkgExt::Targz | NodeJSPkgExt::Tarxz)
+        }
+    };
+
+    if !ext_ok {
+        return Err(bad_ext(os.to_string()));
+    }
+
+    let arch_ok = match arch {
+        NodeJSArch::X64 | NodeJSArch::X86 | NodeJSArch::ARM64 => !matches!(os, NodeJSOS::AIX),
+        NodeJSArch::ARMV7L
+        | NodeJSArch::PPC64LE
+        | NodeJSArch::S390X
+        | NodeJSArch::ARMV6L
+        | NodeJSArch::RISCV64
+        | NodeJSArch::LOONG64 => {
+            matches!(os, NodeJSOS::Linux)
         }
+        NodeJSArch::PPC64 => matches!(os, NodeJSOS::Linux | NodeJSOS::AIX),
+    };
+
+    if !arch_ok {
+        return Err(bad_arch(os.to_string()));
     }
+
+    Ok(())
 }
 
+// tries the primary host then each mirror in order, moving on only when a
+// request fails w/ a retryable error (timeout, 5xx, etc) - returns the body
+// along w/ the formatter for the host which served it
 pub async fn fetch(
     version: &String,
     url_fmt: &NodeJSURLFormatter,
-) -> Result<String, NodeJSRelInfoError> {
-    let info_url = url_fmt.info(version);
-    let res = match reqwest::get(info_url.as_str()).await {
-        Err(e) => return Err(NodeJSRelInfoError::HttpError(e)),
-        Ok(r) => r,
-    };
+    settings: &http::Settings,
+) -> Result<(String, NodeJSURLFormatter), NodeJSRelInfoError> {
+    let mut candidates = url_fmt.candidates().into_iter().peekable();
+
+    loop {
+        let candidate = candidates.next().unwrap();
+        let info_url = candidate.info(version);
+        match http::get(&info_url, settings).await {
+            Ok(body) => return Ok((body, candidate)),
+            Err(NodeJSRelInfoError::HttpStatus { status: 404, .. }) => {
+                return Err(NodeJSRelInfoError::UnrecognizedVersion(version.clone()))
+            }
+            Err(e) if e.is_retryable() && candidates.peek().is_some() => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(url = %info_url, error = %e, "trying next mirror");
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// filenames listed which aren't recognized as an artifact of the given
+// version - e.g. a new platform or package format the parser doesn't know yet
+pub fn unrecognized(version: &String, specs: &str) -> Vec<String> {
+    specs
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .filter(|filename| match parse_filename(filename) {
+            Some((v, ..)) => &v != version,
+            None => parse_sub_path(filename).is_none(),
+        })
+        .map(String::from)
+        .collect()
+}
 
-    // TODO (busticated): handle 5xx errors
-    if res.status().as_u16() >= 400 {
-        return Err(NodeJSRelInfoError::UnrecognizedVersion(version.clone()));
+// parses windows artifacts published under `win-{arch}/` (e.g.
+// `win-x64/node.exe`) into their kind, arch, and ext - ext is left as the
+// default for `node.exe` and `node.lib`
+fn parse_sub_path(filename: &str) -> Option<(NodeJSArtifactKind, NodeJSArch, NodeJSPkgExt)> {
+    let (dir, name) = filename.split_once('/')?;
+    let arch = NodeJSArch::from_str(dir.strip_prefix("win-")?).ok()?;
+    let ext = NodeJSPkgExt::default();
+
+    match name {
+        "node.exe" => Some((NodeJSArtifactKind::Exe, arch, ext)),
+        "node.lib" => Some((NodeJSArtifactKind::Lib, arch, ext)),
+        "node_pdb.zip" => Some((NodeJSArtifactKind::Pdb, arch, NodeJSPkgExt::Zip)),
+        "node_pdb.7z" => Some((NodeJSArtifactKind::Pdb, arch, NodeJSPkgExt::S7z)),
+        _ => None,
     }
+}
+
+// the version of the first recognized artifact listed - used to resolve
+// `latest-v{major}.x` alias directories to a concrete version
+pub fn version_of(specs: &str) -> Option<String> {
+    specs
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .find_map(parse_filename)
+        .map(|(version, ..)| version)
+}
+
+/// A supported Node.js distributable listed in a release's `SHASUMS256.txt` file
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "json", derive(Deserialize, Serialize))]
+pub struct NodeJSShasumsEntry {
+    /// The kind of artifact (e.g. `binary` or `headers`)
+    #[cfg_attr(feature = "json", serde(default))]
+    pub kind: NodeJSArtifactKind,
+    /// The operating system the distributable targets
+    pub os: NodeJSOS,
+    /// The CPU architecture the distributable targets
+    pub arch: NodeJSArch,
+    /// The file extension of the distributable
+    pub ext: NodeJSPkgExt,
+    /// The hash for the distributable
+    pub sha256: String,
+    /// The filename of the distributable
+    pub filename: String,
+}
 
-    match res.text().await {
-        Err(e) => Err(NodeJSRelInfoError::HttpError(e)),
-        Ok(b) => Ok(b),
+/// Parses the contents of a release's `SHASUMS256.txt` file into entries for
+/// each recognized artifact (binaries, headers, source, installers, etc)
+/// sorted by filename - lines for other versions or unrecognized platforms
+/// are skipped
+///
+/// # Arguments
+///
+/// * `version` - The Node.js version the document belongs to (`String` / `&str`)
+/// * `text` - The contents of the release's `SHASUMS256.txt` file
+///
+/// # Examples
+///
+/// ```rust
+/// use node_js_release_info::{parse_shasums, NodeJSArch, NodeJSOS};
+/// let shasums = "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46  node-v20.6.1-darwin-arm64.tar.gz";
+/// let entries = parse_shasums("20.6.1", shasums).unwrap();
+/// assert_eq!(entries.len(), 1);
+/// assert_eq!(entries[0].os, NodeJSOS::Darwin);
+/// assert_eq!(entries[0].arch, NodeJSArch::ARM64);
+/// assert_eq!(entries[0].filename, "node-v20.6.1-darwin-arm64.tar.gz");
+/// ```
+pub fn parse_shasums<V: AsRef<str>>(
+    version: V,
+    text: &str,
+) -> Result<Vec<NodeJSShasumsEntry>, NodeJSRelInfoError> {
+    let version = validate_version(version)?;
+
+    match parse(&version, text.to_string()) {
+        None => Err(NodeJSRelInfoError::UnrecognizedVersion(version)),
+        Some(specs) => Ok(specs
+            .into_iter()
+            .map(
+                |(kind, os, arch, ext, sha256, filename)| NodeJSShasumsEntry {
+                    kind,
+                    os,
+                    arch,
+                    ext,
+                    sha256,
+                    filename,
+                },
+            )
+            .collect()),
     }
 }
 
-pub type ParsedSpecs = Vec<(NodeJSOS, NodeJSArch, NodeJSPkgExt, String, String)>;
+pub type ParsedSpec = (
+    NodeJSArtifactKind,
+    NodeJSOS,
+    NodeJSArch,
+    NodeJSPkgExt,
+    String,
+    String,
+);
+pub type ParsedSpecs = Vec<ParsedSpec>;
 
 pub fn parse(version: &String, specs: String) -> Option<ParsedSpecs> {
     let mut all: ParsedSpecs = vec![];
+    let mut versioned = false;
     for line in specs.lines() {
         let (sha256, filename) = match line.trim().split_once(' ') {
             Some((s, f)) => (s.trim(), f.trim()),
@@ -52,59 +254,171 @@ pub fn parse(version: &String, specs: String) -> Option<ParsedSpecs> {
             continue;
         }
 
-        if !filename.starts_with(format!("node-v{}", &version).as_str()) {
-            continue;
-        }
-
-        let parts: Vec<&str> = filename.split('-').collect();
-        let last = parts.last().unwrap(); // b/c it'll never be empty
-        let is_msi = last.ends_with(".msi");
-
-        if parts.len() < 4 && !is_msi {
-            continue;
-        }
-
-        let os = if is_msi { "win" } else { parts[2] };
-        let os = match NodeJSOS::from_str(os) {
-            Ok(os) => os,
-            Err(_) => {
-                continue;
-            }
-        };
-
-        let (arch, ext) = match last.split_once('.') {
-            Some((a, e)) => (a.trim(), e.trim()),
-            None => {
-                continue;
-            }
-        };
-
-        let arch = match NodeJSArch::from_str(arch) {
-            Ok(a) => a,
-            Err(_) => {
-                continue;
-            }
-        };
-
-        let ext = match NodeJSPkgExt::from_str(ext) {
-            Ok(ext) => ext,
-            Err(_) => {
-                continue;
+        // `win-{arch}/` artifacts belong to the version whose directory lists them
+        let (kind, os, arch, ext) = match parse_filename(filename) {
+            Some((v, kind, os, arch, ext)) if &v == version => {
+                versioned = true;
+                (kind, os, arch, ext)
             }
+            Some(_) => continue,
+            None => match parse_sub_path(filename) {
+                Some((kind, arch, ext)) => (kind, NodeJSOS::Windows, arch, ext),
+                None => continue,
+            },
         };
 
         let filename = filename.to_string();
         let sha256 = sha256.to_string();
-        all.push((os, arch, ext, sha256, filename));
+        all.push((kind, os, arch, ext, sha256, filename));
     }
 
-    if all.is_empty() {
+    // sub-path artifacts alone don't show the document lists `version`
+    if !versioned {
         return None;
     }
 
+    // sorted by filename so results don't depend on SHASUMS line order
+    all.sort_by(|a, b| a.5.cmp(&b.5));
     Some(all)
 }
 
+pub type ParsedFilename = (
+    String,
+    NodeJSArtifactKind,
+    NodeJSOS,
+    NodeJSArch,
+    NodeJSPkgExt,
+);
+
+// parses artifact filenames like `node-v20.6.1-darwin-arm64.tar.gz`,
+// `node-v20.6.1-x64.msi`, `node-v20.6.1-headers.tar.gz`, `node-v20.6.1.tar.gz`
+// (source), or `node-v20.6.1.pkg` into their version, kind, os, arch, and ext -
+// os, arch, and ext are left as defaults when not part of the filename
+pub fn parse_filename(filename: &str) -> Option<ParsedFilename> {
+    let rest = filename.strip_prefix("node-v")?;
+    let (os, arch, ext) = (
+        NodeJSOS::default(),
+        NodeJSArch::default(),
+        NodeJSPkgExt::default(),
+    );
+
+    if let Some((version, ext)) = rest.split_once("-headers.") {
+        let version = validate_version(version).ok()?;
+        let ext = NodeJSPkgExt::from_str(ext).ok()?;
+        return Some((version, NodeJSArtifactKind::Headers, os, arch, ext));
+    }
+
+    // versions are checked for `-` since e.g. `20.6.1-linux-x64` is valid semver
+    if let Some(version) = rest.strip_suffix(".pkg") {
+        if !version.contains('-') {
+            let version = validate_version(version).ok()?;
+            return Some((
+                version,
+                NodeJSArtifactKind::Pkg,
+                NodeJSOS::Darwin,
+                arch,
+                ext,
+            ));
+        }
+    }
+
+    for ext in [NodeJSPkgExt::Targz, NodeJSPkgExt::Tarxz] {
+        if let Some(version) = rest.strip_suffix(format!(".{}", ext).as_str()) {
+            if !version.contains('-') {
+                let version = validate_version(version).ok()?;
+                return Some((version, NodeJSArtifactKind::Source, os, arch, ext));
+            }
+        }
+    }
+
+    let parts: Vec<&str> = rest.split('-').collect();
+    let last = parts.last().unwrap(); // b/c it'll never be empty
+    let is_msi = last.ends_with(".msi");
+    let (os, version) = match (is_msi, parts.len()) {
+        (true, n) if n >= 2 => ("win", parts[..n - 1].join("-")),
+        (false, n) if n >= 3 => (parts[n - 2], parts[..n - 2].join("-")),
+        _ => return None,
+    };
+
+    let (arch, ext) = last.split_once('.')?;
+    let version = validate_version(version).ok()?;
+    let os = NodeJSOS::from_str(os).ok()?;
+    let arch = NodeJSArch::from_str(arch).ok()?;
+    let ext = NodeJSPkgExt::from_str(ext).ok()?;
+    Some((version, NodeJSArtifactKind::Binary, os, arch, ext))
+}
+
+#[cfg(test)]
+use mockito::{Mock, Server};
+
+#[cfg(test)]
+fn assert_is_darwin_arm64_targz_specs(specs: ParsedSpecs) {
+    assert_eq!(specs.len(), 1);
+    let (kind, os, arch, ext, sha256, filename) = &specs[0];
+    assert_eq!(*kind, NodeJSArtifactKind::Binary);
+    assert_eq!(*os, NodeJSOS::Darwin);
+    assert_eq!(*arch, NodeJSArch::ARM64);
+    assert_eq!(*ext, NodeJSPkgExt::Targz);
+    assert_eq!(filename, "node-v20.6.1-darwin-arm64.tar.gz");
+    assert_eq!(sha256, "FAKESHA");
+}
+
+#[cfg(test)]
+pub fn setup_server_mock(
+    version: &str,
+    url_fmt: &mut NodeJSURLFormatter,
+    server: &mut Server,
+) -> Mock {
+    url_fmt.host = server.host_with_port();
+    url_fmt.protocol = "http:".to_string();
+    server.mock("GET", url_fmt.info_pathname(version).as_str())
+}
+
+#[cfg(test)]
+pub fn get_fake_specs() -> &'static str {
+    "ea52b4feaf917e08cd2c729c1186585fcacef07c261a01310c91333b9e41d93c  node-v20.6.1-aix-ppc64.tar.gz
+    9471bd6dc491e09c31b0f831f5953284b8a6842ed4ccb98f5c62d13e6086c471  node-v20.6.1-arm64.msi
+    d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46  node-v20.6.1-darwin-arm64.tar.gz
+    9c61b0d60fce962244d5e54549dc912e28b3c5f5e23149bfd15f66f8f7269129  node-v20.6.1-darwin-arm64.tar.xz
+    365ec544c6596f194afff9a613554abfc68d4a2274181b7651386d9a11cf5862  node-v20.6.1-darwin-x64.tar.gz
+    9b10c16670781e3a5af722656d28f264cdd8ebb3140f62692b33813100391349  node-v20.6.1-darwin-x64.tar.xz
+    d8271461ced2887f65af413949caee19db3e80d22bbefdaf01252ca998570052  node-v20.6.1-headers.tar.gz
+    60963e3ee60b6739e97e0c7b8ffb25848a82649c0c277af728400c570fd9db6d  node-v20.6.1-headers.tar.xz
+    d38fe2e41e3fe8ae81b517b4cf49521f500e181e54f4c3d05e2b2d691a57b2ca  node-v20.6.1-linux-arm64.tar.gz
+    6823720796b287465bb4aa8e7611143322ffd6cbdb9c6e3b149576f6d87953bf  node-v20.6.1-linux-arm64.tar.xz
+    459510281ea51cf5d89fc666e36fbba80793ae4b90c3a7f89dd6666c65c825b3  node-v20.6.1-linux-armv7l.tar.gz
+    9dbd4fd7f804a28de91ffb8792df6e89bbb4f934fccd013624b3dabf8bf809ac  node-v20.6.1-linux-armv7l.tar.xz
+    ca00f1aa8b2535fa167258cf5f2cfce4b79d83c442dd5e46f5e17d6a5749ec0f  node-v20.6.1-linux-ppc64le.tar.gz
+    27884935b025b6676e4b8737f334673ee825947d0baef61aa0326374597aeb05  node-v20.6.1-linux-ppc64le.tar.xz
+    4a3f29cfc8a7ed1e9e44fcacb78e2fbaa3ce01be1efc4971a42710ad1e9e45d1  node-v20.6.1-linux-s390x.tar.gz
+    3968d629989b6de16b8872b6d7ee6e6cdf1204def99c43412a6ee28203ed0022  node-v20.6.1-linux-s390x.tar.xz
+    26dd13a6f7253f0ab9bcab561353985a297d927840771d905566735b792868da  node-v20.6.1-linux-x64.tar.gz
+    591f9f274104f266a8cf085d2c7d5d2848ba73b98ae323d501db2d4c4b7026e5  node-v20.6.1-linux-x64.tar.xz
+    d9acf82d9576dd0350c8e66b55f6fc2750fa9f4aa23d6453ffc58e32af995894  node-v20.6.1.pkg
+    0053c09a01b1b355bca5af82927cae376124c13d74fa53567f08f4cfb085e6aa  node-v20.6.1.tar.gz
+    3aec5e728daa38800c343b129221d3488064a2529a39bb5467bc55be226c6a2b  node-v20.6.1.tar.xz
+    337549faf397deb0da3bccd4e27db45a619d89de4ea12830d16d9dfaded8e92c  node-v20.6.1-win-arm64.7z
+    0e62045bfc9d7c38360bd7da152c75ed82087242d5e4b401fa23a439588d36f6  node-v20.6.1-win-arm64.zip
+    c6cfe7824770a266a30bee8c33f485d0e89b94254c682250a239d83adfb7ce77  node-v20.6.1-win-x64.7z
+    88371914f1f75d594bb367570e163cf5ecebeb514fd54cc765093819ebb0ed48  node-v20.6.1-win-x64.zip
+    87d631b294a25386400d0f44d227330da62a1326e2a4fbb98bda3d7c431257f1  node-v20.6.1-win-x86.7z
+    578cff623601aa8878a035f06edbf69190338ee3b345e7a096e804cb80c4ce24  node-v20.6.1-win-x86.zip
+    5c2616da46728dd1326645c7db114e78ad87138a258c0724a035269258c23509  node-v20.6.1-x64.msi
+    cb83586af83182187e760b7e01aa7c7b2bacb521d60ceefed3ac6fc62c222449  node-v20.6.1-x86.msi
+    7cc3240fd7ce7926eef1cbbad33b033f7c5d97b3f3e527d65ff1e2c3f7638a11  win-arm64/node.exe
+    deb027ded744371657811cfe52e774881ea928d36779924af84aa9a7a31104d2  win-arm64/node.lib
+    dcb6b4bc6f2a78bf0f759853b59e94ddbe9ad6b9f32d24fdcf590d74c6350bc2  win-arm64/node_pdb.7z
+    bdcd574e99646ec4a03bb13b3661c957f5a7ca837f5c33827075c4262d449689  win-arm64/node_pdb.zip
+    5b824f3a375cca06dfd7dc70fa341a6ef8bb0b2e912358d8602a0c7ad273b9a4  win-x64/node.exe
+    d275cfc4d637d2feaf4c39e1a5f5cd84f5b474fa713c15013e940c329feed13b  win-x64/node.lib
+    fea6c0fcff45739a6e5af9843ec45455c97ff8677167bd649fd48cbef59ca52d  win-x64/node_pdb.7z
+    bc13f5e63c1510cd41f82dc20725f40bbfa378252e09a00a8531cddabbf1b106  win-x64/node_pdb.zip
+    837db0d8fb7fa194ebe23cd34ac7bedc02d1132de67cf4f147d694574be5cc4e  win-x86/node.exe
+    a0738dec64427ae73eeb1d036081652c1c0223a679a63e0459c2af667f284f58  win-x86/node.lib
+    516ac820f05eb8478be541ac12386c3b5b5c07624f73934bcf0b11a3fcdb1c95  win-x86/node_pdb.7z
+    9b68f3e1f1717a2f6a090e1679f8cc627566ed064c657c35eddd0dba9484e310  win-x86/node_pdb.zip"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,13 +441,26 @@ mod tests {
         assert_eq!(format!("{error}"), "Error: Invalid Version! Received: ''");
     }
 
+    #[test]
+    fn it_parses_node_js_specs_in_filename_order() {
+        let version = String::from("20.6.1");
+        let specs_raw: Vec<&str> = get_fake_specs().lines().rev().collect();
+        let specs = parse(&version, specs_raw.join("\n")).unwrap();
+        let filenames: Vec<&str> = specs.iter().map(|spec| spec.5.as_str()).collect();
+        let mut sorted = filenames.clone();
+        sorted.sort();
+        assert_eq!(filenames, sorted);
+        assert_eq!(specs[2].5, "node-v20.6.1-darwin-arm64.tar.gz");
+    }
+
     #[test]
     fn it_parses_node_js_specs() {
         let version = String::from("20.6.1");
         let specs_raw = get_fake_specs().to_string();
         let specs = parse(&version, specs_raw).unwrap();
-        assert_eq!(specs.len(), 24);
-        let (os, arch, ext, sha256, filename) = &specs[2];
+        assert_eq!(specs.len(), 41);
+        let (kind, os, arch, ext, sha256, filename) = &specs[2];
+        assert_eq!(*kind, NodeJSArtifactKind::Binary);
         assert_eq!(*os, NodeJSOS::Darwin);
         assert_eq!(*arch, NodeJSArch::ARM64);
         assert_eq!(*ext, NodeJSPkgExt::Targz);
@@ -144,6 +471,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_parses_every_artifact_kind() {
+        let version = String::from("20.6.1");
+        let specs = parse(&version, get_fake_specs().to_string()).unwrap();
+        let find = |filename: &str| {
+            specs
+                .iter()
+                .find(|spec| spec.5 == filename)
+                .map(|(kind, os, arch, ext, ..)| {
+                    (kind.clone(), os.clone(), arch.clone(), ext.clone())
+                })
+                .unwrap()
+        };
+        let count = |kind: NodeJSArtifactKind| specs.iter().filter(|spec| spec.0 == kind).count();
+
+        assert_eq!(count(NodeJSArtifactKind::Binary), 24);
+        assert_eq!(count(NodeJSArtifactKind::Headers), 2);
+        assert_eq!(count(NodeJSArtifactKind::Source), 2);
+        assert_eq!(count(NodeJSArtifactKind::Pkg), 1);
+        assert_eq!(count(NodeJSArtifactKind::Exe), 3);
+        assert_eq!(count(NodeJSArtifactKind::Lib), 3);
+        assert_eq!(count(NodeJSArtifactKind::Pdb), 6);
+
+        let (kind, _, _, ext) = find("node-v20.6.1-headers.tar.xz");
+        assert_eq!(
+            (kind, ext),
+            (NodeJSArtifactKind::Headers, NodeJSPkgExt::Tarxz)
+        );
+
+        let (kind, _, _, ext) = find("node-v20.6.1.tar.gz");
+        assert_eq!(
+            (kind, ext),
+            (NodeJSArtifactKind::Source, NodeJSPkgExt::Targz)
+        );
+
+        let (kind, os, _, _) = find("node-v20.6.1.pkg");
+        assert_eq!((kind, os), (NodeJSArtifactKind::Pkg, NodeJSOS::Darwin));
+
+        let (kind, os, arch, _) = find("win-arm64/node.exe");
+        assert_eq!(kind, NodeJSArtifactKind::Exe);
+        assert_eq!((os, arch), (NodeJSOS::Windows, NodeJSArch::ARM64));
+
+        let (kind, _, arch, _) = find("win-x86/node.lib");
+        assert_eq!((kind, arch), (NodeJSArtifactKind::Lib, NodeJSArch::X86));
+
+        let (kind, _, arch, ext) = find("win-x64/node_pdb.7z");
+        assert_eq!(kind, NodeJSArtifactKind::Pdb);
+        assert_eq!((arch, ext), (NodeJSArch::X64, NodeJSPkgExt::S7z));
+    }
+
     #[test]
     fn it_handles_empty_data_when_parsing_node_js_specs() {
         let version = String::from("20.6.1");
@@ -151,6 +528,12 @@ mod tests {
         assert!(parse(&version, specs_raw.join("\n").to_string()).is_none());
     }
 
+    #[test]
+    fn it_handles_unlisted_version_when_parsing_node_js_specs() {
+        let version = String::from("1.0.0");
+        assert!(parse(&version, get_fake_specs().to_string()).is_none());
+    }
+
     #[test]
     fn it_ignores_invalid_data_when_parsing_node_js_specs() {
         let version = String::from("20.6.1");
@@ -236,6 +619,212 @@ mod tests {
         assert_is_darwin_arm64_targz_specs(specs);
     }
 
+    #[test]
+    fn it_parses_a_distributable_filename() {
+        let (version, kind, os, arch, ext) =
+            parse_filename("node-v20.6.1-darwin-arm64.tar.gz").unwrap();
+        assert_eq!(version, "20.6.1");
+        assert_eq!(kind, NodeJSArtifactKind::Binary);
+        assert_eq!(os, NodeJSOS::Darwin);
+        assert_eq!(arch, NodeJSArch::ARM64);
+        assert_eq!(ext, NodeJSPkgExt::Targz);
+
+        let (version, kind, os, arch, ext) = parse_filename("node-v20.6.1-x86.msi").unwrap();
+        assert_eq!(version, "20.6.1");
+        assert_eq!(kind, NodeJSArtifactKind::Binary);
+        assert_eq!(os, NodeJSOS::Windows);
+        assert_eq!(arch, NodeJSArch::X86);
+        assert_eq!(ext, NodeJSPkgExt::Msi);
+
+        let (version, kind, _, _, ext) = parse_filename("node-v20.6.1-headers.tar.xz").unwrap();
+        assert_eq!(version, "20.6.1");
+        assert_eq!(kind, NodeJSArtifactKind::Headers);
+        assert_eq!(ext, NodeJSPkgExt::Tarxz);
+
+        let (_, _, os, arch, _) = parse_filename("node-v20.6.1-linux-riscv64.tar.xz").unwrap();
+        assert_eq!(os, NodeJSOS::Linux);
+        assert_eq!(arch, NodeJSArch::RISCV64);
+
+        let (version, kind, _, _, ext) = parse_filename("node-v20.6.1.tar.xz").unwrap();
+        assert_eq!(version, "20.6.1");
+        assert_eq!(kind, NodeJSArtifactKind::Source);
+        assert_eq!(ext, NodeJSPkgExt::Tarxz);
+
+        let (version, kind, os, _, _) = parse_filename("node-v20.6.1.pkg").unwrap();
+        assert_eq!(version, "20.6.1");
+        assert_eq!(kind, NodeJSArtifactKind::Pkg);
+        assert_eq!(os, NodeJSOS::Darwin);
+    }
+
+    #[test]
+    fn it_fails_to_parse_an_unrecognized_filename() {
+        assert!(parse_filename("").is_none());
+        assert!(parse_filename("node-v20.6.1.zip").is_none());
+        assert!(parse_filename("node-vNOPE.tar.gz").is_none());
+        assert!(parse_filename("node-vNOPE.pkg").is_none());
+        assert!(parse_filename("node-v20.6.1-headers.NOPE").is_none());
+        assert!(parse_filename("node-vNOPE-headers.tar.gz").is_none());
+        assert!(parse_filename("node-vNOPE-darwin-arm64.tar.gz").is_none());
+        assert!(parse_filename("NOPE-v20.6.1-darwin-arm64.tar.gz").is_none());
+        assert!(parse_filename("node-v20.6.1-darwin-arm64").is_none());
+        assert!(parse_filename("win-x64/node.exe").is_none());
+    }
+
+    #[test]
+    fn it_accepts_every_published_configuration() {
+        let specs = parse(&"20.6.1".to_string(), get_fake_specs().to_string()).unwrap();
+
+        for (kind, os, arch, ext, _, filename) in specs {
+            assert!(
+                validate_configuration(&kind, &os, &arch, &ext).is_ok(),
+                "{}",
+                filename
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_unpublished_configurations() {
+        let cases = [
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::Windows,
+                NodeJSArch::X64,
+                NodeJSPkgExt::Targz,
+                "IncompatibleExt(\"tar.gz (win)\")",
+            ),
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::Darwin,
+                NodeJSArch::ARM64,
+                NodeJSPkgExt::Zip,
+                "IncompatibleExt(\"zip (darwin)\")",
+            ),
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::Linux,
+                NodeJSArch::X64,
+                NodeJSPkgExt::Msi,
+                "IncompatibleExt(\"msi (linux)\")",
+            ),
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::AIX,
+                NodeJSArch::PPC64,
+                NodeJSPkgExt::Tarxz,
+                "IncompatibleExt(\"tar.xz (aix)\")",
+            ),
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::AIX,
+                NodeJSArch::X64,
+                NodeJSPkgExt::Targz,
+                "IncompatibleArch(\"x64 (aix)\")",
+            ),
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::Windows,
+                NodeJSArch::ARMV7L,
+                NodeJSPkgExt::Zip,
+                "IncompatibleArch(\"armv7l (win)\")",
+            ),
+            (
+                NodeJSArtifactKind::Binary,
+                NodeJSOS::Darwin,
+                NodeJSArch::RISCV64,
+                NodeJSPkgExt::Targz,
+                "IncompatibleArch(\"riscv64 (darwin)\")",
+            ),
+            (
+                NodeJSArtifactKind::Headers,
+                NodeJSOS::Linux,
+                NodeJSArch::X64,
+                NodeJSPkgExt::Zip,
+                "IncompatibleExt(\"zip (headers)\")",
+            ),
+            (
+                NodeJSArtifactKind::Pkg,
+                NodeJSOS::Linux,
+                NodeJSArch::X64,
+                NodeJSPkgExt::Targz,
+                "IncompatibleOs(\"linux (pkg)\")",
+            ),
+            (
+                NodeJSArtifactKind::Lib,
+                NodeJSOS::Windows,
+                NodeJSArch::S390X,
+                NodeJSPkgExt::Targz,
+                "IncompatibleArch(\"s390x (lib)\")",
+            ),
+            (
+                NodeJSArtifactKind::Pdb,
+                NodeJSOS::Windows,
+                NodeJSArch::X64,
+                NodeJSPkgExt::Msi,
+                "IncompatibleExt(\"msi (pdb)\")",
+            ),
+        ];
+
+        for (kind, os, arch, ext, expected) in cases {
+            let err = validate_configuration(&kind, &os, &arch, &ext).unwrap_err();
+            assert_eq!(format!("{:?}", err), expected);
+        }
+    }
+
+    #[test]
+    fn it_parses_shasums_into_entries() {
+        let entries = parse_shasums("20.6.1", get_fake_specs()).unwrap();
+        assert_eq!(entries.len(), 41);
+        assert_eq!(
+            entries[2],
+            NodeJSShasumsEntry {
+                kind: NodeJSArtifactKind::Binary,
+                os: NodeJSOS::Darwin,
+                arch: NodeJSArch::ARM64,
+                ext: NodeJSPkgExt::Targz,
+                sha256: "d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46"
+                    .to_string(),
+                filename: "node-v20.6.1-darwin-arm64.tar.gz".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_fails_to_parse_shasums_when_version_is_invalid_or_unlisted() {
+        let err = parse_shasums("NOPE!", get_fake_specs()).unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::InvalidVersion(v) if v == "NOPE!"));
+
+        let err = parse_shasums("1.0.0", get_fake_specs()).unwrap_err();
+        assert!(matches!(err, NodeJSRelInfoError::UnrecognizedVersion(v) if v == "1.0.0"));
+    }
+
+    #[test]
+    fn it_finds_unrecognized_artifacts() {
+        let version = "20.6.1".to_string();
+        assert!(unrecognized(&version, get_fake_specs()).is_empty());
+
+        let specs = format!(
+            "{}\nabc123  node-v20.6.1-linux-wasm32.tar.gz\nabc123  node-v20.6.1-win-x64.appx\nabc123  win-x64/node.dll\nabc123  node-v18.18.0-linux-x64.tar.gz",
+            get_fake_specs()
+        );
+        assert_eq!(
+            unrecognized(&version, &specs),
+            vec![
+                "node-v20.6.1-linux-wasm32.tar.gz",
+                "node-v20.6.1-win-x64.appx",
+                "win-x64/node.dll",
+                "node-v18.18.0-linux-x64.tar.gz",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_finds_version_of_specs() {
+        assert_eq!(version_of(get_fake_specs()), Some("20.6.1".to_string()));
+        assert_eq!(version_of("abc123  SHASUMS256.txt\n\n"), None);
+        assert_eq!(version_of(""), None);
+    }
+
     #[tokio::test]
     async fn it_fetches_node_js_specs() {
         let version = String::from("20.6.1");
@@ -246,9 +835,12 @@ mod tests {
             .create_async()
             .await;
 
-        let specs = fetch(&version, &url_fmt).await.unwrap();
+        let (specs, served_by) = fetch(&version, &url_fmt, &http::Settings::default())
+            .await
+            .unwrap();
         mock.assert_async().await;
         assert_eq!(specs, get_fake_specs());
+        assert_eq!(served_by, url_fmt);
     }
 
     #[tokio::test]
@@ -265,77 +857,39 @@ mod tests {
             .create_async()
             .await;
 
-        fetch(&version, &url_fmt).await.unwrap();
+        fetch(&version, &url_fmt, &http::Settings::default())
+            .await
+            .unwrap();
         mock.assert_async().await;
     }
-}
-
-#[cfg(test)]
-use mockito::{Mock, Server};
 
-#[cfg(test)]
-fn assert_is_darwin_arm64_targz_specs(specs: ParsedSpecs) {
-    assert_eq!(specs.len(), 1);
-    let (os, arch, ext, sha256, filename) = &specs[0];
-    assert_eq!(*os, NodeJSOS::Darwin);
-    assert_eq!(*arch, NodeJSArch::ARM64);
-    assert_eq!(*ext, NodeJSPkgExt::Targz);
-    assert_eq!(filename, "node-v20.6.1-darwin-arm64.tar.gz");
-    assert_eq!(sha256, "FAKESHA");
-}
+    #[tokio::test]
+    async fn it_fails_to_fetch_node_js_specs_when_server_errors() {
+        let version = String::from("20.6.1");
+        let mut url_fmt = NodeJSURLFormatter::new();
+        let mut server = Server::new_async().await;
+        let mock = setup_server_mock(&version, &mut url_fmt, &mut server)
+            .with_status(503)
+            .with_header("retry-after", "30")
+            .create_async()
+            .await;
 
-#[cfg(test)]
-pub fn setup_server_mock(
-    version: &str,
-    url_fmt: &mut NodeJSURLFormatter,
-    server: &mut Server,
-) -> Mock {
-    url_fmt.host = server.host_with_port();
-    url_fmt.protocol = "http:".to_string();
-    server.mock("GET", url_fmt.info_pathname(version).as_str())
-}
+        let err = fetch(&version, &url_fmt, &http::Settings::default())
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
 
-#[cfg(test)]
-pub fn get_fake_specs() -> &'static str {
-    "ea52b4feaf917e08cd2c729c1186585fcacef07c261a01310c91333b9e41d93c  node-v20.6.1-aix-ppc64.tar.gz
-    9471bd6dc491e09c31b0f831f5953284b8a6842ed4ccb98f5c62d13e6086c471  node-v20.6.1-arm64.msi
-    d8ba8018d45b294429b1a7646ccbeaeb2af3cdf45b5c91dabbd93e2a2035cb46  node-v20.6.1-darwin-arm64.tar.gz
-    9c61b0d60fce962244d5e54549dc912e28b3c5f5e23149bfd15f66f8f7269129  node-v20.6.1-darwin-arm64.tar.xz
-    365ec544c6596f194afff9a613554abfc68d4a2274181b7651386d9a11cf5862  node-v20.6.1-darwin-x64.tar.gz
-    9b10c16670781e3a5af722656d28f264cdd8ebb3140f62692b33813100391349  node-v20.6.1-darwin-x64.tar.xz
-    d8271461ced2887f65af413949caee19db3e80d22bbefdaf01252ca998570052  node-v20.6.1-headers.tar.gz
-    60963e3ee60b6739e97e0c7b8ffb25848a82649c0c277af728400c570fd9db6d  node-v20.6.1-headers.tar.xz
-    d38fe2e41e3fe8ae81b517b4cf49521f500e181e54f4c3d05e2b2d691a57b2ca  node-v20.6.1-linux-arm64.tar.gz
-    6823720796b287465bb4aa8e7611143322ffd6cbdb9c6e3b149576f6d87953bf  node-v20.6.1-linux-arm64.tar.xz
-    459510281ea51cf5d89fc666e36fbba80793ae4b90c3a7f89dd6666c65c825b3  node-v20.6.1-linux-armv7l.tar.gz
-    9dbd4fd7f804a28de91ffb8792df6e89bbb4f934fccd013624b3dabf8bf809ac  node-v20.6.1-linux-armv7l.tar.xz
-    ca00f1aa8b2535fa167258cf5f2cfce4b79d83c442dd5e46f5e17d6a5749ec0f  node-v20.6.1-linux-ppc64le.tar.gz
-    27884935b025b6676e4b8737f334673ee825947d0baef61aa0326374597aeb05  node-v20.6.1-linux-ppc64le.tar.xz
-    4a3f29cfc8a7ed1e9e44fcacb78e2fbaa3ce01be1efc4971a42710ad1e9e45d1  node-v20.6.1-linux-s390x.tar.gz
-    3968d629989b6de16b8872b6d7ee6e6cdf1204def99c43412a6ee28203ed0022  node-v20.6.1-linux-s390x.tar.xz
-    26dd13a6f7253f0ab9bcab561353985a297d927840771d905566735b792868da  node-v20.6.1-linux-x64.tar.gz
-    591f9f274104f266a8cf085d2c7d5d2848ba73b98ae323d501db2d4c4b7026e5  node-v20.6.1-linux-x64.tar.xz
-    d9acf82d9576dd0350c8e66b55f6fc2750fa9f4aa23d6453ffc58e32af995894  node-v20.6.1.pkg
-    0053c09a01b1b355bca5af82927cae376124c13d74fa53567f08f4cfb085e6aa  node-v20.6.1.tar.gz
-    3aec5e728daa38800c343b129221d3488064a2529a39bb5467bc55be226c6a2b  node-v20.6.1.tar.xz
-    337549faf397deb0da3bccd4e27db45a619d89de4ea12830d16d9dfaded8e92c  node-v20.6.1-win-arm64.7z
-    0e62045bfc9d7c38360bd7da152c75ed82087242d5e4b401fa23a439588d36f6  node-v20.6.1-win-arm64.zip
-    c6cfe7824770a266a30bee8c33f485d0e89b94254c682250a239d83adfb7ce77  node-v20.6.1-win-x64.7z
-    88371914f1f75d594bb367570e163cf5ecebeb514fd54cc765093819ebb0ed48  node-v20.6.1-win-x64.zip
-    87d631b294a25386400d0f44d227330da62a1326e2a4fbb98bda3d7c431257f1  node-v20.6.1-win-x86.7z
-    578cff623601aa8878a035f06edbf69190338ee3b345e7a096e804cb80c4ce24  node-v20.6.1-win-x86.zip
-    5c2616da46728dd1326645c7db114e78ad87138a258c0724a035269258c23509  node-v20.6.1-x64.msi
-    cb83586af83182187e760b7e01aa7c7b2bacb521d60ceefed3ac6fc62c222449  node-v20.6.1-x86.msi
-    7cc3240fd7ce7926eef1cbbad33b033f7c5d97b3f3e527d65ff1e2c3f7638a11  win-arm64/node.exe
-    deb027ded744371657811cfe52e774881ea928d36779924af84aa9a7a31104d2  win-arm64/node.lib
-    dcb6b4bc6f2a78bf0f759853b59e94ddbe9ad6b9f32d24fdcf590d74c6350bc2  win-arm64/node_pdb.7z
-    bdcd574e99646ec4a03bb13b3661c957f5a7ca837f5c33827075c4262d449689  win-arm64/node_pdb.zip
-    5b824f3a375cca06dfd7dc70fa341a6ef8bb0b2e912358d8602a0c7ad273b9a4  win-x64/node.exe
-    d275cfc4d637d2feaf4c39e1a5f5cd84f5b474fa713c15013e940c329feed13b  win-x64/node.lib
-    fea6c0fcff45739a6e5af9843ec45455c97ff8677167bd649fd48cbef59ca52d  win-x64/node_pdb.7z
-    bc13f5e63c1510cd41f82dc20725f40bbfa378252e09a00a8531cddabbf1b106  win-x64/node_pdb.zip
-    837db0d8fb7fa194ebe23cd34ac7bedc02d1132de67cf4f147d694574be5cc4e  win-x86/node.exe
-    a0738dec64427ae73eeb1d036081652c1c0223a679a63e0459c2af667f284f58  win-x86/node.lib
-    516ac820f05eb8478be541ac12386c3b5b5c07624f73934bcf0b11a3fcdb1c95  win-x86/node_pdb.7z
-    9b68f3e1f1717a2f6a090e1679f8cc627566ed064c657c35eddd0dba9484e310  win-x86/node_pdb.zip"
+        match err {
+            NodeJSRelInfoError::HttpStatus {
+                status,
+                url,
+                retry_after,
+            } => {
+                assert_eq!(status, 503);
+                assert_eq!(url, url_fmt.info(&version));
+                assert_eq!(retry_after, Some(30));
+            }
+            _ => panic!("unexpected error: {err:?}"),
+        }
+    }
 }
@@ -3,6 +3,13 @@ pub struct NodeJSURLFormatter {
     pub protocol: String,
     pub host: String,
     pub pathname: String,
+    pub schedule: String,
+    // ordered base urls (e.g. `https://mirror.internal/node`) tried when the
+    // primary host times out or responds w/ a 5xx status
+    pub mirrors: Vec<String>,
+    // when set, release info and packages are requested from the
+    // `latest-v{major}.x` alias directory instead of `v{version}`
+    pub latest: Option<u64>,
 }
 
 impl Default for NodeJSURLFormatter {
@@ -17,9 +24,54 @@ impl NodeJSURLFormatter {
             protocol: String::from("https:"),
             host: String::from("nodejs.org"),
             pathname: String::from("/download/release"),
+            schedule: String::from(
+                "https://raw.githubusercontent.com/nodejs/Release/main/schedule.json",
+            ),
+            mirrors: vec![],
+            latest: None,
         }
     }
 
+    pub fn dir<V: AsRef<str>>(&self, version: V) -> String {
+        match self.latest {
+            Some(major) => format!("latest-v{}.x", major),
+            None => format!("v{}", version.as_ref()),
+        }
+    }
+
+    // copy serving releases from the given base url (e.g.
+    // `https://mirror.internal/node`) - base urls w/o a protocol default to
+    // `https:` and w/o a pathname to this formatter's
+    pub fn with_base<B: AsRef<str>>(&self, base: B) -> NodeJSURLFormatter {
+        let base = base.as_ref();
+        let (protocol, rest) = match base.split_once("//") {
+            Some((p, r)) if p.ends_with(':') => (p.to_string(), r),
+            _ => (String::from("https:"), base),
+        };
+        let (host, pathname) = match rest.split_once('/') {
+            Some((h, p)) => (h.to_string(), format!("/{}", p.trim_end_matches('/'))),
+            None => (rest.to_string(), self.pathname.clone()),
+        };
+
+        NodeJSURLFormatter {
+            protocol,
+            host,
+            pathname,
+            ..self.clone()
+        }
+    }
+
+    // the primary formatter followed by one formatter per mirror
+    pub fn candidates(&self) -> Vec<NodeJSURLFormatter> {
+        let mut all = vec![self.clone()];
+
+        for base in &self.mirrors {
+            all.push(self.with_base(base));
+        }
+
+        all
+    }
+
     pub fn info<V: AsRef<str>>(&self, version: V) -> String {
         format!(
             "{}//{}{}",
@@ -30,13 +82,32 @@ impl NodeJSURLFormatter {
     }
 
     pub fn info_pathname<V: AsRef<str>>(&self, version: V) -> String {
+        format!("{}/{}/SHASUMS256.txt", self.pathname, self.dir(version))
+    }
+
+    #[cfg(feature = "gpg")]
+    pub fn info_sig<V: AsRef<str>>(&self, version: V) -> String {
         format!(
-            "{}/v{}/SHASUMS256.txt",
-            self.pathname,
-            version.as_ref().to_owned(),
+            "{}//{}{}",
+            self.protocol,
+            self.host,
+            self.info_sig_pathname(version),
         )
     }
 
+    #[cfg(feature = "gpg")]
+    pub fn info_sig_pathname<V: AsRef<str>>(&self, version: V) -> String {
+        format!("{}.sig", self.info_pathname(version))
+    }
+
+    pub fn index(&self) -> String {
+        format!("{}//{}{}", self.protocol, self.host, self.index_pathname())
+    }
+
+    pub fn index_pathname(&self) -> String {
+        format!("{}/index.json", self.pathname)
+    }
+
     pub fn pkg<V: AsRef<str>, F: AsRef<str>>(&self, version: V, filename: F) -> String {
         format!(
             "{}//{}{}",
@@ -48,9 +119,9 @@ impl NodeJSURLFormatter {
 
     pub fn pkg_pathname<V: AsRef<str>, F: AsRef<str>>(&self, version: V, filename: F) -> String {
         format!(
-            "{}/v{}/{}",
+            "{}/{}/{}",
             self.pathname,
-            version.as_ref().to_owned(),
+            self.dir(version),
             filename.as_ref().to_owned(),
         )
     }
@@ -66,6 +137,12 @@ mod tests {
         assert_eq!(url_fmt.protocol, "https:");
         assert_eq!(url_fmt.host, "nodejs.org");
         assert_eq!(url_fmt.pathname, "/download/release");
+        assert_eq!(
+            url_fmt.schedule,
+            "https://raw.githubusercontent.com/nodejs/Release/main/schedule.json"
+        );
+        assert!(url_fmt.mirrors.is_empty());
+        assert_eq!(url_fmt.latest, None);
     }
 
     #[test]
@@ -83,6 +160,25 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "gpg")]
+    fn it_formats_url_for_node_js_release_info_signature() {
+        let url_fmt = NodeJSURLFormatter::new();
+        assert_eq!(
+            url_fmt.info_sig("1.0.0"),
+            "https://nodejs.org/download/release/v1.0.0/SHASUMS256.txt.sig"
+        );
+    }
+
+    #[test]
+    fn it_formats_url_for_node_js_release_index() {
+        let url_fmt = NodeJSURLFormatter::new();
+        assert_eq!(
+            url_fmt.index(),
+            "https://nodejs.org/download/release/index.json"
+        );
+    }
+
     #[test]
     fn it_formats_url_for_node_js_package() {
         let url_fmt = NodeJSURLFormatter::new();
@@ -91,4 +187,51 @@ mod tests {
             "https://nodejs.org/download/release/v1.0.0/fake-filename"
         );
     }
+
+    #[test]
+    fn it_lists_candidates_for_mirrors() {
+        let mut url_fmt = NodeJSURLFormatter::new();
+        assert_eq!(url_fmt.candidates(), vec![url_fmt.clone()]);
+
+        url_fmt.mirrors = vec![
+            "https://npmmirror.com/mirrors/node/".to_string(),
+            "http://127.0.0.1:1234".to_string(),
+            "mirror.internal/node".to_string(),
+        ];
+
+        let candidates = url_fmt.candidates();
+        assert_eq!(candidates.len(), 4);
+        assert_eq!(candidates[0], url_fmt);
+        assert_eq!(
+            candidates[1].info("1.0.0"),
+            "https://npmmirror.com/mirrors/node/v1.0.0/SHASUMS256.txt"
+        );
+        assert_eq!(
+            candidates[2].info("1.0.0"),
+            "http://127.0.0.1:1234/download/release/v1.0.0/SHASUMS256.txt"
+        );
+        assert_eq!(
+            candidates[3].info("1.0.0"),
+            "https://mirror.internal/node/v1.0.0/SHASUMS256.txt"
+        );
+        assert_eq!(candidates[3].mirrors, url_fmt.mirrors);
+    }
+
+    #[test]
+    fn it_formats_urls_for_latest_alias() {
+        let mut url_fmt = NodeJSURLFormatter::new();
+        assert_eq!(url_fmt.dir("1.0.0"), "v1.0.0");
+
+        url_fmt.latest = Some(20);
+
+        assert_eq!(url_fmt.dir("1.0.0"), "latest-v20.x");
+        assert_eq!(
+            url_fmt.info("1.0.0"),
+            "https://nodejs.org/download/release/latest-v20.x/SHASUMS256.txt"
+        );
+        assert_eq!(
+            url_fmt.pkg("1.0.0", "fake-filename"),
+            "https://nodejs.org/download/release/latest-v20.x/fake-filename"
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use mockito::Server;
 use node_js_release_info::*;
 
 const VERSION: &str = "20.7.0";
@@ -32,7 +33,7 @@ async fn it_fetches_node_js_release_info_for_a_given_configuration() {
 async fn it_fetches_node_js_release_info_for_all_supported_configurations() {
     let info = NodeJSRelInfo::new(VERSION);
     let result = info.fetch_all().await.unwrap();
-    assert_eq!(result.len(), 24);
+    assert_eq!(result.len(), 41);
     assert_eq!(
         result[4].url,
         "https://nodejs.org/download/release/v20.7.0/node-v20.7.0-darwin-x64.tar.gz"
@@ -42,3 +43,61 @@ async fn it_fetches_node_js_release_info_for_all_supported_configurations() {
         "ceeba829f44e7573949f2ce2ad5def27f1d6daa55f2860bea82964851fae01bc"
     );
 }
+
+#[tokio::test]
+async fn it_fetches_node_js_release_info_from_custom_host() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/dist/v20.7.0/SHASUMS256.txt")
+        .with_body("ceeba829f44e7573949f2ce2ad5def27f1d6daa55f2860bea82964851fae01bc  node-v20.7.0-darwin-x64.tar.gz")
+        .create_async()
+        .await;
+
+    let all = NodeJSRelInfo::new(VERSION)
+        .protocol("http:")
+        .host(server.host_with_port())
+        .pathname("/dist")
+        .fetch_all()
+        .await
+        .unwrap();
+    mock.assert_async().await;
+
+    assert_eq!(all.len(), 1);
+    assert_eq!(
+        all[0].url,
+        format!(
+            "{}/dist/v20.7.0/node-v20.7.0-darwin-x64.tar.gz",
+            server.url()
+        )
+    );
+}
+
+#[tokio::test]
+async fn it_applies_process_wide_config_to_instances_created_from_it() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/dist/v20.7.0/SHASUMS256.txt")
+        .match_header("user-agent", "global-config/1.0.0")
+        .with_body("ceeba829f44e7573949f2ce2ad5def27f1d6daa55f2860bea82964851fae01bc  node-v20.7.0-darwin-x64.tar.gz")
+        .create_async()
+        .await;
+
+    // the only test touching the process-wide config - restored right away so
+    // it never leaks into other tests
+    let previous = NodeJSRelInfoConfig::global();
+    NodeJSRelInfoConfig::set_global(NodeJSRelInfoConfig {
+        mirror: Some(format!("{}/dist", server.url())),
+        user_agent: Some("global-config/1.0.0".to_string()),
+        ..NodeJSRelInfoConfig::new()
+    });
+    let info = NodeJSRelInfo::from_global_config(VERSION);
+    let unconfigured = NodeJSRelInfo::new(VERSION);
+    NodeJSRelInfoConfig::set_global(previous.clone());
+
+    let all = info.fetch_all().await.unwrap();
+    mock.assert_async().await;
+
+    assert_eq!(all.len(), 1);
+    assert!(unconfigured.url().starts_with("https://nodejs.org/"));
+    assert_eq!(NodeJSRelInfoConfig::global(), previous);
+}